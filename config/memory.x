@@ -1,7 +1,8 @@
 MEMORY
 {
   /* NOTE 1 K = 1 KiBi = 1024 bytes */
-  FLASH : ORIGIN = 0x08000000, LENGTH = 2M
+  /* The last 256K sector (sector 11) is reserved for the persistent configuration */
+  FLASH : ORIGIN = 0x08000000, LENGTH = 2M - 256K
   RAM : ORIGIN = 0x20020000, LENGTH = 368K + 16K
   ITCM : ORIGIN = 0x00000000, LENGTH = 16K /* Instruction Tighly Coupled Memory */
   DTCM : ORIGIN = 0x20000000, LENGTH = 128K /* Data Tighly Coupled Memory */
@@ -47,3 +48,13 @@ SECTIONS
         . = ALIGN(4);
     } > DTCM
 }
+
+/* Reserved slot holding the expected firmware CRC, checked by the `selftest` app. */
+/* It is placed after .rodata so that it lies outside the __stext..__etext region. */
+SECTIONS
+{
+    .fw_crc : ALIGN(4)
+    {
+        KEEP(*(.fw_crc));
+    } > FLASH
+} INSERT AFTER .rodata;
@@ -19,9 +19,39 @@ pub enum Colors {
     Cyan,
     /// Magenta (255, 0, 255)
     Magenta,
+    /// Arbitrary color given by its (red, green, blue) components
+    Rgb(u8, u8, u8),
+    /// Fully transparent pixel, showing the background layer when drawn on the foreground
+    Transparent,
 }
 
+/// Names of the predefined colors, as accepted by [`Colors::from_name`].
+pub const K_COLOR_NAMES: [&str; 8] = [
+    "black", "white", "red", "green", "blue", "yellow", "cyan", "magenta",
+];
+
 impl Colors {
+    /// Returns the predefined color matching a name.
+    ///
+    /// # Parameters
+    /// - `name`: Lowercase color name, one of [`K_COLOR_NAMES`].
+    ///
+    /// # Returns
+    /// The matching color, or `None` if the name is unknown.
+    pub fn from_name(p_name: &str) -> Option<Colors> {
+        match p_name {
+            "black" => Some(Colors::Black),
+            "white" => Some(Colors::White),
+            "red" => Some(Colors::Red),
+            "green" => Some(Colors::Green),
+            "blue" => Some(Colors::Blue),
+            "yellow" => Some(Colors::Yellow),
+            "cyan" => Some(Colors::Cyan),
+            "magenta" => Some(Colors::Magenta),
+            _ => None,
+        }
+    }
+
     /// Converts the high-level color to its ARGB representation.
     ///
     /// # Returns
@@ -36,6 +66,166 @@ impl Colors {
             Colors::Yellow => PixelColorARGB::from_u32(0xFFFFFF00),
             Colors::Cyan => PixelColorARGB::from_u32(0xFF00FFFF),
             Colors::Magenta => PixelColorARGB::from_u32(0xFFFF00FF),
+            Colors::Rgb(l_r, l_g, l_b) => PixelColorARGB::from_u32(
+                0xFF000000 | ((*l_r as u32) << 16) | ((*l_g as u32) << 8) | (*l_b as u32),
+            ),
+            Colors::Transparent => PixelColorARGB::from_u32(0x00000000),
+        }
+    }
+
+    /// Returns the text color readable on this color used as background.
+    ///
+    /// The luminance is computed with integer math from the ARGB channels, using the Rec. 601
+    /// weights `(299 R + 587 G + 114 B) / 1000`. [`Colors::Transparent`] is treated as black.
+    ///
+    /// # Returns
+    /// [`Colors::Black`] if the luminance is at least 128, [`Colors::White`] otherwise.
+    pub fn contrasting(&self) -> Colors {
+        let l_argb = self.to_argb().as_u32();
+        let l_r = (l_argb >> 16) & 0xFF;
+        let l_g = (l_argb >> 8) & 0xFF;
+        let l_b = l_argb & 0xFF;
+        if (299 * l_r + 587 * l_g + 114 * l_b) / 1000 >= 128 {
+            Colors::Black
+        } else {
+            Colors::White
+        }
+    }
+
+    /// Builds a color from its HSV representation, using integer arithmetic only.
+    ///
+    /// # Parameters
+    /// - `h`: Hue in degrees. Values of 360 and above wrap around.
+    /// - `s`: Saturation, from 0 (grey) to 255 (fully saturated).
+    /// - `v`: Value, from 0 (black) to 255 (full brightness).
+    ///
+    /// # Returns
+    /// A [`Colors::Rgb`] color, fully opaque when converted with [`Colors::to_argb`].
+    pub fn from_hsv(p_h: u16, p_s: u8, p_v: u8) -> Colors {
+        let l_s = p_s as u32;
+        let l_v = p_v as u32;
+
+        if l_s == 0 {
+            return Colors::Rgb(p_v, p_v, p_v);
+        }
+
+        // Split hue into 6 sectors of 60 degrees, remainder scaled to 0..255
+        let l_h = (p_h % 360) as u32;
+        let l_sector = l_h / 60;
+        let l_rem = (l_h % 60) * 255 / 60;
+
+        let l_p = (l_v * (255 - l_s) / 255) as u8;
+        let l_q = (l_v * (255 - l_s * l_rem / 255) / 255) as u8;
+        let l_t = (l_v * (255 - l_s * (255 - l_rem) / 255) / 255) as u8;
+
+        match l_sector {
+            0 => Colors::Rgb(p_v, l_t, l_p),
+            1 => Colors::Rgb(l_q, p_v, l_p),
+            2 => Colors::Rgb(l_p, p_v, l_t),
+            3 => Colors::Rgb(l_p, l_q, p_v),
+            4 => Colors::Rgb(l_t, l_p, p_v),
+            _ => Colors::Rgb(p_v, l_p, l_q),
         }
     }
 }
+
+/// Linearly interpolates between two ARGB8888 colors, channel by channel, using integer math.
+///
+/// # Parameters
+/// - `from`: Color at position `0`.
+/// - `to`: Color at position `steps`.
+/// - `pos`: Position to compute, from `0` to `steps`.
+/// - `steps`: Number of steps between the two colors. `0` returns `from`.
+///
+/// # Returns
+/// The interpolated ARGB8888 value.
+pub(crate) fn interpolate_argb(p_from: u32, p_to: u32, p_pos: u32, p_steps: u32) -> u32 {
+    if p_steps == 0 {
+        return p_from;
+    }
+
+    (0..4).fold(0, |l_acc, l_channel| {
+        let l_shift = l_channel * 8;
+        let l_from = ((p_from >> l_shift) & 0xFF) as i32;
+        let l_to = ((p_to >> l_shift) & 0xFF) as i32;
+        let l_value = l_from + (l_to - l_from) * p_pos as i32 / p_steps as i32;
+        l_acc | ((l_value as u32 & 0xFF) << l_shift)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolation_ends_on_both_colors() {
+        assert_eq!(interpolate_argb(0xFF000000, 0xFFFFFFFF, 0, 10), 0xFF000000);
+        assert_eq!(interpolate_argb(0xFF000000, 0xFFFFFFFF, 10, 10), 0xFFFFFFFF);
+    }
+
+    #[test]
+    fn interpolation_midpoint_is_channel_average() {
+        assert_eq!(interpolate_argb(0xFF000000, 0xFFC8643C, 1, 2), 0xFF64321E);
+        assert_eq!(interpolate_argb(0xFFFF0000, 0xFF0000FF, 1, 2), 0xFF80007F);
+    }
+
+    #[test]
+    fn interpolation_without_steps_returns_start_color() {
+        assert_eq!(interpolate_argb(0xFF123456, 0xFFFFFFFF, 5, 0), 0xFF123456);
+    }
+
+    #[test]
+    fn light_colors_get_black_text() {
+        assert!(matches!(Colors::White.contrasting(), Colors::Black));
+        assert!(matches!(Colors::Yellow.contrasting(), Colors::Black));
+        assert!(matches!(
+            Colors::Rgb(128, 128, 128).contrasting(),
+            Colors::Black
+        ));
+    }
+
+    #[test]
+    fn dark_colors_get_white_text() {
+        assert!(matches!(Colors::Black.contrasting(), Colors::White));
+        assert!(matches!(Colors::Blue.contrasting(), Colors::White));
+        assert!(matches!(
+            Colors::Rgb(127, 127, 127).contrasting(),
+            Colors::White
+        ));
+        assert!(matches!(Colors::Transparent.contrasting(), Colors::White));
+    }
+
+    #[test]
+    fn hsv_primary_colors_convert_exactly() {
+        assert!(matches!(
+            Colors::from_hsv(0, 255, 255),
+            Colors::Rgb(255, 0, 0)
+        ));
+        assert!(matches!(
+            Colors::from_hsv(120, 255, 255),
+            Colors::Rgb(0, 255, 0)
+        ));
+        assert!(matches!(
+            Colors::from_hsv(240, 255, 255),
+            Colors::Rgb(0, 0, 255)
+        ));
+        assert!(matches!(
+            Colors::from_hsv(360, 255, 255),
+            Colors::Rgb(255, 0, 0)
+        ));
+    }
+
+    #[test]
+    fn hsv_greys_ignore_the_hue() {
+        assert!(matches!(
+            Colors::from_hsv(0, 0, 255),
+            Colors::Rgb(255, 255, 255)
+        ));
+        assert!(matches!(
+            Colors::from_hsv(200, 0, 255),
+            Colors::Rgb(255, 255, 255)
+        ));
+        assert!(matches!(Colors::from_hsv(0, 255, 0), Colors::Rgb(0, 0, 0)));
+        assert!(matches!(Colors::from_hsv(300, 0, 0), Colors::Rgb(0, 0, 0)));
+    }
+}
@@ -28,6 +28,15 @@ pub enum DisplayError {
     DisplayDriverNotInitialized,
     OutOfScreenBounds,
     UnknownCharacter(u8),
+    NonAsciiInput(usize),
+    InvalidBacklightLimits(u8, u8),
+    BufferSizeMismatch(usize, usize),
+    InvalidGlyphCode(u8),
+    InvalidRadius(u16),
+    InvalidCellSize(u16),
+    InvalidFontTable,
+    InvalidTextScale(u8, u8),
+    InvalidFrameBuffer(u32),
     UnknownError,
 }
 
@@ -54,6 +63,90 @@ impl DisplayError {
                     .push_str(format!(25; "Unknown character: {}", l_c).unwrap().as_str())
                     .unwrap()
             }
+            DisplayError::NonAsciiInput(l_index) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(40; "Non-ASCII input at byte {}", l_index)
+                            .unwrap()
+                            .as_str(),
+                    )
+                    .unwrap()
+            }
+            DisplayError::InvalidBacklightLimits(l_min, l_max) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(50; "Invalid backlight limits: [{}, {}]", l_min, l_max)
+                            .unwrap()
+                            .as_str(),
+                    )
+                    .unwrap()
+            }
+            DisplayError::BufferSizeMismatch(l_expected, l_actual) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(60; "Buffer size mismatch: expected {}, got {}", l_expected, l_actual)
+                            .unwrap()
+                            .as_str(),
+                    )
+                    .unwrap()
+            }
+            DisplayError::InvalidGlyphCode(l_code) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(40; "Invalid custom glyph code: {}", l_code)
+                            .unwrap()
+                            .as_str(),
+                    )
+                    .unwrap()
+            }
+            DisplayError::InvalidRadius(l_radius) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(40; "Invalid radius: {}", l_radius)
+                            .unwrap()
+                            .as_str(),
+                    )
+                    .unwrap()
+            }
+            DisplayError::InvalidCellSize(l_cell) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(40; "Invalid cell size: {}", l_cell)
+                            .unwrap()
+                            .as_str(),
+                    )
+                    .unwrap()
+            }
+            DisplayError::InvalidFontTable => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg.push_str("Invalid font table").unwrap()
+            }
+            DisplayError::InvalidTextScale(l_sx, l_sy) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(40; "Invalid text scale: {}x{}", l_sx, l_sy)
+                            .unwrap()
+                            .as_str(),
+                    )
+                    .unwrap()
+            }
+            DisplayError::InvalidFrameBuffer(l_address) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(50; "Invalid frame buffer address: {:#010x}", l_address)
+                            .unwrap()
+                            .as_str(),
+                    )
+                    .unwrap()
+            }
         }
         l_msg
     }
@@ -69,6 +162,15 @@ impl DisplayError {
             DisplayError::UnknownError => Error,
             DisplayError::OutOfScreenBounds => Error,
             DisplayError::UnknownCharacter(_) => Error,
+            DisplayError::NonAsciiInput(_) => Error,
+            DisplayError::InvalidBacklightLimits(_, _) => Error,
+            DisplayError::BufferSizeMismatch(_, _) => Error,
+            DisplayError::InvalidGlyphCode(_) => Error,
+            DisplayError::InvalidRadius(_) => Error,
+            DisplayError::InvalidCellSize(_) => Error,
+            DisplayError::InvalidFontTable => Error,
+            DisplayError::InvalidTextScale(_, _) => Error,
+            DisplayError::InvalidFrameBuffer(_) => Critical,
         }
     }
 }
@@ -7235,6 +7235,123 @@ const K_FONT_ASCII_24: [u32; 2280] = [
     0x00000000, //
 ];
 
+/// Glyph rows of a font table.
+///
+/// Each row holds one pixel line of a glyph, the leftmost pixel being the most significant
+/// bit. The row type bounds the glyph width: 8, 16 or 32 pixels.
+#[derive(Copy, Clone)]
+pub enum FontBitmap {
+    /// Rows of up to 8 pixels.
+    Rows8(&'static [u8]),
+    /// Rows of up to 16 pixels.
+    Rows16(&'static [u16]),
+    /// Rows of up to 32 pixels.
+    Rows32(&'static [u32]),
+}
+
+impl FontBitmap {
+    /// Returns the number of rows in the bitmap.
+    fn len(&self) -> usize {
+        match self {
+            FontBitmap::Rows8(l_rows) => l_rows.len(),
+            FontBitmap::Rows16(l_rows) => l_rows.len(),
+            FontBitmap::Rows32(l_rows) => l_rows.len(),
+        }
+    }
+
+    /// Returns the number of pixels in a row.
+    fn row_bits(&self) -> u8 {
+        match self {
+            FontBitmap::Rows8(_) => 8,
+            FontBitmap::Rows16(_) => 16,
+            FontBitmap::Rows32(_) => 32,
+        }
+    }
+
+    /// Returns whether pixel `x` of row `row` is set.
+    fn is_pixel_set(&self, p_row: usize, p_x: u8) -> bool {
+        match self {
+            FontBitmap::Rows8(l_rows) => l_rows[p_row] & (1 << (7 - p_x)) != 0,
+            FontBitmap::Rows16(l_rows) => l_rows[p_row] & (1 << (15 - p_x)) != 0,
+            FontBitmap::Rows32(l_rows) => l_rows[p_row] & (1 << (31 - p_x)) != 0,
+        }
+    }
+}
+
+/// Monospaced font described by a glyph table.
+///
+/// Glyphs are stored one after the other for every code of `first_char..=last_char`, each
+/// glyph being `height` rows of the bitmap. Built-in fonts use the same representation, so
+/// a custom table is rendered exactly like them (see [`FontSize::Custom`]).
+pub struct FontTable {
+    /// Glyph width in pixels.
+    pub width: u8,
+    /// Glyph height in pixels.
+    pub height: u8,
+    /// First character code in the table.
+    pub first_char: u8,
+    /// Last character code in the table.
+    pub last_char: u8,
+    /// Glyph rows, `height` rows per character.
+    pub bitmap: FontBitmap,
+}
+
+impl FontTable {
+    /// Returns whether the table is consistent.
+    ///
+    /// The glyph size must not be null, the width must fit in a bitmap row, the code range
+    /// must not be empty and the bitmap must hold exactly one glyph per code.
+    pub fn is_valid(&self) -> bool {
+        self.width > 0
+            && self.height > 0
+            && self.width <= self.bitmap.row_bits()
+            && self.first_char <= self.last_char
+            && self.bitmap.len()
+                == ((self.last_char - self.first_char) as usize + 1) * self.height as usize
+    }
+
+    /// Returns whether the table has a glyph for the character code.
+    pub fn contains(&self, p_char: u8) -> bool {
+        (self.first_char..=self.last_char).contains(&p_char)
+    }
+}
+
+/// 7x12 pixel built-in font.
+const K_FONT_TABLE_12: FontTable = FontTable {
+    width: 7,
+    height: 12,
+    first_char: K_FIRST_ASCII_CHAR,
+    last_char: K_LAST_ASCII_CHAR,
+    bitmap: FontBitmap::Rows8(&K_FONT_ASCII_12),
+};
+
+/// 11x16 pixel built-in font.
+const K_FONT_TABLE_16: FontTable = FontTable {
+    width: 11,
+    height: 16,
+    first_char: K_FIRST_ASCII_CHAR,
+    last_char: K_LAST_ASCII_CHAR,
+    bitmap: FontBitmap::Rows16(&K_FONT_ASCII_16),
+};
+
+/// 14x20 pixel built-in font.
+const K_FONT_TABLE_20: FontTable = FontTable {
+    width: 14,
+    height: 20,
+    first_char: K_FIRST_ASCII_CHAR,
+    last_char: K_LAST_ASCII_CHAR,
+    bitmap: FontBitmap::Rows16(&K_FONT_ASCII_20),
+};
+
+/// 17x24 pixel built-in font.
+const K_FONT_TABLE_24: FontTable = FontTable {
+    width: 17,
+    height: 24,
+    first_char: K_FIRST_ASCII_CHAR,
+    last_char: K_LAST_ASCII_CHAR,
+    bitmap: FontBitmap::Rows32(&K_FONT_ASCII_24),
+};
+
 /// Represents the available font sizes for text rendering.
 #[derive(Copy, Clone)]
 pub enum FontSize {
@@ -7246,44 +7363,47 @@ pub enum FontSize {
     Font20,
     /// 17x24 pixel font.
     Font24,
+    /// Font supplied by the user as a glyph table.
+    Custom(&'static FontTable),
 }
 
 impl FontSize {
-    pub(crate) fn is_pixel_set(&self, p_ascii_char: u8, p_x: u8, p_y: u8) -> bool {
+    /// Returns the glyph table of this font.
+    pub fn table(&self) -> &'static FontTable {
         match self {
-            FontSize::Font12 => {
-                let l_col_data = K_FONT_ASCII_12[(p_ascii_char - 0x20) as usize
-                    * self.get_char_size().1 as usize
-                    + p_y as usize];
-                l_col_data & (1 << (7 - p_x)) != 0
-            }
-            FontSize::Font16 => {
-                let l_col_data = K_FONT_ASCII_16[(p_ascii_char - 0x20) as usize
-                    * self.get_char_size().1 as usize
-                    + p_y as usize];
-                l_col_data & (1 << (15 - p_x)) != 0
-            }
-            FontSize::Font20 => {
-                let l_col_data = K_FONT_ASCII_20[(p_ascii_char - 0x20) as usize
-                    * self.get_char_size().1 as usize
-                    + p_y as usize];
-                l_col_data & (1 << (15 - p_x)) != 0
-            }
-            FontSize::Font24 => {
-                let l_col_data = K_FONT_ASCII_24[(p_ascii_char - 0x20) as usize
-                    * self.get_char_size().1 as usize
-                    + p_y as usize];
-                l_col_data & (1 << (31 - p_x)) != 0
-            }
+            FontSize::Font12 => &K_FONT_TABLE_12,
+            FontSize::Font16 => &K_FONT_TABLE_16,
+            FontSize::Font20 => &K_FONT_TABLE_20,
+            FontSize::Font24 => &K_FONT_TABLE_24,
+            FontSize::Custom(l_table) => l_table,
         }
     }
 
-    pub(crate) fn get_char_size(&self) -> (u8, u8) {
-        match self {
-            FontSize::Font12 => (7, 12),
-            FontSize::Font16 => (11, 16),
-            FontSize::Font20 => (14, 20),
-            FontSize::Font24 => (17, 24),
-        }
+    /// Returns whether pixel `(x, y)` of the glyph of `ascii_char` is set.
+    ///
+    /// The character must be in the font table (see [`FontTable::contains`]).
+    pub(crate) fn is_pixel_set(&self, p_ascii_char: u8, p_x: u8, p_y: u8) -> bool {
+        let l_table = self.table();
+        l_table.bitmap.is_pixel_set(
+            (p_ascii_char - l_table.first_char) as usize * l_table.height as usize + p_y as usize,
+            p_x,
+        )
+    }
+
+    /// Returns the horizontal advance in pixels for the given character.
+    ///
+    /// All current fonts are monospaced, so the advance is the font character width
+    /// whatever the character.
+    pub(crate) fn get_char_advance(&self, _p_ascii_char: u8) -> u16 {
+        self.get_char_size().0 as u16
+    }
+
+    /// Returns the size of a character cell of this font.
+    ///
+    /// # Returns
+    /// `(width, height)` in pixels.
+    pub fn get_char_size(&self) -> (u8, u8) {
+        let l_table = self.table();
+        (l_table.width, l_table.height)
     }
 }
@@ -1,5 +1,16 @@
-const K_FRAME_BUFFER_1_ADDRESS: u32 = 0xC0000000;
-const K_FRAME_BUFFER_2_ADDRESS: u32 = 0xC0200000;
+/// Base address of the frame buffers of the primary display, as set by the LCD initialization
+/// sequence.
+pub const K_DEFAULT_FRAME_BUFFER_BASE: u32 = 0xC0000000;
+/// Offset of the first foreground frame buffer from the base address.
+const K_FRAME_BUFFER_1_OFFSET: u32 = 0;
+/// Offset of the second foreground frame buffer from the base address.
+const K_FRAME_BUFFER_2_OFFSET: u32 = 0x200000;
+/// Offset of the frame buffer of the background layer from the base address.
+///
+/// The background layer is single-buffered: it is meant for static content redrawn rarely.
+const K_BACKGROUND_FRAME_BUFFER_OFFSET: u32 = 0x400000;
+/// Size of the memory used by the frame buffers of a display, from its base address.
+pub const K_FRAME_BUFFER_MEMORY_SIZE: u32 = 0x600000;
 
 pub enum FrameBufferSelector {
     FrameBuffer1,
@@ -7,20 +18,70 @@ pub enum FrameBufferSelector {
 }
 
 pub struct FrameBuffer {
+    /// Address of the first byte of the frame buffers memory.
+    base: u32,
     selected: FrameBufferSelector,
+    /// Bounding box of the modified pixels since the last flush, as
+    /// `(x_min, y_min, x_max, y_max)` with exclusive maximum bounds.
+    dirty: Option<(u16, u16, u16, u16)>,
 }
 
 impl FrameBuffer {
     /// Constructs a new instance of the struct with default values.
     ///
+    /// The frame buffers lie in the [`K_FRAME_BUFFER_MEMORY_SIZE`] bytes starting at `base`:
+    /// the two foreground frame buffers, then the background frame buffer.
+    ///
+    /// # Parameters
+    /// - `base`: Address of the frame buffers memory.
+    ///
     /// # Returns
     /// A new instance of the struct where:
     /// - `selected` is set to `FrameBufferSelector::FrameBuffer2`.
+    /// - no region is marked as dirty.
     ///
-    pub fn new() -> Self {
-        Self {
-            selected: FrameBufferSelector::FrameBuffer2,
-        }
+    /// `None` if `base` is not word-aligned or if the memory would wrap around the address
+    /// space.
+    pub fn new(p_base: u32) -> Option<Self> {
+        (p_base.is_multiple_of(4) && p_base.checked_add(K_FRAME_BUFFER_MEMORY_SIZE).is_some())
+            .then_some(Self {
+                base: p_base,
+                selected: FrameBufferSelector::FrameBuffer2,
+                dirty: None,
+            })
+    }
+
+    /// Extends the dirty region so that it includes the given rectangle.
+    ///
+    /// # Parameters
+    /// - `x`: X coordinate of the top-left corner of the modified rectangle.
+    /// - `y`: Y coordinate of the top-left corner of the modified rectangle.
+    /// - `width`: Width of the modified rectangle in pixels.
+    /// - `height`: Height of the modified rectangle in pixels.
+    pub fn mark_dirty(&mut self, p_x: u16, p_y: u16, p_width: u16, p_height: u16) {
+        let l_x_max = p_x.saturating_add(p_width);
+        let l_y_max = p_y.saturating_add(p_height);
+
+        self.dirty = Some(match self.dirty {
+            Some((l_x0, l_y0, l_x1, l_y1)) => (
+                l_x0.min(p_x),
+                l_y0.min(p_y),
+                l_x1.max(l_x_max),
+                l_y1.max(l_y_max),
+            ),
+            None => (p_x, p_y, l_x_max, l_y_max),
+        });
+    }
+
+    /// Returns the dirty region and clears it.
+    ///
+    /// # Returns
+    /// * `Some((x, y, width, height))` - Bounding box of the pixels modified since the last call.
+    /// * `None` - If nothing was modified.
+    pub fn take_dirty(&mut self) -> Option<(u16, u16, u16, u16)> {
+        self.dirty
+            .take()
+            .map(|(l_x0, l_y0, l_x1, l_y1)| (l_x0, l_y0, l_x1 - l_x0, l_y1 - l_y0))
     }
 
     /// Returns the memory address of the currently active frame buffer.
@@ -30,8 +91,8 @@ impl FrameBuffer {
     /// determines the active frame buffer.
     ///
     /// # Returns
-    /// * `K_FRAME_BUFFER_1_ADDRESS` if `self.selected` is `FrameBufferSelector::FrameBuffer1`.
-    /// * `K_FRAME_BUFFER_2_ADDRESS` if `self.selected` is `FrameBufferSelector::FrameBuffer2`.
+    /// * The first frame buffer address if `self.selected` is `FrameBufferSelector::FrameBuffer1`.
+    /// * The second frame buffer address if `self.selected` is `FrameBufferSelector::FrameBuffer2`.
     ///
     /// # Assumptions
     /// This function assumes that the `self.selected` field is properly initialized
@@ -41,13 +102,10 @@ impl FrameBuffer {
     /// This function does not return any errors and assumes the selected frame buffer
     /// always maps to a valid address.
     ///
-    /// # Requirements
-    /// Ensure that the constants `K_FRAME_BUFFER_1_ADDRESS` and `K_FRAME_BUFFER_2_ADDRESS`
-    /// are defined in the same scope or accessible to this function.
     pub fn address_active(&self) -> u32 {
         match self.selected {
-            FrameBufferSelector::FrameBuffer1 => K_FRAME_BUFFER_1_ADDRESS,
-            FrameBufferSelector::FrameBuffer2 => K_FRAME_BUFFER_2_ADDRESS,
+            FrameBufferSelector::FrameBuffer1 => self.base + K_FRAME_BUFFER_1_OFFSET,
+            FrameBufferSelector::FrameBuffer2 => self.base + K_FRAME_BUFFER_2_OFFSET,
         }
     }
 
@@ -60,23 +118,25 @@ impl FrameBuffer {
     /// the other is displayed.
     ///
     /// # Returns
-    /// * `K_FRAME_BUFFER_2_ADDRESS` - If the selected frame buffer is `FrameBuffer1`.
-    /// * `K_FRAME_BUFFER_1_ADDRESS` - If the selected frame buffer is `FrameBuffer2`.
+    /// * The second frame buffer address - If the selected frame buffer is `FrameBuffer1`.
+    /// * The first frame buffer address - If the selected frame buffer is `FrameBuffer2`.
     ///
     /// # Note
     /// Ensure that the `selected` field is set correctly to represent the current
     /// rendering buffer before calling this method.
     ///
-    /// # Dependencies
-    /// This function relies on the `FrameBufferSelector` enum and the constants
-    /// `K_FRAME_BUFFER_1_ADDRESS` and `K_FRAME_BUFFER_2_ADDRESS` being defined.
     pub fn address_displayed(&self) -> u32 {
         match self.selected {
-            FrameBufferSelector::FrameBuffer1 => K_FRAME_BUFFER_2_ADDRESS,
-            FrameBufferSelector::FrameBuffer2 => K_FRAME_BUFFER_1_ADDRESS,
+            FrameBufferSelector::FrameBuffer1 => self.base + K_FRAME_BUFFER_2_OFFSET,
+            FrameBufferSelector::FrameBuffer2 => self.base + K_FRAME_BUFFER_1_OFFSET,
         }
     }
 
+    /// Returns the memory address of the background layer frame buffer.
+    pub fn address_background(&self) -> u32 {
+        self.base + K_BACKGROUND_FRAME_BUFFER_OFFSET
+    }
+
     /// Switches the currently selected frame buffer and returns the address of the displayed frame.
     ///
     /// # Description
@@ -95,4 +155,61 @@ impl FrameBuffer {
         }
         self.address_displayed()
     }
+
+    /// Returns whether a whole frame starting at an address lies in the foreground frame
+    /// buffer region.
+    ///
+    /// # Parameters
+    /// - `address`: Start address of the frame.
+    /// - `width`: Width of a frame in pixels.
+    /// - `height`: Height of a frame in pixels.
+    ///
+    /// # Returns
+    /// `true` if the address is word-aligned and the ARGB8888 frame fits between the base
+    /// address and the background frame buffer. `false` if the frame size overflows.
+    pub fn is_valid_address(&self, p_address: u32, p_width: u16, p_height: u16) -> bool {
+        let l_frame_size = 4u32
+            .checked_mul(p_width as u32)
+            .and_then(|l_row| l_row.checked_mul(p_height as u32));
+
+        p_address.is_multiple_of(4)
+            && p_address >= self.base
+            && l_frame_size
+                .and_then(|l_size| p_address.checked_add(l_size))
+                .is_some_and(|l_end| l_end <= self.address_background())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displayed_frame_buffers_are_valid() {
+        let mut l_fb = FrameBuffer::new(K_DEFAULT_FRAME_BUFFER_BASE).unwrap();
+        assert!(l_fb.is_valid_address(l_fb.address_displayed(), 800, 480));
+        let l_address = l_fb.switch();
+        assert!(l_fb.is_valid_address(l_address, 800, 480));
+    }
+
+    #[test]
+    fn out_of_range_address_is_rejected() {
+        let l_fb = FrameBuffer::new(K_DEFAULT_FRAME_BUFFER_BASE).unwrap();
+        assert!(!l_fb.is_valid_address(K_DEFAULT_FRAME_BUFFER_BASE - 4, 800, 480));
+        assert!(!l_fb.is_valid_address(l_fb.address_background(), 800, 480));
+        assert!(!l_fb.is_valid_address(K_DEFAULT_FRAME_BUFFER_BASE + 2, 800, 480));
+    }
+
+    #[test]
+    fn oversized_frame_is_rejected() {
+        let l_fb = FrameBuffer::new(K_DEFAULT_FRAME_BUFFER_BASE).unwrap();
+        assert!(!l_fb.is_valid_address(K_DEFAULT_FRAME_BUFFER_BASE, 1024, 1025));
+        assert!(!l_fb.is_valid_address(K_DEFAULT_FRAME_BUFFER_BASE, u16::MAX, u16::MAX));
+    }
+
+    #[test]
+    fn unaligned_or_wrapping_base_is_rejected() {
+        assert!(FrameBuffer::new(K_DEFAULT_FRAME_BUFFER_BASE + 1).is_none());
+        assert!(FrameBuffer::new(u32::MAX - 3).is_none());
+    }
 }
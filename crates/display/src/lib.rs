@@ -5,29 +5,58 @@ mod fonts;
 mod frame_buffer;
 
 pub use errors::{DisplayError, DisplayErrorLevel, DisplayResult};
-pub use fonts::FontSize;
+pub use fonts::{FontBitmap, FontSize, FontTable};
 use hal_interface::{
-    Hal, InterfaceReadAction, InterfaceWriteActions, LcdActions, LcdLayer, LcdReadAction,
+    Hal, InterfaceReadAction, InterfaceWriteActions, LcdActions, LcdArea, LcdLayer, LcdReadAction,
 };
 
 use crate::FontSize::Font16;
-use crate::fonts::{K_FIRST_ASCII_CHAR, K_LAST_ASCII_CHAR};
 use crate::frame_buffer::FrameBuffer;
-pub use colors::Colors;
+use colors::interpolate_argb;
+pub use colors::{Colors, K_COLOR_NAMES};
+pub use frame_buffer::{K_DEFAULT_FRAME_BUFFER_BASE, K_FRAME_BUFFER_MEMORY_SIZE};
 use hal_interface::InterfaceReadResult::LcdRead;
 use hal_interface::LcdRead::LcdSize;
 
+/// Lowest backlight brightness in percent.
+const K_BACKLIGHT_MIN: u8 = 0;
+/// Highest backlight brightness in percent.
+const K_BACKLIGHT_MAX: u8 = 100;
+/// Size of the buffer used to format a number: sign and 10 digits of `u32::MAX`.
+const K_NUMBER_BUFFER_SIZE: usize = 11;
+/// First character code available for custom glyphs (control characters `0x10..=0x1F`).
+pub const K_CUSTOM_GLYPH_FIRST: u8 = 0x10;
+/// Number of character codes available for custom glyphs.
+pub const K_CUSTOM_GLYPH_COUNT: usize = 16;
+/// Largest horizontal or vertical text scale factor accepted by [`Display::set_text_scale`].
+pub const K_MAX_TEXT_SCALE: u8 = 4;
+
+/// Custom glyph entry: bitmap and `(width, height)` of the font it was registered for.
+type CustomGlyph = (&'static [u8], (u8, u8));
+
+/// Direction of a gradient drawn by [`Display::draw_gradient`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum GradientDir {
+    /// Color changes from left to right, each column has a single color.
+    Horizontal,
+    /// Color changes from top to bottom, each row has a single color.
+    Vertical,
+}
+
 /// Display driver abstraction wrapping an LCD HAL interface.
 ///
 /// This type manages:
 /// - An LCD HAL interface identifier and lock ownership (`kernel_master_id`)
 /// - Screen size discovery
-/// - A double frame buffer (via [`FrameBuffer`])
+/// - A double frame buffer (via [`FrameBuffer`]) for the foreground layer, and a single frame
+///   buffer for the background layer
 /// - Text rendering using the selected [`FontSize`]
-/// - A text cursor and default text color
+/// - A text cursor, default text color and default background color
 pub struct Display {
     /// The HAL interface ID for the LCD.
     hal_id: Option<usize>,
+    /// Name of the LCD interface, set by [`Display::init`].
+    name: Option<&'static str>,
     /// The master ID used for locking the interface.
     kernel_master_id: u32,
     /// Reference to the HAL implementation.
@@ -36,14 +65,32 @@ pub struct Display {
     size: Option<(u16, u16)>,
     /// Double frame buffer manager.
     frame_buffer: Option<FrameBuffer>,
+    /// Layer targeted by the clear and drawing methods.
+    active_layer: LcdLayer,
     /// Whether the display has been initialized.
     initialized: bool,
     /// Current text cursor position (x, y) in pixels.
     cursor_pos: (u16, u16),
     /// Active font size for text rendering.
     font: FontSize,
+    /// Horizontal and vertical text scale factors.
+    text_scale: (u8, u8),
     /// Active default color for text rendering.
     color: Colors,
+    /// Default background color, used by [`Display::clear_default`].
+    background: Colors,
+    /// Whether the panel needs an explicit refresh to show frame buffer changes.
+    flush_required: bool,
+    /// Allowed backlight range `(min, max)` in percent.
+    backlight_limits: (u8, u8),
+    /// Last backlight brightness applied, in percent.
+    backlight: u8,
+    /// Minimum delay between two frame buffer switches, in milliseconds.
+    min_frame_interval: u32,
+    /// Tick of the last frame buffer switch.
+    last_frame_tick: Option<u32>,
+    /// Custom glyphs registered for codes starting at [`K_CUSTOM_GLYPH_FIRST`].
+    custom_glyphs: [Option<CustomGlyph>; K_CUSTOM_GLYPH_COUNT],
 }
 
 impl Display {
@@ -59,22 +106,38 @@ impl Display {
     /// # Returns
     /// A [`Display`] instance in a non-initialized state with:
     /// - cursor at `(0, 0)`
-    /// - font set to [`FontSize::Font16`]
+    /// - foreground layer active
+    /// - font set to [`FontSize::Font16`], without text scaling
     /// - color set to [`Colors::White`]
+    /// - background set to [`Colors::Black`]
+    /// - no explicit flush required (memory-mapped frame buffer)
+    /// - backlight limits set to `[0, 100]`, brightness assumed at 100%
+    /// - no minimum frame interval
+    /// - no custom glyph
     ///
     /// # Errors
     /// This function does not return errors.
     pub fn new(p_kernel_master_id: u32) -> Self {
         Self {
             hal_id: None,
+            name: None,
             hal: None,
             kernel_master_id: p_kernel_master_id,
             size: None,
             frame_buffer: None,
+            active_layer: LcdLayer::FOREGROUND,
             initialized: false,
             cursor_pos: (0, 0),
             font: Font16,
+            text_scale: (1, 1),
             color: Colors::White,
+            background: Colors::Black,
+            flush_required: false,
+            backlight_limits: (K_BACKLIGHT_MIN, K_BACKLIGHT_MAX),
+            backlight: K_BACKLIGHT_MAX,
+            min_frame_interval: 0,
+            last_frame_tick: None,
+            custom_glyphs: [None; K_CUSTOM_GLYPH_COUNT],
         }
     }
 
@@ -86,18 +149,29 @@ impl Display {
     /// 3. Reads and stores the LCD size.
     /// 4. Stores the HAL reference and initializes the internal [`FrameBuffer`].
     /// 5. Locks the interface using `kernel_master_id`.
-    /// 6. Clears the display to `background_color`.
+    /// 6. Points both LCD layers to the frame buffers of this display.
+    /// 7. Clears the background layer to black and the foreground layer to `background_color`,
+    ///    which becomes the default background. The foreground layer is left active.
+    ///
+    /// Each display owns the [`K_FRAME_BUFFER_MEMORY_SIZE`] bytes starting at `fb_base`, the
+    /// memory of two displays must not overlap.
     ///
     /// # Parameters
     /// - `lcd_name`: Name of the LCD interface as known by the HAL.
     /// - `hal`: A mutable static reference to the HAL implementation.
-    /// - `background_color`: Color used to clear the display after initialization.
+    /// - `background_color`: Color used to clear the display after initialization, stored as
+    ///   the default background (see [`Display::set_background`]).
+    /// - `fb_base`: Address of the frame buffers memory, [`K_DEFAULT_FRAME_BUFFER_BASE`] for
+    ///   the primary display.
     ///
     /// # Returns
     /// - `Ok(())` if initialization succeeds.
     ///
     /// # Errors
-    /// - [`DisplayError::HalError`] if HAL operations fail (lookup, enable, size read, lock, clear).
+    /// - [`DisplayError::InvalidFrameBuffer`] if `fb_base` is not word-aligned or if the frame
+    ///   buffers memory would wrap around the address space. Nothing is done in this case.
+    /// - [`DisplayError::HalError`] if HAL operations fail (lookup, enable, size read, lock,
+    ///   layer address, clear).
     /// - Any error returned by [`Display::clear`] (propagated), such as
     ///   [`DisplayError::DisplayDriverNotInitialized`] (should not occur if init flow succeeds).
     pub fn init(
@@ -105,13 +179,18 @@ impl Display {
         p_lcd_name: &'static str,
         p_hal: &'static mut Hal,
         p_background_color: Colors,
+        p_fb_base: u32,
     ) -> DisplayResult<()> {
+        let l_frame_buffer =
+            FrameBuffer::new(p_fb_base).ok_or(DisplayError::InvalidFrameBuffer(p_fb_base))?;
+
         // Get LCD interface ID
         self.hal_id = Some(
             p_hal
                 .get_interface_id(p_lcd_name)
                 .map_err(DisplayError::HalError)?,
         );
+        self.name = Some(p_lcd_name);
 
         // Enable display
         p_hal
@@ -139,7 +218,11 @@ impl Display {
         self.hal = Some(p_hal);
 
         // Initialize the frame buffer
-        self.frame_buffer = Some(FrameBuffer::new());
+        let l_layer_addresses = [
+            (LcdLayer::FOREGROUND, l_frame_buffer.address_displayed()),
+            (LcdLayer::BACKGROUND, l_frame_buffer.address_background()),
+        ];
+        self.frame_buffer = Some(l_frame_buffer);
 
         // Mark the driver as initialized
         self.initialized = true;
@@ -151,16 +234,140 @@ impl Display {
             .lock_interface(self.hal_id.unwrap(), self.kernel_master_id)
             .map_err(DisplayError::HalError)?;
 
-        // Clean the buffer
+        // Show the frame buffers of this display
+        for (l_layer, l_address) in l_layer_addresses {
+            self.hal
+                .as_mut()
+                .unwrap()
+                .interface_write(
+                    self.hal_id.unwrap(),
+                    self.kernel_master_id,
+                    InterfaceWriteActions::Lcd(LcdActions::SetFbAddress(l_layer, l_address)),
+                )
+                .map_err(DisplayError::HalError)?;
+        }
+
+        // Clean both layers, the opaque foreground hides the background layer
+        self.active_layer = LcdLayer::BACKGROUND;
+        self.clear(Colors::Black)?;
+        self.active_layer = LcdLayer::FOREGROUND;
+        self.background = p_background_color;
         self.clear(p_background_color)?;
 
         Ok(())
     }
 
-    /// Clears the display and resets the cursor to `(0, 0)`.
+    /// Indicates whether the display has been initialized with [`Display::init`].
+    ///
+    /// # Returns
+    /// `true` if the driver is ready for drawing operations.
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    /// Returns the name of the LCD interface driven by this display.
+    ///
+    /// # Returns
+    /// The interface name given to [`Display::init`], or `None` before initialization.
+    pub fn get_name(&self) -> Option<&'static str> {
+        self.name
+    }
+
+    /// Returns the screen dimensions.
+    ///
+    /// # Returns
+    /// - `Ok((width, height))` in pixels.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    pub fn get_size(&self) -> DisplayResult<(u16, u16)> {
+        if self.initialized {
+            Ok(self.size.unwrap())
+        } else {
+            Err(DisplayError::DisplayDriverNotInitialized)
+        }
+    }
+
+    /// Returns the text cursor position.
+    ///
+    /// # Returns
+    /// - `Ok((x, y))` in pixels, the top-left corner of the next character cell.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    pub fn get_cursor_pos(&self) -> DisplayResult<(u16, u16)> {
+        if self.initialized {
+            Ok(self.cursor_pos)
+        } else {
+            Err(DisplayError::DisplayDriverNotInitialized)
+        }
+    }
+
+    /// Returns the active font used for text rendering.
+    ///
+    /// # Returns
+    /// The current [`FontSize`].
+    pub fn get_font(&self) -> FontSize {
+        self.font
+    }
+
+    /// Returns the default color used by drawing operations when `color: None` is provided.
+    ///
+    /// # Returns
+    /// The color set by [`Display::set_color`].
+    pub fn get_color(&self) -> Colors {
+        self.color
+    }
+
+    /// Returns the size of a character cell on screen, with the text scale applied.
+    ///
+    /// # Returns
+    /// `(width, height)` in pixels.
+    pub fn get_char_cell(&self) -> (u16, u16) {
+        let (l_width, l_height) = self.font.get_char_size();
+        (
+            l_width as u16 * self.text_scale.0 as u16,
+            l_height as u16 * self.text_scale.1 as u16,
+        )
+    }
+
+    /// Returns the size in pixels a string would occupy if drawn with the current font.
+    ///
+    /// The text scale is applied. Nothing is drawn. Each `'\n'` starts a new line: the width is the one of the longest
+    /// line and the height stacks all lines. An empty string measures one empty line.
+    ///
+    /// # Parameters
+    /// - `string`: ASCII string to measure.
+    ///
+    /// # Returns
+    /// - `Ok((width, height))` in pixels, saturated to `u16::MAX`.
+    ///
+    /// # Errors
+    /// - [`DisplayError::NonAsciiInput`] with the index of the first non-ASCII byte in `string`.
+    pub fn measure_string(&self, p_string: &str) -> DisplayResult<(u16, u16)> {
+        check_ascii(p_string)?;
+
+        // Fonts are monospaced, all glyphs advance by the character width
+        let (l_char_width, l_char_height) = self.get_char_cell();
+        let (l_max_len, l_lines) = p_string
+            .split('\n')
+            .fold((0usize, 0usize), |(l_max, l_count), l_line| {
+                (l_max.max(l_line.len()), l_count + 1)
+            });
+
+        let l_width = l_max_len.saturating_mul(l_char_width as usize);
+        let l_height = l_lines.saturating_mul(l_char_height as usize);
+        Ok((
+            u16::try_from(l_width).unwrap_or(u16::MAX),
+            u16::try_from(l_height).unwrap_or(u16::MAX),
+        ))
+    }
+
+    /// Clears the active layer and resets the cursor to `(0, 0)`.
     ///
     /// # Parameters
-    /// - `color`: Background color used to clear the foreground layer.
+    /// - `color`: Background color used to clear the active layer. Clearing the foreground
+    ///   layer with [`Colors::Transparent`] uncovers the background layer.
     ///
     /// # Returns
     /// - `Ok(())` if the display was cleared successfully.
@@ -177,11 +384,12 @@ impl Display {
                     self.hal_id.unwrap(),
                     self.kernel_master_id,
                     InterfaceWriteActions::Lcd(LcdActions::Clear(
-                        LcdLayer::FOREGROUND,
+                        self.active_layer,
                         p_color.to_argb(),
                     )),
                 )
                 .map_err(DisplayError::HalError)?;
+            self.mark_dirty(0, 0, self.size.unwrap().0, self.size.unwrap().1);
             self.cursor_pos = (0, 0);
             Ok(())
         } else {
@@ -189,24 +397,69 @@ impl Display {
         }
     }
 
+    /// Returns the base address of the frame buffer currently used for drawing.
+    ///
+    /// This is the buffer of the active layer written by the drawing methods, so pixels
+    /// written at this address appear on screen. The address changes on every [`Display::switch_frame_buffer`], it
+    /// must be queried again after each switch.
+    ///
+    /// # Safety
+    /// This method is safe by itself, but the returned address gives raw access to the
+    /// frame buffer memory. Writing through it bypasses all driver checks (bounds, dirty
+    /// region tracking, pixel format) and races with any other display user: callers must
+    /// stay within the frame buffer size and pixel format.
+    ///
+    /// # Returns
+    /// - `Ok(address)` with the frame buffer base address.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    pub fn displayed_fb_address(&self) -> DisplayResult<u32> {
+        if self.initialized {
+            Ok(self.layer_address())
+        } else {
+            Err(DisplayError::DisplayDriverNotInitialized)
+        }
+    }
+
     /// Switches the internal frame buffer and updates the LCD to display the new buffer.
     ///
     /// This uses the driver's [`FrameBuffer`] to flip buffers and then issues an LCD
-    /// command to set the framebuffer base address.
+    /// command to set the framebuffer base address. If the previous switch happened less
+    /// than the minimum frame interval ago (see [`Display::set_min_frame_interval`]), the
+    /// call returns early without flipping.
     ///
     /// # Returns
-    /// - `Ok(())` if the framebuffer address was successfully updated.
+    /// - `Ok(true)` if the framebuffer address was successfully updated.
+    /// - `Ok(false)` if the switch was skipped because of the minimum frame interval.
     ///
     /// # Errors
     /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    /// - [`DisplayError::InvalidFrameBuffer`] if the new frame buffer address does not lie in
+    ///   the foreground frame buffer region. The LCD controller is not updated.
     /// - [`DisplayError::HalError`] if the underlying HAL write fails.
-    pub fn switch_frame_buffer(&mut self) -> DisplayResult<()> {
+    pub fn switch_frame_buffer(&mut self) -> DisplayResult<bool> {
         // Returns error if not initialized
         if !self.initialized {
             return Err(DisplayError::DisplayDriverNotInitialized);
         }
 
-        let l_fb_addr = self.frame_buffer.as_mut().unwrap().switch();
+        // Throttle frame rate
+        let l_tick = self.hal.as_ref().unwrap().get_tick();
+        if let Some(l_last_tick) = self.last_frame_tick
+            && l_tick.wrapping_sub(l_last_tick) < self.min_frame_interval
+        {
+            return Ok(false);
+        }
+
+        let l_frame_buffer = self.frame_buffer.as_mut().unwrap();
+        let l_fb_addr = l_frame_buffer.switch();
+
+        // Never point the LCD controller outside the frame buffers
+        let (l_width, l_height) = self.size.unwrap();
+        if !l_frame_buffer.is_valid_address(l_fb_addr, l_width, l_height) {
+            return Err(DisplayError::InvalidFrameBuffer(l_fb_addr));
+        }
 
         self.hal
             .as_mut()
@@ -220,10 +473,152 @@ impl Display {
                 )),
             )
             .map_err(DisplayError::HalError)?;
+        self.mark_dirty(0, 0, self.size.unwrap().0, self.size.unwrap().1);
+        self.last_frame_tick = Some(l_tick);
+
+        Ok(true)
+    }
+
+    /// Selects the layer targeted by the clear and drawing methods.
+    ///
+    /// The LCD controller composites the two layers: the foreground layer is blended over the
+    /// background layer using the alpha channel of each foreground pixel, so the background
+    /// layer only shows through foreground pixels drawn with [`Colors::Transparent`]. All
+    /// other colors are opaque. A typical use is to draw static content once on the
+    /// background layer, then clear the foreground layer with [`Colors::Transparent`] and
+    /// draw the dynamic content on it.
+    ///
+    /// The background layer has a single frame buffer: [`Display::switch_frame_buffer`] only
+    /// flips the foreground layer buffers. Switching the layer keeps the text cursor.
+    ///
+    /// # Parameters
+    /// - `layer`: Layer to draw on.
+    ///
+    /// # Returns
+    /// - `Ok(())` always.
+    ///
+    /// # Errors
+    /// This function does not currently return errors.
+    pub fn set_active_layer(&mut self, p_layer: LcdLayer) -> DisplayResult<()> {
+        self.active_layer = p_layer;
+        Ok(())
+    }
+
+    /// Returns the layer targeted by the clear and drawing methods.
+    pub fn get_active_layer(&self) -> LcdLayer {
+        self.active_layer
+    }
+
+    /// Returns the base address of the frame buffer written for the active layer.
+    fn layer_address(&self) -> u32 {
+        match self.active_layer {
+            LcdLayer::FOREGROUND => self.frame_buffer.as_ref().unwrap().address_displayed(),
+            LcdLayer::BACKGROUND => self.frame_buffer.as_ref().unwrap().address_background(),
+        }
+    }
+
+    /// Sets the minimum delay between two frame buffer switches.
+    ///
+    /// Calls to [`Display::switch_frame_buffer`] occurring before this delay has elapsed
+    /// since the previous switch are skipped, which caps the display work regardless of how
+    /// often apps redraw.
+    ///
+    /// # Parameters
+    /// - `interval_ms`: Minimum delay in milliseconds. `0` disables throttling.
+    ///
+    /// # Returns
+    /// - `Ok(())` always.
+    ///
+    /// # Errors
+    /// This function does not currently return errors.
+    pub fn set_min_frame_interval(&mut self, p_interval_ms: u32) -> DisplayResult<()> {
+        self.min_frame_interval = p_interval_ms;
+        Ok(())
+    }
+
+    /// Transfers the region modified since the last flush to the panel.
+    ///
+    /// Drawing operations record the bounding box of the pixels they modify. This function
+    /// issues an LCD refresh for that box only, then clears it. When the panel scans the
+    /// frame buffer by itself (see [`Display::set_flush_required`]), no HAL request is issued
+    /// and the dirty region is simply discarded.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the region was flushed or nothing needed to be flushed.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    /// - [`DisplayError::HalError`] if the underlying HAL write fails.
+    pub fn flush(&mut self) -> DisplayResult<()> {
+        // Returns error if not initialized
+        if !self.initialized {
+            return Err(DisplayError::DisplayDriverNotInitialized);
+        }
+
+        let l_dirty = self.frame_buffer.as_mut().unwrap().take_dirty();
+
+        if let Some((l_x, l_y, l_width, l_height)) = l_dirty
+            && self.flush_required
+        {
+            self.hal
+                .as_mut()
+                .unwrap()
+                .interface_write(
+                    self.hal_id.unwrap(),
+                    self.kernel_master_id,
+                    InterfaceWriteActions::Lcd(LcdActions::Refresh(
+                        LcdLayer::FOREGROUND,
+                        LcdArea {
+                            x: l_x,
+                            y: l_y,
+                            width: l_width,
+                            height: l_height,
+                        },
+                    )),
+                )
+                .map_err(DisplayError::HalError)?;
+        }
+
+        Ok(())
+    }
 
+    /// Declares whether the panel requires explicit flushes to display frame buffer changes.
+    ///
+    /// # Parameters
+    /// - `flush_required`: `true` if [`Display::flush`] must issue LCD refresh requests,
+    ///   `false` for memory-mapped panels continuously scanning the frame buffer.
+    ///
+    /// # Returns
+    /// - `Ok(())` always.
+    ///
+    /// # Errors
+    /// This function does not currently return errors.
+    pub fn set_flush_required(&mut self, p_flush_required: bool) -> DisplayResult<()> {
+        self.flush_required = p_flush_required;
         Ok(())
     }
 
+    /// Adds a rectangle to the dirty region, clipped to the screen size.
+    ///
+    /// # Parameters
+    /// - `x`: X coordinate of the top-left corner in pixels.
+    /// - `y`: Y coordinate of the top-left corner in pixels.
+    /// - `width`: Width of the rectangle in pixels.
+    /// - `height`: Height of the rectangle in pixels.
+    fn mark_dirty(&mut self, p_x: u16, p_y: u16, p_width: u16, p_height: u16) {
+        let (l_screen_x, l_screen_y) = self.size.unwrap();
+        if p_x >= l_screen_x || p_y >= l_screen_y {
+            return;
+        }
+
+        self.frame_buffer.as_mut().unwrap().mark_dirty(
+            p_x,
+            p_y,
+            p_width.min(l_screen_x - p_x),
+            p_height.min(l_screen_y - p_y),
+        );
+    }
+
     /// Draws an ASCII string at the provided pixel coordinates into the current frame buffer.
     ///
     /// Each character is rendered using the current [`FontSize`]. The provided `x`/`y`
@@ -231,7 +626,8 @@ impl Display {
     ///
     /// # Parameters
     /// - `string`: UTF-8 string whose bytes are interpreted as ASCII codes.
-    ///   Characters outside the supported ASCII range cause an error.
+    ///   The string is checked before drawing: non-ASCII input is rejected and nothing
+    ///   is drawn.
     /// - `x`: X coordinate in pixels of the first character.
     /// - `y`: Y coordinate in pixels of the first character.
     /// - `color`: Optional override color. If `None`, the current default color
@@ -242,8 +638,10 @@ impl Display {
     ///
     /// # Errors
     /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    /// - [`DisplayError::NonAsciiInput`] with the index of the first non-ASCII byte (e.g.
+    ///   part of a multi-byte UTF-8 character) in `string`.
     /// - [`DisplayError::UnknownCharacter`] if any byte in `string` is outside
-    ///   `FIRST_ASCII_CHAR..=LAST_ASCII_CHAR`.
+    ///   the character range of the current font.
     /// - Any error propagated from internal drawing routines.
     pub fn draw_string(
         &mut self,
@@ -257,8 +655,12 @@ impl Display {
             return Err(DisplayError::DisplayDriverNotInitialized);
         }
 
+        // Reject non-ASCII input before drawing anything
+        check_ascii(p_string)?;
+
         // Initialize variables
         let l_char_size = self.font.get_char_size();
+        let l_char_cell = self.get_char_cell();
         let mut l_current_x = p_x;
 
         // Get display color
@@ -269,8 +671,8 @@ impl Display {
         };
 
         // Compute frame buffer address
-        let mut l_fb_write_address = self.frame_buffer.as_mut().unwrap().address_displayed()
-            + 4 * (p_y as u32 * self.size.unwrap().0 as u32 + p_x as u32);
+        let mut l_fb_write_address =
+            self.layer_address() + 4 * (p_y as u32 * self.size.unwrap().0 as u32 + p_x as u32);
 
         for l_char_to_display in p_string.as_bytes() {
             self.draw_char_in_fb(
@@ -278,208 +680,1073 @@ impl Display {
                 l_fb_write_address,
                 l_char_size,
                 l_color_argb,
+                0,
             )?;
 
             // Compute next char position
-            l_current_x += l_char_size.0 as u16;
+            l_current_x += l_char_cell.0;
             // Increment frame buffer address
-            l_fb_write_address = self.frame_buffer.as_mut().unwrap().address_displayed()
+            l_fb_write_address = self.layer_address()
                 + 4 * (p_y as u32 * self.size.unwrap().0 as u32 + l_current_x as u32);
         }
 
+        // Record modified area
+        self.mark_dirty(p_x, p_y, l_current_x - p_x, l_char_cell.1);
+
         Ok(())
     }
 
-    /// Draws a single ASCII character at the provided pixel coordinates into the current frame buffer.
+    /// Draws an ASCII string over a background color, with a text color readable on it.
+    ///
+    /// The text color is [`Colors::contrasting`] of `bg`. Each character cell is filled with
+    /// `bg`, as with [`Display::draw_char_bg`].
     ///
     /// # Parameters
-    /// - `char_to_display`: ASCII byte to render.
-    /// - `x`: X coordinate in pixels of the character's top-left corner.
-    /// - `y`: Y coordinate in pixels of the character's top-left corner.
-    /// - `color`: Optional override color. If `None`, the current default color
-    ///   set by [`Display::set_color`] is used.
+    /// - `string`: ASCII string to draw. The string is checked before drawing: non-ASCII
+    ///   input is rejected and nothing is drawn.
+    /// - `x`: X coordinate in pixels of the first character.
+    /// - `y`: Y coordinate in pixels of the first character.
+    /// - `bg`: Background color of the text.
     ///
     /// # Returns
-    /// - `Ok(())` if the character was drawn successfully.
+    /// - `Ok(())` if all characters were drawn successfully.
     ///
     /// # Errors
     /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
-    /// - [`DisplayError::UnknownCharacter`] if `char_to_display` is outside
-    ///   `FIRST_ASCII_CHAR..=LAST_ASCII_CHAR`.
-    pub fn draw_char(
+    /// - [`DisplayError::NonAsciiInput`] with the index of the first non-ASCII byte in `string`.
+    /// - Any error returned by [`Display::draw_char_bg`] (propagated).
+    pub fn draw_string_auto_contrast(
         &mut self,
-        p_char_to_display: u8,
+        p_string: &str,
         p_x: u16,
         p_y: u16,
-        p_color: Option<Colors>,
+        p_bg: Colors,
     ) -> DisplayResult<()> {
         // Returns error if not initialized
         if !self.initialized {
             return Err(DisplayError::DisplayDriverNotInitialized);
         }
 
-        let l_char_size = self.font.get_char_size();
-
-        // Get display color
-        let l_color_argb = if let Some(l_c) = p_color {
-            l_c.to_argb().as_u32()
-        } else {
-            self.color.to_argb().as_u32()
-        };
-
-        // Compute frame buffer address
-        let l_fb_write_address = self.frame_buffer.as_mut().unwrap().address_displayed()
-            + 4 * (p_y as u32 * self.size.unwrap().0 as u32 + p_x as u32);
+        // Reject non-ASCII input before drawing anything
+        check_ascii(p_string)?;
 
-        // Draw char in fb
-        self.draw_char_in_fb(
-            p_char_to_display,
-            l_fb_write_address,
-            l_char_size,
-            l_color_argb,
-        )?;
+        let l_fg = p_bg.contrasting();
+        let l_advance = self.get_char_cell().0;
+        let mut l_current_x = p_x;
+        for l_char_to_display in p_string.bytes() {
+            self.draw_char_bg(l_char_to_display, l_current_x, p_y, l_fg, p_bg)?;
+            l_current_x += l_advance;
+        }
 
         Ok(())
     }
 
-    /// Renders a single ASCII character glyph directly into the frame buffer memory.
+    /// Draws several strings, each on its own line, into the current frame buffer.
     ///
-    /// This is an internal routine used by [`Display::draw_char`] and [`Display::draw_string`].
+    /// Line `i` is drawn at `y + i * h`, where `h` is the character height of the current
+    /// [`FontSize`], text scale applied. All lines are checked before drawing: if any line is not ASCII or if the
+    /// last line would run off the bottom of the screen, nothing is drawn.
     ///
     /// # Parameters
-    /// - `char_to_display`: ASCII byte to render.
-    /// - `fb_write_address`: Base address (in bytes) of the top-left pixel of the character
-    ///   within the currently displayed frame buffer. The routine writes 32-bit ARGB pixels.
-    /// - `char_size`: `(width, height)` in pixels for the current font glyph.
-    /// - `color_argb`: Pixel color written for "set" glyph pixels, encoded as ARGB `u32`.
-    ///   Unset pixels are written as `0`.
+    /// - `lines`: Strings to draw, from top to bottom.
+    /// - `x`: X coordinate in pixels of the first character of each line.
+    /// - `y`: Y coordinate in pixels of the first line.
+    /// - `color`: Optional override color. If `None`, the current default color
+    ///   set by [`Display::set_color`] is used.
     ///
     /// # Returns
-    /// - `Ok(())` if the glyph was written successfully.
+    /// - `Ok(())` if all lines were drawn successfully.
     ///
     /// # Errors
-    /// - [`DisplayError::UnknownCharacter`] if `char_to_display` is outside
-    ///   `FIRST_ASCII_CHAR..=LAST_ASCII_CHAR`.
-    ///
-    /// # Safety
-    /// This function performs raw pointer writes into the frame buffer memory.
-    fn draw_char_in_fb(
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    /// - [`DisplayError::NonAsciiInput`] if any line contains a non-ASCII byte.
+    /// - [`DisplayError::OutOfScreenBounds`] if the lines do not fit above the bottom of the
+    ///   screen.
+    /// - Any error returned by [`Display::draw_string`] (propagated).
+    pub fn draw_lines(
         &mut self,
-        p_char_to_display: u8,
-        mut p_fb_write_address: u32,
-        p_char_size: (u8, u8),
-        p_color_argb: u32,
+        p_lines: &[&str],
+        p_x: u16,
+        p_y: u16,
+        p_color: Option<Colors>,
     ) -> DisplayResult<()> {
-        // Check if the character to display is valid
-        if !(K_FIRST_ASCII_CHAR..=K_LAST_ASCII_CHAR).contains(&p_char_to_display) {
-            return Err(DisplayError::UnknownCharacter(p_char_to_display));
-        } else {
-            // Display chat at the current position
-            for l_line in 0..p_char_size.1 {
-                for l_col in 0..p_char_size.0 {
-                    if self.font.is_pixel_set(p_char_to_display, l_col, l_line) {
-                        unsafe {
-                            *(p_fb_write_address as *mut u32) = p_color_argb;
-                        }
-                    } else {
-                        unsafe {
-                            *(p_fb_write_address as *mut u32) = 0;
-                        }
-                    }
+        // Returns error if not initialized
+        if !self.initialized {
+            return Err(DisplayError::DisplayDriverNotInitialized);
+        }
 
-                    // Increment frame buffer address
-                    p_fb_write_address += 4;
-                }
+        // Check all lines before drawing anything
+        for l_line in p_lines {
+            check_ascii(l_line)?;
+        }
+        let l_line_height = self.get_char_cell().1 as u32;
+        if p_y as u32 + p_lines.len() as u32 * l_line_height > self.size.unwrap().1 as u32 {
+            return Err(DisplayError::OutOfScreenBounds);
+        }
 
-                // Increment frame buffer address
-                p_fb_write_address += self.size.unwrap().0 as u32 * 4 - p_char_size.0 as u32 * 4;
-            }
+        for (l_index, l_line) in p_lines.iter().enumerate() {
+            self.draw_string(
+                l_line,
+                p_x,
+                p_y + (l_index as u32 * l_line_height) as u16,
+                p_color,
+            )?;
         }
 
         Ok(())
     }
 
-    /// Draws a string starting at the current cursor position.
-    ///
-    /// For each byte in `string`:
-    /// - `\n` advances the cursor to the next line (line feed).
-    /// - `\r` returns the cursor to the start of the current line (carriage return).
-    /// - Any other byte is drawn as an ASCII glyph at the cursor and the cursor is advanced.
+    /// Draws a filled rectangle into the current frame buffer.
     ///
     /// # Parameters
-    /// - `string`: UTF-8 string whose bytes are interpreted as ASCII codes.
-    /// - `color`: Optional override color for all characters. If `None`, the current
-    ///   default color is used.
+    /// - `x`: X coordinate of the top-left corner in pixels.
+    /// - `y`: Y coordinate of the top-left corner in pixels.
+    /// - `width`: Width of the rectangle in pixels.
+    /// - `height`: Height of the rectangle in pixels.
+    /// - `color`: Optional override color. If `None`, the current default color
+    ///   set by [`Display::set_color`] is used.
     ///
     /// # Returns
-    /// - `Ok(())` if the entire string was processed successfully.
+    /// - `Ok(())` if the rectangle was drawn.
     ///
     /// # Errors
     /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
-    /// - [`DisplayError::UnknownCharacter`] if any non-control byte is outside the supported
+    /// - [`DisplayError::OutOfScreenBounds`] if the rectangle does not fit on the screen.
+    pub fn draw_rect(
+        &mut self,
+        p_x: u16,
+        p_y: u16,
+        p_width: u16,
+        p_height: u16,
+        p_color: Option<Colors>,
+    ) -> DisplayResult<()> {
+        // Returns error if not initialized
+        if !self.initialized {
+            return Err(DisplayError::DisplayDriverNotInitialized);
+        }
+
+        // Check bounds
+        let (l_screen_x, l_screen_y) = self.size.unwrap();
+        if p_x as u32 + p_width as u32 > l_screen_x as u32
+            || p_y as u32 + p_height as u32 > l_screen_y as u32
+        {
+            return Err(DisplayError::OutOfScreenBounds);
+        }
+
+        // Get display color
+        let l_color_argb = p_color.unwrap_or(self.color).to_argb().as_u32();
+
+        // Fill the rectangle line by line
+        let l_fb_address = self.layer_address();
+        for l_line in p_y..p_y + p_height {
+            let l_line_address = l_fb_address + 4 * (l_line as u32 * l_screen_x as u32);
+            for l_col in p_x..p_x + p_width {
+                unsafe {
+                    *((l_line_address + 4 * l_col as u32) as *mut u32) = l_color_argb;
+                }
+            }
+        }
+
+        // Record modified area
+        self.mark_dirty(p_x, p_y, p_width, p_height);
+
+        Ok(())
+    }
+
+    /// Sets a single pixel of the active layer.
+    ///
+    /// Meant for plotting isolated points. Filling areas pixel by pixel is slow, especially
+    /// through the display syscall, prefer [`Display::draw_rect`] or
+    /// [`Display::restore_region`] for bulk drawing.
+    ///
+    /// # Parameters
+    /// - `x`: X coordinate of the pixel.
+    /// - `y`: Y coordinate of the pixel.
+    /// - `color`: Color of the pixel.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the pixel was written.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    /// - [`DisplayError::OutOfScreenBounds`] if the pixel is outside of the screen.
+    pub fn set_pixel(&mut self, p_x: u16, p_y: u16, p_color: Colors) -> DisplayResult<()> {
+        // Returns error if not initialized
+        if !self.initialized {
+            return Err(DisplayError::DisplayDriverNotInitialized);
+        }
+
+        // Check bounds
+        let (l_screen_x, l_screen_y) = self.size.unwrap();
+        if p_x >= l_screen_x || p_y >= l_screen_y {
+            return Err(DisplayError::OutOfScreenBounds);
+        }
+
+        let l_address = self.layer_address() + 4 * (p_y as u32 * l_screen_x as u32 + p_x as u32);
+        unsafe {
+            *(l_address as *mut u32) = p_color.to_argb().as_u32();
+        }
+
+        // Record modified area
+        self.mark_dirty(p_x, p_y, 1, 1);
+
+        Ok(())
+    }
+
+    /// Draws a rectangle with rounded corners, either as a 1-pixel border or filled.
+    ///
+    /// Each corner is a quarter circle of the given radius, computed per row with integer
+    /// math. Corner pixels outside the radius are left untouched. A radius of `0` draws a
+    /// plain rectangle.
+    ///
+    /// # Parameters
+    /// - `x`: X coordinate of the top-left corner of the bounding box in pixels.
+    /// - `y`: Y coordinate of the top-left corner of the bounding box in pixels.
+    /// - `width`: Width of the bounding box in pixels.
+    /// - `height`: Height of the bounding box in pixels.
+    /// - `radius`: Radius of the corners in pixels.
+    /// - `color`: Optional override color. If `None`, the current default color
+    ///   set by [`Display::set_color`] is used.
+    /// - `filled`: `true` to fill the interior, `false` to draw the border only.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the rectangle was drawn.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    /// - [`DisplayError::InvalidRadius`] if `2 * radius` exceeds the width or the height.
+    /// - [`DisplayError::OutOfScreenBounds`] if the rectangle does not fit on the screen.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_rounded_rect(
+        &mut self,
+        p_x: u16,
+        p_y: u16,
+        p_width: u16,
+        p_height: u16,
+        p_radius: u16,
+        p_color: Option<Colors>,
+        p_filled: bool,
+    ) -> DisplayResult<()> {
+        // Returns error if not initialized
+        if !self.initialized {
+            return Err(DisplayError::DisplayDriverNotInitialized);
+        }
+
+        if 2 * p_radius as u32 > p_width.min(p_height) as u32 {
+            return Err(DisplayError::InvalidRadius(p_radius));
+        }
+
+        // Check bounds
+        let (l_screen_x, l_screen_y) = self.size.unwrap();
+        if p_x as u32 + p_width as u32 > l_screen_x as u32
+            || p_y as u32 + p_height as u32 > l_screen_y as u32
+        {
+            return Err(DisplayError::OutOfScreenBounds);
+        }
+
+        if p_width == 0 || p_height == 0 {
+            return Ok(());
+        }
+
+        // Get display color
+        let l_color_argb = p_color.unwrap_or(self.color).to_argb().as_u32();
+
+        let l_radius = p_radius as u32;
+        let l_inset = |p_row: u32| corner_inset(l_radius, p_row);
+
+        let l_fb_address = self.layer_address();
+        let l_width = p_width as u32;
+        for l_line in 0..p_height as u32 {
+            let l_row = l_line.min(p_height as u32 - 1 - l_line);
+            let l_start = l_inset(l_row);
+            let l_span = l_width - 2 * l_start;
+
+            let l_line_address =
+                l_fb_address + 4 * ((p_y as u32 + l_line) * l_screen_x as u32 + p_x as u32);
+            let l_fill = |p_from: u32, p_to: u32| {
+                for l_col in p_from..p_to {
+                    unsafe {
+                        *((l_line_address + 4 * l_col) as *mut u32) = l_color_argb;
+                    }
+                }
+            };
+
+            if p_filled || l_row == 0 {
+                l_fill(l_start, l_start + l_span);
+            } else {
+                // Border pixels reach the inset of the previous row, so the arc has no gap
+                let l_run = l_inset(l_row - 1)
+                    .saturating_sub(l_start)
+                    .clamp(1, l_span.div_ceil(2));
+                l_fill(l_start, l_start + l_run);
+                l_fill(l_start + l_span - l_run, l_start + l_span);
+            }
+        }
+
+        // Record modified area
+        self.mark_dirty(p_x, p_y, p_width, p_height);
+
+        Ok(())
+    }
+
+    /// Fills the whole screen with a checkerboard of two colors.
+    ///
+    /// Squares of `cell`×`cell` pixels alternate in both directions, starting with `color_a`
+    /// in the top-left corner. Squares on the right and bottom edges are cut by the screen
+    /// border. Meant for panel testing: dead pixels and color errors stand out on the regular
+    /// pattern.
+    ///
+    /// # Parameters
+    /// - `cell`: Side of a square in pixels.
+    /// - `color_a`: Color of the top-left square.
+    /// - `color_b`: Color of the other squares.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the checkerboard was drawn.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    /// - [`DisplayError::InvalidCellSize`] if `cell` is 0.
+    pub fn draw_checkerboard(
+        &mut self,
+        p_cell: u16,
+        p_color_a: Colors,
+        p_color_b: Colors,
+    ) -> DisplayResult<()> {
+        // Returns error if not initialized
+        if !self.initialized {
+            return Err(DisplayError::DisplayDriverNotInitialized);
+        }
+
+        if p_cell == 0 {
+            return Err(DisplayError::InvalidCellSize(p_cell));
+        }
+
+        let (l_screen_x, l_screen_y) = self.size.unwrap();
+        for l_y in (0..l_screen_y).step_by(p_cell as usize) {
+            for l_x in (0..l_screen_x).step_by(p_cell as usize) {
+                let l_color = if (l_x / p_cell + l_y / p_cell).is_multiple_of(2) {
+                    p_color_a
+                } else {
+                    p_color_b
+                };
+                self.draw_rect(
+                    l_x,
+                    l_y,
+                    p_cell.min(l_screen_x - l_x),
+                    p_cell.min(l_screen_y - l_y),
+                    Some(l_color),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fills a rectangle with a linear gradient between two colors.
+    ///
+    /// The color is interpolated per channel with integer math: the first column (or row) is
+    /// drawn with `from`, the last one with `to`.
+    ///
+    /// # Parameters
+    /// - `x`: X coordinate of the top-left corner in pixels.
+    /// - `y`: Y coordinate of the top-left corner in pixels.
+    /// - `width`: Width of the rectangle in pixels.
+    /// - `height`: Height of the rectangle in pixels.
+    /// - `from`: Color of the left column or top row.
+    /// - `to`: Color of the right column or bottom row.
+    /// - `direction`: [`GradientDir::Horizontal`] interpolates per column,
+    ///   [`GradientDir::Vertical`] per row.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the gradient was drawn.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    /// - [`DisplayError::OutOfScreenBounds`] if the rectangle does not fit on the screen.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_gradient(
+        &mut self,
+        p_x: u16,
+        p_y: u16,
+        p_width: u16,
+        p_height: u16,
+        p_from: Colors,
+        p_to: Colors,
+        p_direction: GradientDir,
+    ) -> DisplayResult<()> {
+        // Returns error if not initialized
+        if !self.initialized {
+            return Err(DisplayError::DisplayDriverNotInitialized);
+        }
+
+        // Check bounds
+        let (l_screen_x, l_screen_y) = self.size.unwrap();
+        if p_x as u32 + p_width as u32 > l_screen_x as u32
+            || p_y as u32 + p_height as u32 > l_screen_y as u32
+        {
+            return Err(DisplayError::OutOfScreenBounds);
+        }
+
+        let l_from = p_from.to_argb().as_u32();
+        let l_to = p_to.to_argb().as_u32();
+        let l_steps = match p_direction {
+            GradientDir::Horizontal => p_width.saturating_sub(1),
+            GradientDir::Vertical => p_height.saturating_sub(1),
+        } as u32;
+
+        // Fill the rectangle line by line
+        let l_fb_address = self.layer_address();
+        for l_line in p_y..p_y + p_height {
+            let l_line_address = l_fb_address + 4 * (l_line as u32 * l_screen_x as u32);
+            for l_col in p_x..p_x + p_width {
+                let l_pos = match p_direction {
+                    GradientDir::Horizontal => l_col - p_x,
+                    GradientDir::Vertical => l_line - p_y,
+                } as u32;
+                unsafe {
+                    *((l_line_address + 4 * l_col as u32) as *mut u32) =
+                        interpolate_argb(l_from, l_to, l_pos, l_steps);
+                }
+            }
+        }
+
+        // Record modified area
+        self.mark_dirty(p_x, p_y, p_width, p_height);
+
+        Ok(())
+    }
+
+    /// Copies a rectangular region of the current frame buffer into a caller-provided buffer.
+    ///
+    /// Pixels are stored row by row as ARGB8888 values, so `buf` holds `width * height` entries.
+    /// Combined with [`Display::restore_region`], this saves what lies under a transient overlay.
+    ///
+    /// # Parameters
+    /// - `x`: X coordinate of the top-left corner in pixels.
+    /// - `y`: Y coordinate of the top-left corner in pixels.
+    /// - `width`: Width of the region in pixels.
+    /// - `height`: Height of the region in pixels.
+    /// - `buf`: Destination buffer, must hold exactly `width * height` pixels.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the region was copied.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    /// - [`DisplayError::OutOfScreenBounds`] if the region does not fit on the screen.
+    /// - [`DisplayError::BufferSizeMismatch`] if `buf.len()` differs from `width * height`.
+    pub fn save_region(
+        &mut self,
+        p_x: u16,
+        p_y: u16,
+        p_width: u16,
+        p_height: u16,
+        p_buf: &mut [u32],
+    ) -> DisplayResult<()> {
+        self.check_region(p_x, p_y, p_width, p_height, p_buf.len())?;
+
+        let l_screen_x = self.size.unwrap().0 as u32;
+        let l_fb_address = self.layer_address();
+        for (l_row, l_line) in (p_y..p_y + p_height).enumerate() {
+            let l_line_address = l_fb_address + 4 * (l_line as u32 * l_screen_x + p_x as u32);
+            let l_row_buf = &mut p_buf[l_row * p_width as usize..(l_row + 1) * p_width as usize];
+            for (l_col, l_pixel) in l_row_buf.iter_mut().enumerate() {
+                unsafe {
+                    *l_pixel = *((l_line_address + 4 * l_col as u32) as *const u32);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes back a rectangular region previously saved with [`Display::save_region`].
+    ///
+    /// # Parameters
+    /// - `x`: X coordinate of the top-left corner in pixels.
+    /// - `y`: Y coordinate of the top-left corner in pixels.
+    /// - `width`: Width of the region in pixels.
+    /// - `height`: Height of the region in pixels.
+    /// - `buf`: Source buffer of `width * height` ARGB8888 pixels, row by row.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the region was restored.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    /// - [`DisplayError::OutOfScreenBounds`] if the region does not fit on the screen.
+    /// - [`DisplayError::BufferSizeMismatch`] if `buf.len()` differs from `width * height`.
+    pub fn restore_region(
+        &mut self,
+        p_x: u16,
+        p_y: u16,
+        p_width: u16,
+        p_height: u16,
+        p_buf: &[u32],
+    ) -> DisplayResult<()> {
+        self.check_region(p_x, p_y, p_width, p_height, p_buf.len())?;
+
+        let l_screen_x = self.size.unwrap().0 as u32;
+        let l_fb_address = self.layer_address();
+        for (l_row, l_line) in (p_y..p_y + p_height).enumerate() {
+            let l_line_address = l_fb_address + 4 * (l_line as u32 * l_screen_x + p_x as u32);
+            let l_row_buf = &p_buf[l_row * p_width as usize..(l_row + 1) * p_width as usize];
+            for (l_col, l_pixel) in l_row_buf.iter().enumerate() {
+                unsafe {
+                    *((l_line_address + 4 * l_col as u32) as *mut u32) = *l_pixel;
+                }
+            }
+        }
+
+        // Record modified area
+        self.mark_dirty(p_x, p_y, p_width, p_height);
+
+        Ok(())
+    }
+
+    /// Checks that a region and its pixel buffer can be used by the region copy methods.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: Top-left corner of the region in pixels.
+    /// - `width`, `height`: Size of the region in pixels.
+    /// - `buf_len`: Number of pixels in the caller buffer.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    /// - [`DisplayError::OutOfScreenBounds`] if the region does not fit on the screen.
+    /// - [`DisplayError::BufferSizeMismatch`] if `buf_len` differs from `width * height`.
+    fn check_region(
+        &self,
+        p_x: u16,
+        p_y: u16,
+        p_width: u16,
+        p_height: u16,
+        p_buf_len: usize,
+    ) -> DisplayResult<()> {
+        if !self.initialized {
+            return Err(DisplayError::DisplayDriverNotInitialized);
+        }
+
+        let (l_screen_x, l_screen_y) = self.size.unwrap();
+        if p_x as u32 + p_width as u32 > l_screen_x as u32
+            || p_y as u32 + p_height as u32 > l_screen_y as u32
+        {
+            return Err(DisplayError::OutOfScreenBounds);
+        }
+
+        let l_expected = p_width as usize * p_height as usize;
+        if p_buf_len != l_expected {
+            return Err(DisplayError::BufferSizeMismatch(l_expected, p_buf_len));
+        }
+
+        Ok(())
+    }
+
+    /// Draws a bordered box filled with word-wrapped text.
+    ///
+    /// The box is filled with `bg` and surrounded by a 1-pixel `border`. The text is rendered
+    /// with the current [`FontSize`] inside the border, starting at the top-left corner of the
+    /// interior. Lines are wrapped at spaces, words longer than a line are split, and `\n`
+    /// starts a new line. Text that does not fit in the interior height is clipped.
+    ///
+    /// # Parameters
+    /// - `x`: X coordinate of the top-left corner of the box in pixels.
+    /// - `y`: Y coordinate of the top-left corner of the box in pixels.
+    /// - `width`: Width of the box in pixels, border included.
+    /// - `height`: Height of the box in pixels, border included.
+    /// - `text`: ASCII text to render. The string is checked before drawing: non-ASCII
+    ///   input is rejected and nothing is drawn.
+    /// - `fg`: Color of the text.
+    /// - `bg`: Color of the box interior and of the text background.
+    /// - `border`: Color of the border.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the box was drawn, even if part of the text was clipped.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    /// - [`DisplayError::OutOfScreenBounds`] if the box does not fit on the screen.
+    /// - [`DisplayError::NonAsciiInput`] with the index of the first non-ASCII byte in `text`.
+    /// - [`DisplayError::UnknownCharacter`] if a byte of `text` is outside
+    ///   the character range of the current font.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_text_box(
+        &mut self,
+        p_x: u16,
+        p_y: u16,
+        p_width: u16,
+        p_height: u16,
+        p_text: &str,
+        p_fg: Colors,
+        p_bg: Colors,
+        p_border: Colors,
+    ) -> DisplayResult<()> {
+        // Reject non-ASCII input before drawing anything
+        check_ascii(p_text)?;
+
+        // Border, also checks initialization and screen bounds
+        self.draw_rect(p_x, p_y, p_width, p_height, Some(p_border))?;
+        if p_width <= 2 || p_height <= 2 {
+            return Ok(());
+        }
+        self.draw_rect(p_x + 1, p_y + 1, p_width - 2, p_height - 2, Some(p_bg))?;
+
+        // Compute text area in characters
+        let l_char_size = self.font.get_char_size();
+        let l_char_cell = self.get_char_cell();
+        let l_cols = ((p_width - 2) / l_char_cell.0) as usize;
+        let l_rows = (p_height - 2) / l_char_cell.1;
+        if l_cols == 0 {
+            return Ok(());
+        }
+
+        let l_fg_argb = p_fg.to_argb().as_u32();
+        let l_bg_argb = p_bg.to_argb().as_u32();
+        let l_screen_width = self.size.unwrap().0 as u32;
+
+        // Render lines until the text or the box height is exhausted
+        let mut l_remaining = p_text;
+        for l_row in 0..l_rows {
+            if l_remaining.is_empty() {
+                break;
+            }
+            let (l_line, l_rest) = wrap_line(l_remaining, l_cols);
+            l_remaining = l_rest;
+
+            let l_line_y = p_y as u32 + 1 + l_row as u32 * l_char_cell.1 as u32;
+            for (l_index, l_char) in l_line.as_bytes().iter().enumerate() {
+                let l_char_x = p_x as u32 + 1 + l_index as u32 * l_char_cell.0 as u32;
+                let l_fb_write_address =
+                    self.layer_address() + 4 * (l_line_y * l_screen_width + l_char_x);
+                self.draw_char_in_fb(
+                    *l_char,
+                    l_fb_write_address,
+                    l_char_size,
+                    l_fg_argb,
+                    l_bg_argb,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Draws a single ASCII character at the provided pixel coordinates into the current frame buffer.
+    ///
+    /// # Parameters
+    /// - `char_to_display`: ASCII byte to render.
+    /// - `x`: X coordinate in pixels of the character's top-left corner.
+    /// - `y`: Y coordinate in pixels of the character's top-left corner.
+    /// - `color`: Optional override color. If `None`, the current default color
+    ///   set by [`Display::set_color`] is used.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the character was drawn successfully.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    /// - [`DisplayError::UnknownCharacter`] if `char_to_display` is outside
+    ///   the character range of the current font.
+    pub fn draw_char(
+        &mut self,
+        p_char_to_display: u8,
+        p_x: u16,
+        p_y: u16,
+        p_color: Option<Colors>,
+    ) -> DisplayResult<()> {
+        // Returns error if not initialized
+        if !self.initialized {
+            return Err(DisplayError::DisplayDriverNotInitialized);
+        }
+
+        let l_char_size = self.font.get_char_size();
+
+        // Get display color
+        let l_color_argb = if let Some(l_c) = p_color {
+            l_c.to_argb().as_u32()
+        } else {
+            self.color.to_argb().as_u32()
+        };
+
+        // Compute frame buffer address
+        let l_fb_write_address =
+            self.layer_address() + 4 * (p_y as u32 * self.size.unwrap().0 as u32 + p_x as u32);
+
+        // Draw char in fb
+        self.draw_char_in_fb(
+            p_char_to_display,
+            l_fb_write_address,
+            l_char_size,
+            l_color_argb,
+            0,
+        )?;
+
+        // Record modified area
+        let l_char_cell = self.get_char_cell();
+        self.mark_dirty(p_x, p_y, l_char_cell.0, l_char_cell.1);
+
+        Ok(())
+    }
+
+    /// Draws a single ASCII character with an explicit background color.
+    ///
+    /// Unlike [`Display::draw_char`], which writes unset glyph pixels as black, this writes
+    /// `bg` for every unset pixel of the glyph cell. Swapping the text and background colors
+    /// gives highlighted (inverse) text.
+    ///
+    /// # Parameters
+    /// - `char_to_display`: ASCII byte to render.
+    /// - `x`: X coordinate in pixels of the character's top-left corner.
+    /// - `y`: Y coordinate in pixels of the character's top-left corner.
+    /// - `fg`: Color of the set glyph pixels.
+    /// - `bg`: Color of the unset glyph pixels.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the character was drawn successfully.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    /// - [`DisplayError::UnknownCharacter`] if `char_to_display` is outside
+    ///   the character range of the current font.
+    pub fn draw_char_bg(
+        &mut self,
+        p_char_to_display: u8,
+        p_x: u16,
+        p_y: u16,
+        p_fg: Colors,
+        p_bg: Colors,
+    ) -> DisplayResult<()> {
+        // Returns error if not initialized
+        if !self.initialized {
+            return Err(DisplayError::DisplayDriverNotInitialized);
+        }
+
+        let l_char_size = self.font.get_char_size();
+
+        // Compute frame buffer address
+        let l_fb_write_address =
+            self.layer_address() + 4 * (p_y as u32 * self.size.unwrap().0 as u32 + p_x as u32);
+
+        // Draw char in fb
+        self.draw_char_in_fb(
+            p_char_to_display,
+            l_fb_write_address,
+            l_char_size,
+            p_fg.to_argb().as_u32(),
+            p_bg.to_argb().as_u32(),
+        )?;
+
+        // Record modified area
+        let l_char_cell = self.get_char_cell();
+        self.mark_dirty(p_x, p_y, l_char_cell.0, l_char_cell.1);
+
+        Ok(())
+    }
+
+    /// Draws a single ASCII character and returns the horizontal advance of the glyph.
+    ///
+    /// This behaves exactly like [`Display::draw_char`] but additionally reports how many
+    /// pixels the caller should move along X before drawing the next character. This allows
+    /// a caller to build a run of text manually while tracking the X position.
+    ///
+    /// # Parameters
+    /// - `char_to_display`: ASCII byte to render.
+    /// - `x`: X coordinate in pixels of the character's top-left corner.
+    /// - `y`: Y coordinate in pixels of the character's top-left corner.
+    /// - `color`: Optional override color. If `None`, the current default color
+    ///   set by [`Display::set_color`] is used.
+    ///
+    /// # Returns
+    /// - `Ok(advance)` with the glyph advance in pixels. For the current monospaced fonts
+    ///   this is the font character width, multiplied by the horizontal text scale.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    /// - [`DisplayError::UnknownCharacter`] if `char_to_display` is outside
+    ///   the character range of the current font.
+    pub fn draw_char_advance(
+        &mut self,
+        p_char_to_display: u8,
+        p_x: u16,
+        p_y: u16,
+        p_color: Option<Colors>,
+    ) -> DisplayResult<u16> {
+        self.draw_char(p_char_to_display, p_x, p_y, p_color)?;
+        Ok(self.font.get_char_advance(p_char_to_display) * self.text_scale.0 as u16)
+    }
+
+    /// Renders a single ASCII character glyph directly into the frame buffer memory.
+    ///
+    /// This is an internal routine used by [`Display::draw_char`], [`Display::draw_char_bg`]
+    /// and [`Display::draw_string`].
+    ///
+    /// # Parameters
+    /// - `char_to_display`: ASCII byte to render.
+    /// - `fb_write_address`: Base address (in bytes) of the top-left pixel of the character
+    ///   within the currently displayed frame buffer. The routine writes 32-bit ARGB pixels.
+    /// - `char_size`: `(width, height)` in pixels for the current font glyph, before scaling.
+    ///   The glyph is drawn with the current text scale (see [`Display::set_text_scale`]).
+    /// - `color_argb`: Pixel color written for "set" glyph pixels, encoded as ARGB `u32`.
+    /// - `bg_argb`: Pixel color written for unset glyph pixels, encoded as ARGB `u32`.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the glyph was written successfully.
+    ///
+    /// # Errors
+    /// - [`DisplayError::UnknownCharacter`] if `char_to_display` is outside
+    ///   the character range of the current font and is not a custom glyph registered for the
+    ///   current font size (see [`Display::register_custom_glyph`]).
+    ///
+    /// # Safety
+    /// This function performs raw pointer writes into the frame buffer memory.
+    fn draw_char_in_fb(
+        &mut self,
+        p_char_to_display: u8,
+        mut p_fb_write_address: u32,
+        p_char_size: (u8, u8),
+        p_color_argb: u32,
+        p_bg_argb: u32,
+    ) -> DisplayResult<()> {
+        // Custom glyphs are only valid for the font size they were registered for
+        let l_custom = self
+            .custom_glyphs
+            .get(p_char_to_display.wrapping_sub(K_CUSTOM_GLYPH_FIRST) as usize)
+            .copied()
+            .flatten()
+            .filter(|(_, l_size)| *l_size == p_char_size)
+            .map(|(l_bitmap, _)| l_bitmap);
+
+        // Check if the character to display is valid
+        if l_custom.is_none() && !self.font.table().contains(p_char_to_display) {
+            return Err(DisplayError::UnknownCharacter(p_char_to_display));
+        } else {
+            // Each glyph pixel is drawn as a block of the text scale size
+            let (l_scale_x, l_scale_y) = self.text_scale;
+            let l_cell_width = p_char_size.0 as u32 * l_scale_x as u32;
+
+            // Display chat at the current position
+            for l_line in 0..p_char_size.1 {
+                for _ in 0..l_scale_y {
+                    for l_col in 0..p_char_size.0 {
+                        let l_pixel_set = match l_custom {
+                            Some(l_bitmap) => {
+                                glyph_pixel_set(l_bitmap, p_char_size.0, l_col, l_line)
+                            }
+                            None => self.font.is_pixel_set(p_char_to_display, l_col, l_line),
+                        };
+                        let l_argb = if l_pixel_set { p_color_argb } else { p_bg_argb };
+                        for _ in 0..l_scale_x {
+                            unsafe {
+                                *(p_fb_write_address as *mut u32) = l_argb;
+                            }
+
+                            // Increment frame buffer address
+                            p_fb_write_address += 4;
+                        }
+                    }
+
+                    // Increment frame buffer address
+                    p_fb_write_address += self.size.unwrap().0 as u32 * 4 - l_cell_width * 4;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Draws a string starting at the current cursor position.
+    ///
+    /// For each byte in `string`:
+    /// - `\n` advances the cursor to the next line (line feed).
+    /// - `\r` returns the cursor to the start of the current line (carriage return).
+    /// - Any other byte is drawn as an ASCII glyph at the cursor and the cursor is advanced.
+    ///
+    /// # Parameters
+    /// - `string`: UTF-8 string whose bytes are interpreted as ASCII codes. The string is
+    ///   checked before drawing: non-ASCII input is rejected and nothing is drawn.
+    /// - `color`: Optional override color for all characters. If `None`, the current
+    ///   default color is used.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the entire string was processed successfully.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    /// - [`DisplayError::NonAsciiInput`] with the index of the first non-ASCII byte in `string`.
+    /// - [`DisplayError::UnknownCharacter`] if any non-control byte is outside the supported
     ///   ASCII range.
     /// - [`DisplayError::OutOfScreenBounds`] if advancing the cursor moves past the bottom
     ///   of the screen.
     pub fn draw_string_at_cursor(
         &mut self,
-        p_string: &str,
+        p_string: &str,
+        p_color: Option<Colors>,
+    ) -> DisplayResult<()> {
+        // Reject non-ASCII input before drawing anything
+        check_ascii(p_string)?;
+
+        // Draw the string at the current cursor position
+        for l_char_to_display in p_string.as_bytes() {
+            self.draw_char_at_cursor(*l_char_to_display, p_color)?;
+        }
+        Ok(())
+    }
+
+    /// Draws a single character at the current cursor position and updates the cursor.
+    ///
+    /// Control characters:
+    /// - `\n`: performs a line feed (moves cursor down by one character height).
+    /// - `\r`: performs a carriage return (sets cursor X to 0).
+    ///
+    /// Otherwise, the character is drawn and the cursor advances by one character width,
+    /// wrapping to the next line if necessary.
+    ///
+    /// # Parameters
+    /// - `char_to_display`: The byte to process as either a control character (`\n`, `\r`)
+    ///   or an ASCII glyph.
+    /// - `color`: Optional override color. If `None`, the current default color is used.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    /// - [`DisplayError::UnknownCharacter`] if a non-control byte is outside the supported range.
+    /// - [`DisplayError::OutOfScreenBounds`] if cursor movement would exceed screen bounds.
+    pub fn draw_char_at_cursor(
+        &mut self,
+        p_char_to_display: u8,
+        p_color: Option<Colors>,
+    ) -> DisplayResult<()> {
+        if p_char_to_display == b'\n' {
+            self.set_cursor_line_feed()?;
+        } else if p_char_to_display == b'\r' {
+            self.set_cursor_return()?;
+        } else {
+            self.draw_char(
+                p_char_to_display,
+                self.cursor_pos.0,
+                self.cursor_pos.1,
+                p_color,
+            )?;
+            self.move_cursor()?;
+        }
+        Ok(())
+    }
+
+    /// Draws an unsigned integer in decimal at the provided pixel coordinates.
+    ///
+    /// The number is formatted digit by digit into a stack buffer, no string formatting
+    /// is involved.
+    ///
+    /// # Parameters
+    /// - `value`: Number to draw.
+    /// - `x`: X coordinate in pixels of the first digit.
+    /// - `y`: Y coordinate in pixels of the first digit.
+    /// - `color`: Optional override color. If `None`, the current default color
+    ///   set by [`Display::set_color`] is used.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the number was drawn successfully.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    pub fn draw_u32(
+        &mut self,
+        p_value: u32,
+        p_x: u16,
+        p_y: u16,
+        p_color: Option<Colors>,
+    ) -> DisplayResult<()> {
+        let mut l_buffer = [0; K_NUMBER_BUFFER_SIZE];
+        self.draw_string(
+            number_to_ascii(p_value, false, &mut l_buffer),
+            p_x,
+            p_y,
+            p_color,
+        )
+    }
+
+    /// Draws a signed integer in decimal at the provided pixel coordinates.
+    ///
+    /// Negative numbers are prefixed with `-`. See [`Display::draw_u32`].
+    ///
+    /// # Parameters
+    /// - `value`: Number to draw.
+    /// - `x`: X coordinate in pixels of the first character.
+    /// - `y`: Y coordinate in pixels of the first character.
+    /// - `color`: Optional override color. If `None`, the current default color
+    ///   set by [`Display::set_color`] is used.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the number was drawn successfully.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    pub fn draw_i32(
+        &mut self,
+        p_value: i32,
+        p_x: u16,
+        p_y: u16,
         p_color: Option<Colors>,
     ) -> DisplayResult<()> {
-        // Draw the string at the current cursor position
-        for l_char_to_display in p_string.as_bytes() {
-            self.draw_char_at_cursor(*l_char_to_display, p_color)?;
-        }
-        Ok(())
+        let mut l_buffer = [0; K_NUMBER_BUFFER_SIZE];
+        self.draw_string(i32_to_ascii(p_value, &mut l_buffer), p_x, p_y, p_color)
     }
 
-    /// Draws a single character at the current cursor position and updates the cursor.
+    /// Draws an unsigned integer in decimal at the current cursor position and updates
+    /// the cursor.
     ///
-    /// Control characters:
-    /// - `\n`: performs a line feed (moves cursor down by one character height).
-    /// - `\r`: performs a carriage return (sets cursor X to 0).
+    /// # Parameters
+    /// - `value`: Number to draw.
+    /// - `color`: Optional override color. If `None`, the current default color is used.
     ///
-    /// Otherwise, the character is drawn and the cursor advances by one character width,
-    /// wrapping to the next line if necessary.
+    /// # Returns
+    /// - `Ok(())` if the number was drawn successfully.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    /// - [`DisplayError::OutOfScreenBounds`] if advancing the cursor moves past the bottom
+    ///   of the screen.
+    pub fn draw_u32_at_cursor(
+        &mut self,
+        p_value: u32,
+        p_color: Option<Colors>,
+    ) -> DisplayResult<()> {
+        let mut l_buffer = [0; K_NUMBER_BUFFER_SIZE];
+        self.draw_string_at_cursor(number_to_ascii(p_value, false, &mut l_buffer), p_color)
+    }
+
+    /// Draws a signed integer in decimal at the current cursor position and updates
+    /// the cursor.
     ///
     /// # Parameters
-    /// - `char_to_display`: The byte to process as either a control character (`\n`, `\r`)
-    ///   or an ASCII glyph.
+    /// - `value`: Number to draw. Negative numbers are prefixed with `-`.
     /// - `color`: Optional override color. If `None`, the current default color is used.
     ///
     /// # Returns
-    /// - `Ok(())` on success.
+    /// - `Ok(())` if the number was drawn successfully.
     ///
     /// # Errors
     /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
-    /// - [`DisplayError::UnknownCharacter`] if a non-control byte is outside the supported range.
-    /// - [`DisplayError::OutOfScreenBounds`] if cursor movement would exceed screen bounds.
-    pub fn draw_char_at_cursor(
+    /// - [`DisplayError::OutOfScreenBounds`] if advancing the cursor moves past the bottom
+    ///   of the screen.
+    pub fn draw_i32_at_cursor(
         &mut self,
-        p_char_to_display: u8,
+        p_value: i32,
         p_color: Option<Colors>,
     ) -> DisplayResult<()> {
-        if p_char_to_display == b'\n' {
-            self.set_cursor_line_feed()?;
-        } else if p_char_to_display == b'\r' {
-            self.set_cursor_return()?;
-        } else {
-            self.draw_char(
-                p_char_to_display,
-                self.cursor_pos.0,
-                self.cursor_pos.1,
-                p_color,
-            )?;
-            self.move_cursor()?;
-        }
-        Ok(())
+        let mut l_buffer = [0; K_NUMBER_BUFFER_SIZE];
+        self.draw_string_at_cursor(i32_to_ascii(p_value, &mut l_buffer), p_color)
     }
 
     /// Advances the cursor by one character cell, with line wrapping.
     ///
     /// Cursor advancement rules:
-    /// - Increments X by the current font width.
+    /// - Increments X by the current character cell width (see [`Display::get_char_cell`]).
     /// - If X would exceed the last full character cell of the line, wraps X to `0`
-    ///   and increments Y by the current font height.
+    ///   and increments Y by the current character cell height.
     ///
     /// # Returns
     /// - `Ok(())` if the cursor moved successfully.
@@ -493,12 +1760,13 @@ impl Display {
         }
 
         // Move cursor
+        let l_char_cell = self.get_char_cell();
         let mut l_next_cursor_pos = self.cursor_pos;
-        l_next_cursor_pos.0 += self.font.get_char_size().0 as u16;
-        if l_next_cursor_pos.0 > self.size.unwrap().0 - self.font.get_char_size().0 as u16 {
+        l_next_cursor_pos.0 += l_char_cell.0;
+        if l_next_cursor_pos.0 > self.size.unwrap().0 - l_char_cell.0 {
             l_next_cursor_pos.0 = 0;
-            l_next_cursor_pos.1 += self.font.get_char_size().1 as u16;
-            if l_next_cursor_pos.1 > self.size.unwrap().1 - self.font.get_char_size().1 as u16 {
+            l_next_cursor_pos.1 += l_char_cell.1;
+            if l_next_cursor_pos.1 > self.size.unwrap().1 - l_char_cell.1 {
                 return Err(DisplayError::OutOfScreenBounds);
             }
         }
@@ -508,20 +1776,93 @@ impl Display {
 
     /// Sets the active font used for subsequent text rendering.
     ///
+    /// Built-in fonts are always accepted. A [`FontSize::Custom`] table is checked with
+    /// [`FontTable::is_valid`] first, the current font is kept if the table is rejected.
+    ///
     /// # Parameters
     /// - `font`: Font size to use for subsequent draw operations.
     ///
     /// # Returns
-    /// - `Ok(())` always.
+    /// - `Ok(())` if the font is now active.
     ///
     /// # Errors
-    /// This function does not currently return errors.
+    /// - [`DisplayError::InvalidFontTable`] if a custom font table is inconsistent.
     pub fn set_font(&mut self, p_font: FontSize) -> DisplayResult<()> {
+        if let FontSize::Custom(l_table) = p_font
+            && !l_table.is_valid()
+        {
+            return Err(DisplayError::InvalidFontTable);
+        }
         self.font = p_font;
         Ok(())
     }
 
-    /// Moves the cursor down by one character height (line feed).
+    /// Sets the text scale used for subsequent text rendering.
+    ///
+    /// Each glyph pixel is drawn as a block of `sx` by `sy` pixels, and the cursor moves by
+    /// the scaled character cell, e.g. `(2, 1)` gives double-width text and `(1, 2)`
+    /// double-height text for headings. `(1, 1)` draws the font unscaled.
+    ///
+    /// # Parameters
+    /// - `sx`: Horizontal scale factor.
+    /// - `sy`: Vertical scale factor.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the scale is now active.
+    ///
+    /// # Errors
+    /// - [`DisplayError::InvalidTextScale`] if a factor is `0` or above [`K_MAX_TEXT_SCALE`].
+    ///   The current scale is kept.
+    pub fn set_text_scale(&mut self, p_sx: u8, p_sy: u8) -> DisplayResult<()> {
+        if !(1..=K_MAX_TEXT_SCALE).contains(&p_sx) || !(1..=K_MAX_TEXT_SCALE).contains(&p_sy) {
+            return Err(DisplayError::InvalidTextScale(p_sx, p_sy));
+        }
+        self.text_scale = (p_sx, p_sy);
+        Ok(())
+    }
+
+    /// Registers a custom glyph (icon, arrow...) for a character code.
+    ///
+    /// Codes `K_CUSTOM_GLYPH_FIRST..K_CUSTOM_GLYPH_FIRST + K_CUSTOM_GLYPH_COUNT` (control
+    /// characters `0x10..=0x1F`) are available, so the glyphs can be embedded in strings, e.g.
+    /// `"\x10 85%"`. The bitmap is made of one row per pixel line, each row being
+    /// `ceil(width / 8)` bytes with the leftmost pixel in the most significant bit of the first
+    /// byte. Its size must match the character size of the current [`FontSize`]; the glyph is
+    /// only drawn while that font size is active. Registering a code again replaces its glyph.
+    ///
+    /// # Parameters
+    /// - `code`: Character code of the glyph.
+    /// - `bitmap`: Glyph bitmap, as described above.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the glyph was registered.
+    ///
+    /// # Errors
+    /// - [`DisplayError::InvalidGlyphCode`] if `code` is outside the custom glyph range, which
+    ///   protects the printable characters.
+    /// - [`DisplayError::BufferSizeMismatch`] if the bitmap size does not match the current
+    ///   font character size.
+    pub fn register_custom_glyph(
+        &mut self,
+        p_code: u8,
+        p_bitmap: &'static [u8],
+    ) -> DisplayResult<()> {
+        let l_index = p_code.wrapping_sub(K_CUSTOM_GLYPH_FIRST) as usize;
+        if p_code < K_CUSTOM_GLYPH_FIRST || l_index >= K_CUSTOM_GLYPH_COUNT {
+            return Err(DisplayError::InvalidGlyphCode(p_code));
+        }
+
+        let l_char_size = self.font.get_char_size();
+        let l_expected = l_char_size.1 as usize * (l_char_size.0 as usize).div_ceil(8);
+        if p_bitmap.len() != l_expected {
+            return Err(DisplayError::BufferSizeMismatch(l_expected, p_bitmap.len()));
+        }
+
+        self.custom_glyphs[l_index] = Some((p_bitmap, l_char_size));
+        Ok(())
+    }
+
+    /// Moves the cursor down by one character cell height (line feed).
     ///
     /// # Returns
     /// - `Ok(())` if the cursor remains within bounds.
@@ -534,8 +1875,9 @@ impl Display {
             return Err(DisplayError::DisplayDriverNotInitialized);
         }
 
-        self.cursor_pos.1 += self.font.get_char_size().1 as u16;
-        if self.cursor_pos.1 > self.size.unwrap().1 - self.font.get_char_size().1 as u16 {
+        let l_char_height = self.get_char_cell().1;
+        self.cursor_pos.1 += l_char_height;
+        if self.cursor_pos.1 > self.size.unwrap().1 - l_char_height {
             Err(DisplayError::OutOfScreenBounds)
         } else {
             Ok(())
@@ -580,6 +1922,32 @@ impl Display {
         }
     }
 
+    /// Sets the cursor position in pixels, clamping it so that a full character fits on screen.
+    ///
+    /// Unlike [`Display::set_cursor_pos`], out-of-range coordinates are snapped to the last
+    /// valid character position instead of being rejected.
+    ///
+    /// # Parameters
+    /// - `x`: X coordinate in pixels.
+    /// - `y`: Y coordinate in pixels.
+    ///
+    /// # Returns
+    /// - `Ok(())` once the cursor has been moved.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    pub fn set_cursor_pos_clamped(&mut self, p_x: u16, p_y: u16) -> DisplayResult<()> {
+        if !self.initialized {
+            return Err(DisplayError::DisplayDriverNotInitialized);
+        }
+
+        let l_size = self.size.unwrap();
+        let l_char_cell = self.get_char_cell();
+        self.cursor_pos.0 = p_x.min(l_size.0.saturating_sub(l_char_cell.0 + 1));
+        self.cursor_pos.1 = p_y.min(l_size.1.saturating_sub(l_char_cell.1 + 1));
+        Ok(())
+    }
+
     /// Sets the default color used by drawing operations when `color: None` is provided.
     ///
     /// # Parameters
@@ -594,4 +1962,409 @@ impl Display {
         self.color = p_color;
         Ok(())
     }
+
+    /// Sets the default background color used by [`Display::clear_default`].
+    ///
+    /// The screen is not redrawn.
+    ///
+    /// # Parameters
+    /// - `color`: New default background color.
+    ///
+    /// # Returns
+    /// - `Ok(())` always.
+    ///
+    /// # Errors
+    /// This function does not currently return errors.
+    pub fn set_background(&mut self, p_color: Colors) -> DisplayResult<()> {
+        self.background = p_color;
+        Ok(())
+    }
+
+    /// Clears the display to the default background color and resets the cursor to `(0, 0)`.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the display was cleared successfully.
+    ///
+    /// # Errors
+    /// Same as [`Display::clear`].
+    pub fn clear_default(&mut self) -> DisplayResult<()> {
+        self.clear(self.background)
+    }
+
+    /// Sets the LCD backlight brightness, clamped to the configured limits.
+    ///
+    /// # Parameters
+    /// - `percent`: Requested brightness in percent. The value is clamped to the range set by
+    ///   [`Display::set_backlight_limits`] (`[0, 100]` by default).
+    ///
+    /// # Returns
+    /// - `Ok(())` if the brightness was applied.
+    ///
+    /// # Errors
+    /// - [`DisplayError::DisplayDriverNotInitialized`] if called before [`Display::init`].
+    /// - [`DisplayError::HalError`] if the underlying HAL write fails.
+    pub fn set_backlight(&mut self, p_percent: u8) -> DisplayResult<()> {
+        // Returns error if not initialized
+        if !self.initialized {
+            return Err(DisplayError::DisplayDriverNotInitialized);
+        }
+
+        let l_percent = p_percent.clamp(self.backlight_limits.0, self.backlight_limits.1);
+
+        self.hal
+            .as_mut()
+            .unwrap()
+            .interface_write(
+                self.hal_id.unwrap(),
+                self.kernel_master_id,
+                InterfaceWriteActions::Lcd(LcdActions::SetBacklight(l_percent)),
+            )
+            .map_err(DisplayError::HalError)?;
+        self.backlight = l_percent;
+
+        Ok(())
+    }
+
+    /// Returns the backlight brightness last applied by [`Display::set_backlight`].
+    ///
+    /// # Returns
+    /// The brightness in percent, 100 if it was never set.
+    pub fn get_backlight(&self) -> u8 {
+        self.backlight
+    }
+
+    /// Sets the range the backlight brightness is clamped to by [`Display::set_backlight`].
+    ///
+    /// The current brightness is not modified.
+    ///
+    /// # Parameters
+    /// - `min`: Lowest allowed brightness in percent.
+    /// - `max`: Highest allowed brightness in percent.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the limits were stored.
+    ///
+    /// # Errors
+    /// - [`DisplayError::InvalidBacklightLimits`] if `min > max` or `max` exceeds 100.
+    pub fn set_backlight_limits(&mut self, p_min: u8, p_max: u8) -> DisplayResult<()> {
+        if p_min > p_max || p_max > K_BACKLIGHT_MAX {
+            return Err(DisplayError::InvalidBacklightLimits(p_min, p_max));
+        }
+
+        self.backlight_limits = (p_min, p_max);
+        Ok(())
+    }
+}
+
+/// Checks that a string only contains ASCII bytes.
+///
+/// Multi-byte UTF-8 characters would otherwise be drawn byte by byte as unrelated glyphs.
+///
+/// # Parameters
+/// - `string`: String to check.
+///
+/// # Errors
+/// - [`DisplayError::NonAsciiInput`] with the index of the first non-ASCII byte.
+fn check_ascii(p_string: &str) -> DisplayResult<()> {
+    match p_string.bytes().position(|l_b| !l_b.is_ascii()) {
+        Some(l_index) => Err(DisplayError::NonAsciiInput(l_index)),
+        None => Ok(()),
+    }
+}
+
+/// Splits the next line to render from a text to be word-wrapped.
+///
+/// The line ends at the first `\n`, or at the last space fitting in `max_cols` characters.
+/// A word longer than `max_cols` is split. Spaces at the wrapping point are dropped.
+///
+/// # Parameters
+/// - `text`: Remaining ASCII text to render.
+/// - `max_cols`: Maximum number of characters of a line, must not be `0`.
+///
+/// # Returns
+/// `(line, rest)` with the line to render and the remaining text.
+fn wrap_line(p_text: &str, p_max_cols: usize) -> (&str, &str) {
+    // Explicit line break
+    let (l_candidate, l_after_newline) = match p_text.find('\n') {
+        Some(l_pos) => (&p_text[..l_pos], Some(&p_text[l_pos + 1..])),
+        None => (p_text, None),
+    };
+
+    if l_candidate.len() <= p_max_cols {
+        return (l_candidate, l_after_newline.unwrap_or(""));
+    }
+
+    // Break at the last space fitting in the line, or split the word
+    let (l_line, l_rest) = match l_candidate[..=p_max_cols].rfind(' ') {
+        Some(l_pos) if l_pos > 0 => (&p_text[..l_pos], &p_text[l_pos..]),
+        _ => (&p_text[..p_max_cols], &p_text[p_max_cols..]),
+    };
+
+    (l_line.trim_end_matches(' '), l_rest.trim_start_matches(' '))
+}
+
+/// Formats a number in decimal into a stack buffer.
+///
+/// Digits are written from the end of the buffer, so no intermediate reversal is needed.
+///
+/// # Parameters
+/// - `magnitude`: Absolute value of the number.
+/// - `negative`: Whether a `-` sign is prepended.
+/// - `buffer`: Storage for the formatted number.
+///
+/// # Returns
+/// The formatted number, borrowed from `buffer`.
+fn number_to_ascii(
+    mut p_magnitude: u32,
+    p_negative: bool,
+    p_buffer: &mut [u8; K_NUMBER_BUFFER_SIZE],
+) -> &str {
+    let mut l_start = K_NUMBER_BUFFER_SIZE;
+    loop {
+        l_start -= 1;
+        p_buffer[l_start] = b'0' + (p_magnitude % 10) as u8;
+        p_magnitude /= 10;
+        if p_magnitude == 0 {
+            break;
+        }
+    }
+    if p_negative {
+        l_start -= 1;
+        p_buffer[l_start] = b'-';
+    }
+
+    // Only ASCII digits and sign were written
+    core::str::from_utf8(&p_buffer[l_start..]).unwrap_or("")
+}
+
+/// Formats a signed number in decimal into a stack buffer, see [`number_to_ascii`].
+///
+/// # Parameters
+/// - `value`: Number to format. Negative numbers are prefixed with `-`.
+/// - `buffer`: Storage for the formatted number.
+///
+/// # Returns
+/// The formatted number, borrowed from `buffer`.
+fn i32_to_ascii(p_value: i32, p_buffer: &mut [u8; K_NUMBER_BUFFER_SIZE]) -> &str {
+    number_to_ascii(p_value.unsigned_abs(), p_value < 0, p_buffer)
+}
+
+/// Tells whether a pixel of a custom glyph bitmap is set.
+///
+/// # Parameters
+/// - `bitmap`: Glyph bitmap, rows of `ceil(width / 8)` bytes, MSB first.
+/// - `width`: Glyph width in pixels.
+/// - `x`: Column of the pixel.
+/// - `y`: Line of the pixel.
+///
+/// # Returns
+/// `true` if the pixel is set.
+fn glyph_pixel_set(p_bitmap: &[u8], p_width: u8, p_x: u8, p_y: u8) -> bool {
+    let l_row_bytes = (p_width as usize).div_ceil(8);
+    let l_byte = p_bitmap[p_y as usize * l_row_bytes + p_x as usize / 8];
+    l_byte & (0x80 >> (p_x % 8)) != 0
+}
+
+/// Returns the horizontal inset of a row of a rounded rectangle, computed with integer math.
+///
+/// # Parameters
+/// - `radius`: Radius of the corners in pixels.
+/// - `row`: Row index counted from the nearest horizontal edge of the rectangle.
+///
+/// # Returns
+/// The number of pixels left untouched at each end of the row, `0` outside the corners.
+fn corner_inset(p_radius: u32, p_row: u32) -> u32 {
+    if p_row < p_radius {
+        let l_dy = p_radius - p_row;
+        p_radius - (p_radius * p_radius - l_dy * l_dy).isqrt()
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a display in the initialized state without an LCD or frame buffer: only the
+    /// state and the checks done before drawing can be used.
+    fn display(p_size: (u16, u16)) -> Display {
+        let mut l_display = Display::new(0);
+        l_display.size = Some(p_size);
+        l_display.initialized = true;
+        l_display
+    }
+
+    #[test]
+    fn char_cell_applies_both_scale_factors() {
+        let mut l_display = Display::new(0);
+        assert_eq!(l_display.get_char_cell(), (11, 16));
+
+        l_display.set_text_scale(2, 3).unwrap();
+        assert_eq!(l_display.get_char_cell(), (22, 48));
+        l_display.set_text_scale(1, 2).unwrap();
+        assert_eq!(l_display.get_char_cell(), (11, 32));
+    }
+
+    #[test]
+    fn clamped_cursor_stays_on_screen_past_each_edge() {
+        // Positions are clamped so that a whole 11x16 character fits on a 100x100 screen
+        let mut l_display = display((100, 100));
+        l_display.set_cursor_pos_clamped(40, 30).unwrap();
+        assert_eq!(l_display.cursor_pos, (40, 30));
+
+        l_display.set_cursor_pos_clamped(100, 30).unwrap();
+        assert_eq!(l_display.cursor_pos, (88, 30));
+        l_display.set_cursor_pos_clamped(40, 100).unwrap();
+        assert_eq!(l_display.cursor_pos, (40, 83));
+        l_display
+            .set_cursor_pos_clamped(u16::MAX, u16::MAX)
+            .unwrap();
+        assert_eq!(l_display.cursor_pos, (88, 83));
+        l_display.set_cursor_pos_clamped(0, 0).unwrap();
+        assert_eq!(l_display.cursor_pos, (0, 0));
+    }
+
+    #[test]
+    fn clamped_cursor_needs_an_initialized_display() {
+        assert!(matches!(
+            Display::new(0).set_cursor_pos_clamped(0, 0),
+            Err(DisplayError::DisplayDriverNotInitialized)
+        ));
+    }
+
+    #[test]
+    fn invalid_text_scale_keeps_the_current_one() {
+        let mut l_display = Display::new(0);
+        l_display.set_text_scale(2, 1).unwrap();
+
+        assert!(matches!(
+            l_display.set_text_scale(0, 1),
+            Err(DisplayError::InvalidTextScale(0, 1))
+        ));
+        assert!(matches!(
+            l_display.set_text_scale(1, K_MAX_TEXT_SCALE + 1),
+            Err(DisplayError::InvalidTextScale(1, _))
+        ));
+        assert_eq!(l_display.get_char_cell(), (22, 16));
+    }
+
+    #[test]
+    fn cursor_advances_and_wraps_by_the_scaled_cell() {
+        let mut l_display = display((100, 100));
+        l_display.set_text_scale(2, 2).unwrap();
+
+        l_display.move_cursor().unwrap();
+        assert_eq!(l_display.cursor_pos, (22, 0));
+
+        // 4 cells of 22 pixels fit on a 100 pixels line
+        l_display.cursor_pos = (66, 0);
+        l_display.move_cursor().unwrap();
+        assert_eq!(l_display.cursor_pos, (0, 32));
+
+        // A third line of 32 pixels fits, not a fourth one
+        l_display.cursor_pos = (66, 64);
+        assert!(matches!(
+            l_display.move_cursor(),
+            Err(DisplayError::OutOfScreenBounds)
+        ));
+    }
+
+    #[test]
+    fn font_and_color_read_back_what_was_set() {
+        let mut l_display = Display::new(0);
+        assert!(matches!(l_display.get_font(), FontSize::Font16));
+        assert!(matches!(l_display.get_color(), Colors::White));
+
+        l_display.set_font(FontSize::Font24).unwrap();
+        l_display.set_color(Colors::Rgb(1, 2, 3)).unwrap();
+        assert!(matches!(l_display.get_font(), FontSize::Font24));
+        assert!(matches!(l_display.get_color(), Colors::Rgb(1, 2, 3)));
+    }
+
+    #[test]
+    fn corner_inset_follows_a_quarter_circle() {
+        assert_eq!(corner_inset(10, 0), 10);
+        assert_eq!(corner_inset(10, 4), 2);
+        assert_eq!(corner_inset(10, 9), 1);
+        assert_eq!(corner_inset(10, 10), 0);
+        assert_eq!(corner_inset(0, 0), 0);
+
+        // The inset never grows towards the middle of the rectangle
+        assert!((1..10).all(|l_row| corner_inset(10, l_row) <= corner_inset(10, l_row - 1)));
+    }
+
+    #[test]
+    fn rounded_rect_rejects_a_radius_larger_than_half_a_side() {
+        let mut l_display = display((100, 100));
+        assert!(matches!(
+            l_display.draw_rounded_rect(0, 0, 20, 10, 6, None, true),
+            Err(DisplayError::InvalidRadius(6))
+        ));
+        assert!(matches!(
+            l_display.draw_rounded_rect(0, 0, 0, 0, 1, None, false),
+            Err(DisplayError::InvalidRadius(1))
+        ));
+    }
+
+    #[test]
+    fn rounded_rect_outside_the_screen_is_rejected() {
+        let mut l_display = display((100, 100));
+        assert!(matches!(
+            l_display.draw_rounded_rect(90, 0, 20, 20, 5, None, true),
+            Err(DisplayError::OutOfScreenBounds)
+        ));
+        assert!(matches!(
+            l_display.draw_rounded_rect(0, u16::MAX, 20, 20, 5, None, true),
+            Err(DisplayError::OutOfScreenBounds)
+        ));
+    }
+
+    #[test]
+    fn lines_are_all_checked_before_drawing() {
+        let mut l_display = display((100, 100));
+        assert!(matches!(
+            l_display.draw_lines(&["first", "sec\u{e9}nd"], 0, 0, None),
+            Err(DisplayError::NonAsciiInput(3))
+        ));
+
+        // 6 lines of 16 pixels fit on 100 pixels, not 7
+        let l_lines = ["line"; 7];
+        assert!(matches!(
+            l_display.draw_lines(&l_lines, 0, 0, None),
+            Err(DisplayError::OutOfScreenBounds)
+        ));
+        assert!(matches!(
+            l_display.draw_lines(&l_lines[..6], 0, 5, None),
+            Err(DisplayError::OutOfScreenBounds)
+        ));
+    }
+
+    #[test]
+    fn no_lines_draws_nothing() {
+        let mut l_display = display((100, 100));
+        assert!(l_display.draw_lines(&[], 0, 99, None).is_ok());
+    }
+
+    #[test]
+    fn unsigned_numbers_are_formatted_in_decimal() {
+        let mut l_buffer = [0; K_NUMBER_BUFFER_SIZE];
+        assert_eq!(number_to_ascii(0, false, &mut l_buffer), "0");
+        assert_eq!(number_to_ascii(7, false, &mut l_buffer), "7");
+        assert_eq!(number_to_ascii(1050, false, &mut l_buffer), "1050");
+        assert_eq!(
+            number_to_ascii(u32::MAX, false, &mut l_buffer),
+            "4294967295"
+        );
+    }
+
+    #[test]
+    fn signed_numbers_are_formatted_with_their_sign() {
+        let mut l_buffer = [0; K_NUMBER_BUFFER_SIZE];
+        assert_eq!(i32_to_ascii(0, &mut l_buffer), "0");
+        assert_eq!(i32_to_ascii(-1, &mut l_buffer), "-1");
+        assert_eq!(i32_to_ascii(-250, &mut l_buffer), "-250");
+        assert_eq!(i32_to_ascii(i32::MAX, &mut l_buffer), "2147483647");
+        assert_eq!(i32_to_ascii(i32::MIN, &mut l_buffer), "-2147483648");
+    }
 }
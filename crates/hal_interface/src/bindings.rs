@@ -2,10 +2,19 @@ use crate::HalError::{
     IncompatibleAction, InterfaceNotFound, ReadOnlyInterface, WriteOnlyInterface, WrongInterfaceId,
 };
 use crate::interface_read::InterfaceReadAction;
-use crate::{
-    GpioWriteAction, HalError, HalResult, InterfaceCallback, InterfaceWriteActions, LcdLayer,
-    RxBuffer,
-};
+use crate::{HalError, HalResult, InterfaceCallback, InterfaceWriteActions, LcdLayer, RxBuffer};
+
+/// Represents the elementary actions on a GPIO pin understood by the underlying C HAL.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy)]
+pub enum GpioPinAction {
+    /// Set the pin to a high state.
+    Set = 0,
+    /// Set the pin to a low state.
+    Clear = 1,
+    /// Toggle the pin state.
+    Toggle = 2,
+}
 
 /// Represents the result codes returned by the underlying C HAL.
 #[repr(u8)]
@@ -27,6 +36,10 @@ pub enum HalInterfaceResult {
     ErrWriteError = 6,
     /// No buffer is associated with the interface for reading.
     ErrNoBuffer = 7,
+    /// An error occurred while reinitializing the interface.
+    ErrResetError = 8,
+    /// The requested timer period cannot be generated.
+    ErrInvalidPeriod = 9,
 }
 
 impl HalInterfaceResult {
@@ -103,6 +116,13 @@ impl HalInterfaceResult {
                 interface_name(p_id.unwrap())?,
                 "No buffer provided for read operation",
             )),
+            HalInterfaceResult::ErrResetError => {
+                Err(HalError::ResetError(interface_name(p_id.unwrap())?))
+            }
+            HalInterfaceResult::ErrInvalidPeriod => Err(HalError::InterfaceBadConfig(
+                interface_name(p_id.unwrap())?,
+                "Timer period out of range",
+            )),
         }
     }
 }
@@ -116,14 +136,35 @@ unsafe extern "C" {
 
     pub fn configure_callback(p_id: u8, p_callback: InterfaceCallback) -> HalInterfaceResult;
 
-    pub fn gpio_write(p_id: u8, p_action: GpioWriteAction) -> HalInterfaceResult;
+    pub fn gpio_write(p_id: u8, p_action: GpioPinAction) -> HalInterfaceResult;
+
+    pub fn gpio_pulse(p_id: u8, p_width_us: u32) -> HalInterfaceResult;
+
+    pub fn gpio_read(p_id: u8, p_state: *mut bool) -> HalInterfaceResult;
+
+    pub fn dac_write(p_id: u8, p_value: u16) -> HalInterfaceResult;
+
+    pub fn timer_configure(p_id: u8, p_period_us: u32, p_one_shot: bool) -> HalInterfaceResult;
 
     pub fn usart_write(p_id: u8, p_str: *const u8, p_len: u16) -> HalInterfaceResult;
 
+    pub fn interface_reset(p_id: u8) -> HalInterfaceResult;
+
     pub fn get_read_buffer(p_id: u8, p_buffer: &mut &mut RxBuffer) -> HalInterfaceResult;
 
     pub fn get_core_clk() -> u32;
 
+    pub fn get_capabilities() -> u32;
+
+    #[link_name = "HAL_GetTick"]
+    pub fn hal_get_tick() -> u32;
+
+    pub fn crc32(p_data: *const u8, p_len: u32) -> u32;
+
+    pub fn config_read(p_data: *mut u8, p_len: u32) -> HalInterfaceResult;
+
+    pub fn config_write(p_data: *const u8, p_len: u32) -> HalInterfaceResult;
+
     pub fn lcd_enable(p_id: u8, p_enable: bool) -> HalInterfaceResult;
 
     pub fn lcd_clear(p_id: u8, p_layer: LcdLayer, p_color: u32) -> HalInterfaceResult;
@@ -145,6 +186,17 @@ unsafe extern "C" {
     ) -> HalInterfaceResult;
 
     pub fn set_fb_address(p_id: u8, p_layer: LcdLayer, p_fb_address: u32) -> HalInterfaceResult;
+
+    pub fn lcd_refresh(
+        p_id: u8,
+        p_layer: LcdLayer,
+        p_x: u16,
+        p_y: u16,
+        p_width: u16,
+        p_height: u16,
+    ) -> HalInterfaceResult;
+
+    pub fn lcd_set_backlight(p_id: u8, p_percent: u8) -> HalInterfaceResult;
 }
 
 /**
@@ -0,0 +1,53 @@
+/// Optional hardware features that may or may not be available on a given board.
+///
+/// Query them with [`crate::Hal::has_capability`] to choose between an accelerated path
+/// and a software fallback at runtime.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HalCapability {
+    /// Hardware CRC unit, used by [`crate::Hal::crc32`].
+    HardwareCrc,
+    /// Adjustable LCD backlight.
+    LcdBacklight,
+    /// DMA2D accelerated fills and copies.
+    Dma2d,
+    /// Real-time clock.
+    Rtc,
+    /// Peripheral reinitialization with [`crate::Hal::reset_interface`].
+    InterfaceReset,
+}
+
+/// All known capabilities, in the order used for listing.
+pub const K_HAL_CAPABILITIES: [HalCapability; 5] = [
+    HalCapability::HardwareCrc,
+    HalCapability::LcdBacklight,
+    HalCapability::Dma2d,
+    HalCapability::Rtc,
+    HalCapability::InterfaceReset,
+];
+
+impl HalCapability {
+    /// Returns the human-readable name of the capability.
+    ///
+    /// # Returns
+    /// A static string slice naming the capability.
+    pub fn name(&self) -> &'static str {
+        match self {
+            HalCapability::HardwareCrc => "Hardware CRC",
+            HalCapability::LcdBacklight => "LCD backlight",
+            HalCapability::Dma2d => "DMA2D",
+            HalCapability::Rtc => "RTC",
+            HalCapability::InterfaceReset => "Interface reset",
+        }
+    }
+
+    /// Returns the bit of the capability in the mask reported by the C HAL.
+    pub(crate) fn mask(&self) -> u32 {
+        match self {
+            HalCapability::HardwareCrc => 0x01,
+            HalCapability::LcdBacklight => 0x02,
+            HalCapability::Dma2d => 0x04,
+            HalCapability::Rtc => 0x08,
+            HalCapability::InterfaceReset => 0x10,
+        }
+    }
+}
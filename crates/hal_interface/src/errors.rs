@@ -4,8 +4,8 @@
 
 use crate::HalError::{
     HalAlreadyInitialized, IncompatibleAction, InterfaceAlreadyLocked, InterfaceBadConfig,
-    InterfaceNotFound, LockedInterface, LockerAlreadyConfigured, ReadError, ReadOnlyInterface,
-    UnknownError, WriteError, WriteOnlyInterface, WrongInterfaceId,
+    InterfaceNotFound, InterfaceNotOwned, LockedInterface, LockerAlreadyConfigured, ReadError,
+    ReadOnlyInterface, ResetError, UnknownError, WriteError, WriteOnlyInterface, WrongInterfaceId,
 };
 use crate::HalErrorLevel::{Critical, Error, Fatal};
 use heapless::{String, format};
@@ -87,6 +87,10 @@ pub enum HalError {
     LockerAlreadyConfigured,
     /// The interface has an invalid configuration for the requested operation.
     InterfaceBadConfig(&'static str, &'static str),
+    /// The caller does not hold the lock required on the interface.
+    InterfaceNotOwned(&'static str),
+    /// An error occurred while reinitializing the interface.
+    ResetError(&'static str),
     /// An unknown error occurred within the HAL.
     UnknownError,
 }
@@ -225,6 +229,26 @@ impl HalError {
                     )
                     .unwrap();
             }
+            InterfaceNotOwned(l_ift) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(256; "Interface {} must be locked by the caller", l_ift)
+                            .unwrap()
+                            .as_str(),
+                    )
+                    .unwrap();
+            }
+            ResetError(l_ift) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(256; "Error during reset of interface {}", l_ift)
+                            .unwrap()
+                            .as_str(),
+                    )
+                    .unwrap();
+            }
             InterfaceBadConfig(l_ift, l_err) => {
                 l_msg.push_str(self.severity().as_str()).unwrap();
                 l_msg
@@ -264,6 +288,8 @@ impl HalError {
             InterfaceAlreadyLocked(_) => Critical,
             LockerAlreadyConfigured => Error,
             InterfaceBadConfig(_, _) => Critical,
+            InterfaceNotOwned(_) => Error,
+            ResetError(_) => Critical,
         }
     }
 }
@@ -20,6 +20,8 @@ pub enum InterfaceReadAction {
     LcdRead(LcdReadAction),
     /// Read action for interfaces with a receive buffer (e.g., UART).
     BufferRead,
+    /// Read the level of an input GPIO pin.
+    GpioRead,
 }
 
 impl InterfaceReadAction {
@@ -27,6 +29,7 @@ impl InterfaceReadAction {
         match self {
             InterfaceReadAction::LcdRead(_) => "LCD Read",
             InterfaceReadAction::BufferRead => "Buffer Read",
+            InterfaceReadAction::GpioRead => "GPIO Read",
         }
     }
 }
@@ -37,6 +40,8 @@ pub enum InterfaceReadResult {
     LcdRead(LcdRead),
     /// Data read from a receive buffer.
     BufferRead(Vec<u8, K_BUFFER_SIZE>),
+    /// Level of a GPIO pin, `true` when high.
+    GpioRead(bool),
 }
 
 /// Specific read operations for LCD interfaces.
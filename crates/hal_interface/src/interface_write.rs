@@ -1,8 +1,11 @@
-use crate::InterfaceWriteActions::{GpioWrite, Lcd, UartWrite};
-use crate::LcdActions::{Clear, DrawPixel, Enable, SetFbAddress};
-use crate::UartWriteActions::{SendChar, SendString};
+use core::fmt::Display;
+
+use crate::InterfaceWriteActions::{DacWrite, GpioWrite, Lcd, UartWrite};
+use crate::LcdActions::{Clear, DrawPixel, Enable, Refresh, SetBacklight, SetFbAddress};
+use crate::UartWriteActions::{SendBytes, SendChar, SendString};
 use crate::bindings::{
-    HalInterfaceResult, lcd_clear, lcd_draw_pixel, lcd_enable, set_fb_address, usart_write,
+    GpioPinAction, HalInterfaceResult, dac_write, gpio_pulse, gpio_write, lcd_clear,
+    lcd_draw_pixel, lcd_enable, lcd_refresh, lcd_set_backlight, set_fb_address, usart_write,
 };
 
 /// High-level enum representing all possible write actions on any hardware interface.
@@ -14,6 +17,11 @@ pub enum InterfaceWriteActions<'a> {
     UartWrite(UartWriteActions<'a>),
     /// Write action for LCD interfaces.
     Lcd(LcdActions),
+    /// Output value for DAC interfaces.
+    ///
+    /// The DAC is used with 12-bit resolution: values above [`K_DAC_MAX_VALUE`] are clamped
+    /// to full scale by the HAL.
+    DacWrite(u16),
 }
 
 impl InterfaceWriteActions<'_> {
@@ -22,10 +30,23 @@ impl InterfaceWriteActions<'_> {
             GpioWrite(_) => "GPIO Write",
             UartWrite(_) => "UART Write",
             Lcd(_) => "LCD Write",
+            DacWrite(_) => "DAC Write",
         }
     }
 }
 
+/// Full scale value of a DAC output, which is used with 12-bit right-aligned data.
+pub const K_DAC_MAX_VALUE: u16 = 0x0FFF;
+
+/// Writes an output value to a DAC interface.
+///
+/// # Parameters
+/// - `id`: Interface ID of the DAC.
+/// - `value`: Output value, clamped to [`K_DAC_MAX_VALUE`].
+pub(crate) fn dac_action(p_id: u8, p_value: u16) -> HalInterfaceResult {
+    unsafe { dac_write(p_id, p_value.min(K_DAC_MAX_VALUE)) }
+}
+
 /// Represents write operations specific to UART interfaces.
 #[derive(Debug, Clone, Copy)]
 pub enum UartWriteActions<'a> {
@@ -33,6 +54,8 @@ pub enum UartWriteActions<'a> {
     SendChar(u8),
     /// Send a string of bytes.
     SendString(&'a str),
+    /// Send raw bytes, which do not need to be valid UTF-8.
+    SendBytes(&'a [u8]),
 }
 
 impl UartWriteActions<'_> {
@@ -45,20 +68,80 @@ impl UartWriteActions<'_> {
             SendString(l_str) => unsafe {
                 usart_write(p_id, l_str.as_bytes().as_ptr(), l_str.len() as u16)
             },
+            SendBytes(l_bytes) => unsafe {
+                usart_write(p_id, l_bytes.as_ptr(), l_bytes.len() as u16)
+            },
         }
     }
 }
 
+/// Maximum width of a GPIO pulse.
+///
+/// Pulses are generated with a busy-wait that blocks the caller (and the scheduler),
+/// so longer widths requested with [`GpioWriteAction::Pulse`] are clamped to this value.
+pub const K_GPIO_MAX_PULSE_WIDTH: Microseconds = Microseconds(1000);
+
+/// A wrapper struct representing a duration in microseconds.
+///
+/// # Fields
+///
+/// * `0` - The inner `u32` value representing the duration in microseconds.
+///
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Microseconds(pub u32);
+
+impl Display for Microseconds {
+    fn fmt(&self, p_formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(p_formatter, "{} us", self.0)
+    }
+}
+
+impl Microseconds {
+    /// Converts the value of the current instance into a `u32`.
+    ///
+    /// # Returns
+    /// - A `u32` representation of the wrapped value.
+    pub fn to_u32(&self) -> u32 {
+        self.0
+    }
+}
+
 /// Represents possible actions on a GPIO pin.
-#[repr(u8)]
 #[derive(Debug, Clone, Copy)]
 pub enum GpioWriteAction {
     /// Set the pin to a high state.
-    Set = 0,
+    Set,
     /// Set the pin to a low state.
-    Clear = 1,
+    Clear,
     /// Toggle the pin state.
-    Toggle = 2,
+    Toggle,
+    /// Set the pin, hold it high for the given width, then clear it.
+    ///
+    /// The width is clamped to [`K_GPIO_MAX_PULSE_WIDTH`] as the call blocks until the
+    /// pulse is complete.
+    Pulse(Microseconds),
+}
+
+impl GpioWriteAction {
+    pub(crate) fn action(&self, p_id: u8) -> HalInterfaceResult {
+        match self {
+            GpioWriteAction::Set => unsafe { gpio_write(p_id, GpioPinAction::Set) },
+            GpioWriteAction::Clear => unsafe { gpio_write(p_id, GpioPinAction::Clear) },
+            GpioWriteAction::Toggle => unsafe { gpio_write(p_id, GpioPinAction::Toggle) },
+            GpioWriteAction::Pulse(l_width) => unsafe {
+                gpio_pulse(p_id, l_width.to_u32().min(K_GPIO_MAX_PULSE_WIDTH.to_u32()))
+            },
+        }
+    }
+}
+
+/// Callback scheduling of a hardware timer, see [`crate::Hal::configure_timer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimerMode {
+    /// The callback is called once per period until the timer is stopped.
+    Periodic,
+    /// The callback is called once, one period after the start, then the timer stops.
+    OneShot,
 }
 
 /// Represents the available LCD layers.
@@ -83,6 +166,20 @@ pub struct LcdPixel {
     pub color: PixelColorARGB,
 }
 
+/// Represents a rectangular area of the LCD screen.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct LcdArea {
+    /// X coordinate of the top-left corner in pixels.
+    pub x: u16,
+    /// Y coordinate of the top-left corner in pixels.
+    pub y: u16,
+    /// Width of the area in pixels.
+    pub width: u16,
+    /// Height of the area in pixels.
+    pub height: u16,
+}
+
 /// Represents a color in ARGB format.
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
@@ -134,6 +231,10 @@ pub enum LcdActions {
     DrawPixel(LcdLayer, LcdPixel),
     /// Set the base address of the frame buffer for a layer.
     SetFbAddress(LcdLayer, u32),
+    /// Transfer an area of the frame buffer of a layer to the panel.
+    Refresh(LcdLayer, LcdArea),
+    /// Set the backlight brightness, in percent (0 to 100).
+    SetBacklight(u8),
 }
 
 impl LcdActions {
@@ -147,6 +248,17 @@ impl LcdActions {
             SetFbAddress(l_layer, l_fb_address) => unsafe {
                 set_fb_address(p_id, *l_layer, *l_fb_address)
             },
+            Refresh(l_layer, l_area) => unsafe {
+                lcd_refresh(
+                    p_id,
+                    *l_layer,
+                    l_area.x,
+                    l_area.y,
+                    l_area.width,
+                    l_area.height,
+                )
+            },
+            SetBacklight(l_percent) => unsafe { lcd_set_backlight(p_id, *l_percent) },
         }
     }
 }
@@ -1,6 +1,7 @@
 #![no_std]
 
 mod bindings;
+mod capabilities;
 mod errors;
 mod interface_read;
 mod interface_write;
@@ -8,13 +9,15 @@ mod lock;
 
 use core::sync::atomic::{AtomicBool, Ordering};
 
+pub use capabilities::*;
 use heapless::Vec;
 pub use interface_read::*;
 pub use interface_write::*;
 
 use crate::bindings::{
-    HalInterfaceResult, configure_callback, get_core_clk, get_interface_id, get_read_buffer,
-    gpio_write, hal_init,
+    HalInterfaceResult, config_read, config_write, configure_callback, crc32, get_capabilities,
+    get_core_clk, get_interface_id, get_read_buffer, gpio_read, hal_get_tick, hal_init,
+    interface_reset, timer_configure,
 };
 use crate::lock::Locker;
 pub use bindings::interface_name;
@@ -22,12 +25,23 @@ pub use errors::*;
 
 pub const K_BUFFER_SIZE: usize = 32;
 
+/// Size in bytes of the persistent configuration area, see [`Hal::config_write`].
+pub const K_CONFIG_STORAGE_SIZE: usize = 512;
+
+/// Maximum number of interfaces tracked by the operation statistics.
+pub const K_MAX_INTERFACES: usize = 64;
+
+/// Shortest period accepted by [`Hal::configure_timer`].
+pub const K_TIMER_MIN_PERIOD: Microseconds = Microseconds(10);
+
 static G_HAL_INIT: AtomicBool = AtomicBool::new(false);
 
 /// High-level interface to the Hardware Abstraction Layer (HAL).
 pub struct Hal {
     /// Optional locking mechanism to manage exclusive access to hardware interfaces.
     locker: Option<Locker>,
+    /// Per-interface operation statistics, as `(operations, errors)` indexed by interface ID.
+    stats: [(u32, u32); K_MAX_INTERFACES],
 }
 
 /// Type definition for a HAL callback function.
@@ -47,7 +61,8 @@ impl Hal {
     ///    exactly once for the whole program (guarded by an atomic flag).
     /// 2. Optional locking can be enabled afterwards via [`Hal::configure_locker`].
     ///
-    /// The returned instance starts with no [`Locker`] configured (`locker: None`).
+    /// The returned instance starts with no [`Locker`] configured (`locker: None`) and all
+    /// interface statistics cleared.
     /// When no locker is configured, lock/authorization methods are effectively no-ops
     /// and interface access is unrestricted by this crate.
     ///
@@ -65,9 +80,15 @@ impl Hal {
         if !G_HAL_INIT.load(Ordering::Relaxed) {
             unsafe { hal_init() }
             G_HAL_INIT.store(true, Ordering::Relaxed);
-            Ok(Self { locker: None })
+            Ok(Self {
+                locker: None,
+                stats: [(0, 0); K_MAX_INTERFACES],
+            })
         } else {
-            Ok(Self { locker: None })
+            Ok(Self {
+                locker: None,
+                stats: [(0, 0); K_MAX_INTERFACES],
+            })
         }
     }
 
@@ -262,16 +283,13 @@ impl Hal {
     ///
     /// # Behavior
     /// - The function matches on the provided `InterfaceActions`:
-    ///   - `InterfaceActions::GpioWrite`: Calls the `gpio_write` function, passing the `id` (as `u8`) and the provided action.
-    ///     Converts the result of `gpio_write` into a `HalResult` using `to_result()`.
+    ///   - `InterfaceActions::GpioWrite`: Executes the GPIO action by calling its `action` method with the `id` (as `u8`),
+    ///     then converts the result into a `HalResult` using `to_result()`.
     ///   - `InterfaceActions::UartWrite`: Executes the action linked to the `UartWrite` operation by calling its `action` method
     ///     with the `id` (as `u8`), then processes its result with `to_result()`.
     ///   - `InterfaceActions::Lcd`: Similar to `UartWrite`, it calls the `action` method for LCD, passing the `id`
     ///     (as `u8`) and processes its result using `to_result()`.
-    ///
-    /// # Safety
-    /// - The `GpioWrite` case executes an `unsafe` block when invoking the `gpio_write` function. Ensure that the usage
-    ///   of the unsafe code does not introduce undefined behavior.
+    ///   - `InterfaceActions::DacWrite`: Writes the output value to the DAC channel, clamped to 12 bits.
     ///
     /// # Conversion
     /// - The `to_result` method is used in all cases to convert the invoked action's return value into an ` HalResult `
@@ -289,15 +307,10 @@ impl Hal {
         }
 
         // Perform action
-        match p_action {
-            InterfaceWriteActions::GpioWrite(l_act) => unsafe {
-                gpio_write(p_ressource_id as u8, l_act).to_result(
-                    Some(p_ressource_id),
-                    None,
-                    Some(p_action),
-                    None,
-                )
-            },
+        let l_result = match p_action {
+            InterfaceWriteActions::GpioWrite(l_act) => l_act
+                .action(p_ressource_id as u8)
+                .to_result(Some(p_ressource_id), None, Some(p_action), None),
             InterfaceWriteActions::UartWrite(l_act) => l_act
                 .action(p_ressource_id as u8)
                 .to_result(Some(p_ressource_id), None, Some(p_action), None),
@@ -307,7 +320,12 @@ impl Hal {
                 Some(p_action),
                 None,
             ),
-        }
+            InterfaceWriteActions::DacWrite(l_value) => dac_action(p_ressource_id as u8, l_value)
+                .to_result(Some(p_ressource_id), None, Some(p_action), None),
+        };
+
+        self.record_stats(p_ressource_id, l_result.is_ok());
+        l_result
     }
 
     /// Reads from a specified interface resource using an authorized caller.
@@ -397,13 +415,157 @@ impl Hal {
                 // that the data has been consumed.
                 l_buffer.size = 0;
             }
+            InterfaceReadAction::GpioRead => {
+                let mut l_state = false;
+                l_interface_res = unsafe { gpio_read(p_ressource_id as u8, &mut l_state) };
+                l_read_result = InterfaceReadResult::GpioRead(l_state);
+            }
         };
-        match l_interface_res.to_result(Some(p_ressource_id), None, None, Some(p_read_action)) {
+        let l_result =
+            l_interface_res.to_result(Some(p_ressource_id), None, None, Some(p_read_action));
+        self.record_stats(p_ressource_id, l_result.is_ok());
+        match l_result {
             Ok(_) => Ok(l_read_result),
             Err(l_e) => Err(l_e),
         }
     }
 
+    /// Reads the receive buffer of an interface directly into a caller-provided buffer.
+    ///
+    /// This is the allocation-free counterpart of [`InterfaceReadAction::BufferRead`]: bytes are
+    /// copied from the driver buffer to `buffer` without going through an intermediate `Vec`.
+    /// When more bytes are pending than `buffer` can hold, the remaining ones are kept in the
+    /// driver buffer for the next read.
+    ///
+    /// # Parameters
+    /// - `ressource_id`: The unique identifier of the interface to read.
+    /// - `caller_id`: The unique identifier of the caller requesting the read.
+    /// - `buffer`: Destination of the received bytes.
+    ///
+    /// # Returns
+    /// - `Ok(count)` with the number of bytes written at the start of `buffer`, which may be
+    ///   lower than its length (including 0) when fewer bytes were available. A single read
+    ///   returns at most [`K_BUFFER_SIZE`] bytes, the size of the driver receive buffer.
+    ///
+    /// # Errors
+    /// - Any error returned by the locker if the caller is not authorized.
+    /// - Any error returned by the HAL if the interface has no receive buffer.
+    pub fn interface_read_into(
+        &mut self,
+        p_ressource_id: usize,
+        p_caller_id: u32,
+        p_buffer: &mut [u8],
+    ) -> HalResult<usize> {
+        // Check for lock on interface
+        if let Some(l_locker) = &mut self.locker {
+            l_locker.authorize_action(p_ressource_id, p_caller_id)?;
+        }
+
+        // Retrieve the buffer address from the HAL for the given resource.
+        let mut l_buffer: &mut RxBuffer = &mut RxBuffer {
+            buffer: core::ptr::null_mut(),
+            size: 0,
+        };
+        let l_interface_res = unsafe { get_read_buffer(p_ressource_id as u8, &mut l_buffer) };
+
+        let mut l_count = 0;
+        if matches!(l_interface_res, HalInterfaceResult::OK) {
+            let l_available = l_buffer.size as usize;
+            l_count = l_available.min(p_buffer.len());
+
+            unsafe {
+                core::ptr::copy_nonoverlapping(l_buffer.buffer, p_buffer.as_mut_ptr(), l_count);
+                // Move the bytes not consumed to the start of the driver buffer
+                core::ptr::copy(
+                    l_buffer.buffer.wrapping_add(l_count),
+                    l_buffer.buffer,
+                    l_available - l_count,
+                );
+            }
+            l_buffer.size = (l_available - l_count) as u8;
+        }
+
+        let l_result = l_interface_res.to_result(
+            Some(p_ressource_id),
+            None,
+            None,
+            Some(InterfaceReadAction::BufferRead),
+        );
+        self.record_stats(p_ressource_id, l_result.is_ok());
+        l_result.map(|_| l_count)
+    }
+
+    /// Returns the operation statistics of an interface.
+    ///
+    /// Every read and write reaching the underlying HAL is counted, failed ones are also
+    /// counted as errors. Operations rejected by the locker are not counted.
+    ///
+    /// # Parameters
+    /// - `id`: The interface identifier to query.
+    ///
+    /// # Returns
+    /// - `Ok((operations, errors))` with the counters since HAL creation.
+    ///
+    /// # Errors
+    /// - [`HalError::WrongInterfaceId`] if `id` is not below [`K_MAX_INTERFACES`].
+    pub fn interface_stats(&self, p_id: usize) -> HalResult<(u32, u32)> {
+        self.stats
+            .get(p_id)
+            .copied()
+            .ok_or(HalError::WrongInterfaceId(p_id))
+    }
+
+    /// Reinitializes the peripheral behind an interface without resetting the MCU.
+    ///
+    /// This is meant to recover a peripheral stuck in a bad state, e.g. a UART that stopped
+    /// delivering RX interrupts. Only USART interfaces are currently supported.
+    ///
+    /// State preserved by the reset:
+    /// - the peripheral configuration (baudrate, framing...), which is re-applied,
+    /// - the callback configured with [`Hal::configure_callback`],
+    /// - the interface lock and the operation statistics.
+    ///
+    /// State cleared by the reset:
+    /// - any ongoing transmission or reception, which is aborted,
+    /// - the data received but not yet read from the interface buffer.
+    ///
+    /// # Parameters
+    /// - `id`: The interface identifier to reset.
+    /// - `caller_id`: The identifier of the caller, which must hold the lock on the interface
+    ///   when a locker is configured.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the interface was reinitialized.
+    ///
+    /// # Errors
+    /// - [`HalError::WrongInterfaceId`] if `id` does not exist.
+    /// - [`HalError::InterfaceNotOwned`] if a locker is configured and the interface is not
+    ///   locked by `caller_id`.
+    /// - [`HalError::IncompatibleAction`] if the interface type does not support reset.
+    /// - [`HalError::ResetError`] if the peripheral could not be reinitialized.
+    pub fn reset_interface(&mut self, p_id: usize, p_caller_id: u32) -> HalResult<()> {
+        // The caller must own the interface
+        if self.locker.is_some() && self.is_interface_locked(p_id)? != Some(p_caller_id) {
+            return Err(HalError::InterfaceNotOwned(interface_name(p_id)?));
+        }
+
+        unsafe { interface_reset(p_id as u8) }.to_result(Some(p_id), None, None, None)
+    }
+
+    /// Updates the operation statistics of an interface.
+    ///
+    /// # Parameters
+    /// - `id`: The interface identifier. Identifiers out of range are ignored.
+    /// - `success`: Whether the operation succeeded.
+    fn record_stats(&mut self, p_id: usize, p_success: bool) {
+        if let Some(l_stats) = self.stats.get_mut(p_id) {
+            l_stats.0 = l_stats.0.wrapping_add(1);
+            if !p_success {
+                l_stats.1 = l_stats.1.wrapping_add(1);
+            }
+        }
+    }
+
     /// Configures a callback interface with the given parameters.
     ///
     /// # Parameters
@@ -452,6 +614,71 @@ impl Hal {
         )
     }
 
+    /// Starts a hardware timer calling `callback` once per `period`, or only once after
+    /// `period` in [`TimerMode::OneShot`] mode.
+    ///
+    /// The timer runs independently of the kernel scheduler, for work needing a finer
+    /// granularity than the scheduler period. Calling this function again on a running timer
+    /// reprograms it, and a period of 0 stops it. A one-shot timer stops by itself after the
+    /// callback, and can be started again with another call.
+    ///
+    /// # Parameters
+    /// - `ressource_id`: ID of the timer interface.
+    /// - `caller_id`: ID of the caller, checked against the interface lock.
+    /// - `period`: Callback period or delay, from [`K_TIMER_MIN_PERIOD`], or 0 to stop the
+    ///   timer.
+    /// - `mode`: Whether the callback is called periodically or once.
+    /// - `callback`: Function called on each period, with the interface ID.
+    ///
+    /// # Interrupt context
+    /// The callback runs in the timer interrupt handler, preempting the scheduler and the
+    /// running app:
+    /// - it must be short, as it delays every lower priority interrupt and the scheduler,
+    /// - it must not block nor wait for another interrupt,
+    /// - any data shared with apps must be accessed atomically or inside a critical section,
+    /// - it bypasses the scheduler, so its errors are not reported to the kernel error
+    ///   handler and a fault in it is not attributed to any app.
+    ///
+    /// # Errors
+    /// - Returns an error if the caller is not authorized to use the interface.
+    /// - Returns [`HalError::IncompatibleAction`] if the interface is not a timer.
+    /// - Returns [`HalError::InterfaceBadConfig`] if the period is below
+    ///   [`K_TIMER_MIN_PERIOD`] or too long for the timer.
+    pub fn configure_timer(
+        &mut self,
+        p_ressource_id: usize,
+        p_caller_id: u32,
+        p_period: Microseconds,
+        p_mode: TimerMode,
+        p_callback: InterfaceCallback,
+    ) -> HalResult<()> {
+        // Check for lock on interface
+        if let Some(l_locker) = &mut self.locker {
+            l_locker.authorize_action(p_ressource_id, p_caller_id)?;
+        }
+
+        // The callback must be in place before the first interrupt
+        unsafe { configure_callback(p_ressource_id as u8, p_callback) }.to_result(
+            Some(p_ressource_id),
+            None,
+            None,
+            None,
+        )?;
+
+        let l_one_shot = p_mode == TimerMode::OneShot;
+        let l_result =
+            match unsafe { timer_configure(p_ressource_id as u8, p_period.to_u32(), l_one_shot) } {
+                HalInterfaceResult::ErrIncompatibleAction => Err(HalError::IncompatibleAction(
+                    "Timer configuration",
+                    interface_name(p_ressource_id)?,
+                )),
+                l_res => l_res.to_result(Some(p_ressource_id), None, None, None),
+            };
+
+        self.record_stats(p_ressource_id, l_result.is_ok());
+        l_result
+    }
+
     /// Retrieves the current core clock frequency.
     ///
     /// # Returns
@@ -468,4 +695,97 @@ impl Hal {
     pub fn get_core_clk(&self) -> u32 {
         unsafe { get_core_clk() }
     }
+
+    /// Checks whether an optional hardware feature is available on the board.
+    ///
+    /// # Parameters
+    ///
+    /// * `cap` - The capability to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the feature is supported by the board and its driver is active.
+    ///
+    pub fn has_capability(&self, p_cap: HalCapability) -> bool {
+        let l_caps = unsafe { get_capabilities() };
+        l_caps & p_cap.mask() != 0
+    }
+
+    /// Retrieves the current system tick counter.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of milliseconds elapsed since the system tick was started,
+    /// as provided by `HAL_GetTick`. The counter wraps around on overflow.
+    ///
+    pub fn get_tick(&self) -> u32 {
+        unsafe { hal_get_tick() }
+    }
+
+    /// Computes the CRC-32 of a memory region using the hardware CRC unit.
+    ///
+    /// The CRC unit uses the default configuration: polynomial `0x04C11DB7`, initial value
+    /// `0xFFFFFFFF`, no input/output reflection and no final XOR.
+    ///
+    /// # Parameters
+    ///
+    /// * `data` - Bytes to process.
+    ///
+    /// # Returns
+    ///
+    /// The computed CRC value.
+    ///
+    pub fn crc32(&self, p_data: &[u8]) -> u32 {
+        unsafe { crc32(p_data.as_ptr(), p_data.len() as u32) }
+    }
+
+    /// Reads the start of the persistent configuration area.
+    ///
+    /// The area keeps its content across resets. An erased area reads as `0xFF` bytes, the
+    /// caller is responsible for validating the content.
+    ///
+    /// # Parameters
+    ///
+    /// * `data` - Destination buffer, filled from the start of the area.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if `data` was filled.
+    ///
+    /// # Errors
+    ///
+    /// * [`HalError::ReadError`] if `data` is longer than [`K_CONFIG_STORAGE_SIZE`].
+    ///
+    pub fn config_read(&self, p_data: &mut [u8]) -> HalResult<()> {
+        match unsafe { config_read(p_data.as_mut_ptr(), p_data.len() as u32) } {
+            HalInterfaceResult::OK => Ok(()),
+            _ => Err(HalError::ReadError("Config storage")),
+        }
+    }
+
+    /// Replaces the content of the persistent configuration area.
+    ///
+    /// The area is a flash sector: it is erased, then `data` is programmed at its start. The
+    /// CPU stalls during the erase, which can take a few seconds, so this is only meant for
+    /// occasional configuration changes.
+    ///
+    /// # Parameters
+    ///
+    /// * `data` - Bytes to store.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if `data` was stored.
+    ///
+    /// # Errors
+    ///
+    /// * [`HalError::WriteError`] if `data` is longer than [`K_CONFIG_STORAGE_SIZE`] or if
+    ///   the flash could not be erased or programmed.
+    ///
+    pub fn config_write(&self, p_data: &[u8]) -> HalResult<()> {
+        match unsafe { config_write(p_data.as_ptr(), p_data.len() as u32) } {
+            HalInterfaceResult::OK => Ok(()),
+            _ => Err(HalError::WriteError("Config storage")),
+        }
+    }
 }
@@ -2,8 +2,9 @@ use heapless::{String, Vec};
 
 use crate::apps::app_config::AppStatus::{Running, Stopped};
 use crate::data::Kernel;
-use crate::scheduler::App;
-use crate::{KernelError, KernelResult, Milliseconds};
+use crate::ident::K_KERNEL_MASTER_ID;
+use crate::scheduler::{App, AppCall, AppParam};
+use crate::{KernelError, KernelResult, Milliseconds, SysCallDisplayArgs, syscall_display};
 
 /// Maximum number of parameters accepted after the app name.
 pub const K_MAX_APP_PARAMS: usize = 8;
@@ -21,6 +22,15 @@ pub enum CallPeriodicity {
     PeriodicUntil(Milliseconds, Milliseconds),
 }
 
+/// Defines how the main function of an application is called.
+#[derive(Copy, Clone)]
+pub enum CallMethod {
+    /// The function is called without parameter.
+    Call(App),
+    /// The function is called with the `param` value of the [`AppConfig`].
+    CallParam(AppParam),
+}
+
 /// Represents the current runtime status of an application.
 #[derive(PartialEq, Copy, Clone)]
 pub enum AppStatus {
@@ -51,13 +61,23 @@ pub struct AppConfig {
     /// The execution periodicity of the application.
     pub periodicity: CallPeriodicity,
     /// The main function of the application.
-    pub app_fn: App,
+    pub app_fn: CallMethod,
+    /// Parameter passed to `app_fn` at each call, required for [`CallMethod::CallParam`].
+    /// Allows registering the same function several times against different resources.
+    pub param: Option<u32>,
     /// Optional initialization hook invoked before scheduling the app.
     /// Receives the assigned scheduler id and parsed parameters.
     pub init_fn:
         Option<fn(u32, Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>) -> KernelResult<()>>,
     /// Optional cleanup function invoked when the application is stopped.
     pub end_fn: Option<App>,
+    /// Optional hook invoked before a reboot while the application is running, to save state
+    /// or release hardware (see [`crate::apps::AppsManager::shutdown`]).
+    pub on_shutdown: Option<App>,
+    /// Whether the kernel clears the display when the application is stopped.
+    pub clear_display_on_exit: bool,
+    /// Whether the application is started when registered during kernel initialization.
+    pub autostart: bool,
     /// The current operational status of the application.
     pub app_status: AppStatus,
     /// The scheduler identifier assigned to the application when running.
@@ -68,7 +88,8 @@ impl AppConfig {
     /// Starts (schedules) this app if it is currently stopped.
     ///
     /// This registers the configured app with the kernel scheduler according to its
-    /// [`CallPeriodicity`] and `app_fn`.
+    /// [`CallPeriodicity`] and `app_fn`. For [`CallMethod::CallParam`], `param` is stored in the
+    /// scheduler task and passed to `app_fn` at each call.
     ///
     /// - [`CallPeriodicity::Once`]: schedules the app to run once (using the scheduler period).
     /// - [`CallPeriodicity::Periodic`]: schedules the app to run indefinitely at the given period.
@@ -95,8 +116,22 @@ impl AppConfig {
     /// parameter count exceeds [`K_MAX_APP_PARAMS`], or
     /// [`KernelError::AppNeedsNoParam`] if parameters are provided while no
     /// no `init_fn` hook is configured.
+    /// Returns [`KernelError::AppParamMissing`] if `app_fn` is a [`CallMethod::CallParam`]
+    /// while `param` is `None`, or [`KernelError::AppNeedsNoParam`] if `param` is set for a
+    /// [`CallMethod::Call`].
     pub fn start(&mut self, p_app_param: &str) -> KernelResult<u32> {
         if self.app_status == Stopped {
+            let l_app_call = match (self.app_fn, self.param) {
+                (CallMethod::Call(l_app), None) => AppCall::App(l_app),
+                (CallMethod::CallParam(l_app), Some(l_param)) => AppCall::AppParam(l_app, l_param),
+                (CallMethod::Call(_), Some(_)) => {
+                    return Err(KernelError::AppNeedsNoParam(self.name));
+                }
+                (CallMethod::CallParam(_), None) => {
+                    return Err(KernelError::AppParamMissing(self.name));
+                }
+            };
+
             let l_period;
             let l_ends_in;
             match self.periodicity {
@@ -116,7 +151,7 @@ impl AppConfig {
 
             let l_app_id = Kernel::scheduler().add_periodic_app(
                 self.name,
-                self.app_fn,
+                l_app_call,
                 self.end_fn,
                 l_period,
                 l_ends_in,
@@ -175,19 +210,23 @@ impl AppConfig {
     /// If the app is [`AppStatus::Running`], this function:
     /// - invokes `end_fn` (if configured),
     /// - removes the corresponding periodic task from the scheduler,
+    /// - clears the display if `clear_display_on_exit` is set,
     /// - notifies the terminal that the app exited (using the stored scheduler id),
     /// - updates `self.app_status` to [`AppStatus::Stopped`] and clears `self.id`.
     ///
     /// If the app is already stopped, this is a no-op.
     ///
     /// # Errors
-    /// Returns any error produced by the end hook or terminal exit notifier.
+    /// Returns any error produced by the end hook, display clear or terminal exit notifier.
     pub fn stop(&mut self) -> KernelResult<()> {
         if self.app_status == Running {
             if let Some(l_stop_fn) = self.end_fn {
                 l_stop_fn()?;
             }
             Kernel::scheduler().remove_periodic_app(self.name)?;
+            if self.clear_display_on_exit {
+                syscall_display(SysCallDisplayArgs::ClearDefault, K_KERNEL_MASTER_ID)?;
+            }
             Kernel::terminal().app_exit_notifier(self.id.unwrap())?;
             self.app_status = Stopped;
             self.id = None;
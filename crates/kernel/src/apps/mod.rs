@@ -1,18 +1,26 @@
-use crate::KernelResult;
-use heapless::Vec;
+use crate::data::Kernel;
+use crate::systick::watchdog_suspended;
+use crate::{KernelError, KernelResult, LogLevel, Milliseconds, log, now_ms};
+use hal_interface::K_CONFIG_STORAGE_SIZE;
+use heapless::{Vec, format};
 
 mod app_config;
 
 pub use self::app_config::{
-    AppConfig, AppStatus, CallPeriodicity, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS,
+    AppConfig, AppStatus, CallMethod, CallPeriodicity, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS,
 };
 
-const K_MAX_APPS: usize = 32;
+const K_MAX_APPS: usize = 48;
+
+/// Magic word starting a valid autostart record ("ASTR").
+const K_AUTOSTART_MAGIC: u32 = 0x4153_5452;
 
 /// Manages the registration and lifecycle of user applications.
 pub struct AppsManager {
     /// Internal list of registered application configurations.
     apps: Vec<AppConfig, K_MAX_APPS>,
+    /// Successors started when an app reaches the end of its lifetime, as `(app, next)` pairs.
+    successors: Vec<(&'static str, &'static str), K_MAX_APPS>,
 }
 
 impl AppsManager {
@@ -22,7 +30,10 @@ impl AppsManager {
     ///
     /// A new `AppsManager` with no registered applications.
     pub fn new() -> AppsManager {
-        Self { apps: Vec::new() }
+        Self {
+            apps: Vec::new(),
+            successors: Vec::new(),
+        }
     }
 
     /// Registers a new application with the manager.
@@ -41,7 +52,7 @@ impl AppsManager {
     /// * `Ok(())` - If the application was successfully registered.
     ///
     /// * `Err(KernelError::CannotAddNewPeriodicApp)` - If the application registry is
-    ///   full (maximum of 32 applications).
+    ///   full (maximum of 48 applications).
     pub fn add_app(&mut self, mut p_app: AppConfig) -> KernelResult<()> {
         p_app.app_status = AppStatus::Stopped;
         p_app.id = None;
@@ -52,6 +63,109 @@ impl AppsManager {
         }
     }
 
+    /// Unregisters an application from the manager.
+    ///
+    /// If the application is running, it is stopped first (see [`AppConfig::stop`]) so that
+    /// its end hook runs and its scheduler slot is released. The registry slot is then freed
+    /// and can be used by [`AppsManager::add_app`]. Registration order of the remaining apps
+    /// is not preserved.
+    ///
+    /// # Parameters
+    ///
+    /// * `app_name` - Name of the application to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the application was stopped (if needed) and removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::KernelError::AppNotFound`] if no registered app matches `app_name`,
+    /// or propagates any error returned by [`AppsManager::stop_app`].
+    pub fn remove_app(&mut self, p_app_name: &str) -> KernelResult<()> {
+        let l_index = self
+            .apps
+            .iter()
+            .position(|l_app| l_app.name == p_app_name)
+            .ok_or(crate::KernelError::AppNotFound)?;
+
+        // Stop the app before unregistering it
+        if let Some(l_id) = self.apps[l_index].id {
+            self.stop_app(l_id)?;
+        }
+
+        // Drop the chain links involving the app
+        let l_name = self.apps[l_index].name;
+        self.successors
+            .retain(|(l_app, l_next)| *l_app != l_name && *l_next != l_name);
+
+        self.apps.swap_remove(l_index);
+        Ok(())
+    }
+
+    /// Sets the app started automatically when another app reaches the end of its lifetime.
+    ///
+    /// The successor is only started when the scheduler removes the finished task (see
+    /// [`AppsManager::end_app`]), not when the app is stopped explicitly. Setting a new successor
+    /// replaces the previous one. Chains cannot loop: a successor leading back to `app` is
+    /// rejected.
+    ///
+    /// # Parameters
+    ///
+    /// * `app` - Name of the registered app to chain from.
+    /// * `then` - Name of the registered app to start when `app` finishes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the successor was recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::KernelError::AppNotFound`] if `app` or `then` is not registered, or
+    /// [`crate::KernelError::AppChainCycle`] if the chain starting at `then` leads back to `app`.
+    pub fn set_next_app(&mut self, p_app: &str, p_then: &'static str) -> KernelResult<()> {
+        let l_app = self
+            .apps
+            .iter()
+            .find(|l_a| l_a.name == p_app)
+            .ok_or(crate::KernelError::AppNotFound)?
+            .name;
+        if !self.apps.iter().any(|l_a| l_a.name == p_then) {
+            return Err(crate::KernelError::AppNotFound);
+        }
+
+        // Follow the chain from the successor, it must not come back to the app
+        let mut l_current = Some(p_then);
+        for _ in 0..K_MAX_APPS {
+            match l_current {
+                Some(l_name) if l_name == l_app => {
+                    return Err(crate::KernelError::AppChainCycle(l_app));
+                }
+                Some(l_name) => l_current = self.next_app(l_name),
+                None => break,
+            }
+        }
+
+        self.successors.retain(|(l_a, _)| *l_a != l_app);
+        // One link per registered app at most, cannot overflow
+        self.successors.push((l_app, p_then)).unwrap();
+        Ok(())
+    }
+
+    /// Returns the successor of an app, if any.
+    ///
+    /// # Arguments
+    /// * `p_app` - App name to query.
+    ///
+    /// # Returns
+    /// The name of the app started when `p_app` finishes, or `None`.
+    fn next_app(&self, p_app: &str) -> Option<&'static str> {
+        self.successors
+            .iter()
+            .find(|(l_app, _)| *l_app == p_app)
+            .map(|(_, l_next)| *l_next)
+    }
+
     /// Start a registered app by name.
     ///
     /// This searches the internal apps list for an app whose [`AppConfig::name`]
@@ -99,6 +213,84 @@ impl AppsManager {
             .stop()
     }
 
+    /// Stop an app whose lifetime has expired and start its successor, if any.
+    ///
+    /// Called by the scheduler when a finite task ends. The app is stopped as with
+    /// [`AppsManager::stop_app`], then the app set with [`AppsManager::set_next_app`] is started
+    /// without parameters.
+    ///
+    /// # Arguments
+    /// * `p_app_id` - The ID of the finished app.
+    ///
+    /// # Errors
+    /// Returns [`crate::KernelError::AppNotFound`] if no registered app matches `app_id`, or
+    /// propagates any error returned by [`AppConfig::stop`] or when starting the successor.
+    pub(crate) fn end_app(&mut self, p_app_id: u32) -> KernelResult<()> {
+        let l_name = self
+            .apps
+            .iter()
+            .find(|l_app| l_app.id == Some(p_app_id))
+            .ok_or(crate::KernelError::AppNotFound)?
+            .name;
+        self.stop_app(p_app_id)?;
+
+        if let Some(l_next) = self.next_app(l_name) {
+            self.start_app(l_next)?;
+        }
+        Ok(())
+    }
+
+    /// Calls the shutdown hooks of the running apps before a reboot.
+    ///
+    /// Hooks are called one after the other, in registration order. Running them is best
+    /// effort: a failing hook is logged and the next one is called. Once the time budget is
+    /// spent, the remaining hooks are skipped and the number of skipped hooks is logged.
+    ///
+    /// The budget is only checked between hooks, a hook is never interrupted. A hook which
+    /// never returns stalls the scheduler, so the system is still reset by the software
+    /// watchdog when it is enabled.
+    ///
+    /// # Arguments
+    /// * `p_budget` - Time after which no more hook is started.
+    pub(crate) fn shutdown(&self, p_budget: Milliseconds) {
+        let l_start = now_ms();
+        let mut l_hooks = self
+            .apps
+            .iter()
+            .filter(|l_app| l_app.app_status == AppStatus::Running)
+            .filter_map(|l_app| l_app.on_shutdown.map(|l_hook| (l_app.name, l_hook)));
+
+        while let Some((l_name, l_hook)) = l_hooks.next() {
+            if now_ms().wrapping_sub(l_start) >= p_budget.to_u32() {
+                let l_skipped = l_hooks.count() + 1;
+                log(
+                    LogLevel::Warning,
+                    format!(60; "Shutdown budget exceeded, {} hooks skipped", l_skipped)
+                        .unwrap_or_default()
+                        .as_str(),
+                );
+                return;
+            }
+
+            if let Err(l_err) = l_hook() {
+                log(
+                    LogLevel::Warning,
+                    format!(128; "Shutdown hook of {} failed: {}", l_name, l_err.to_string())
+                        .unwrap_or_default()
+                        .as_str(),
+                );
+            }
+        }
+    }
+
+    /// Returns the occupancy of the application registry.
+    ///
+    /// # Returns
+    /// A `(used, total)` tuple of registry slots.
+    pub(crate) fn slots(&self) -> (usize, usize) {
+        (self.apps.len(), K_MAX_APPS)
+    }
+
     /// Returns the list of registered app names.
     ///
     /// # Returns
@@ -107,6 +299,107 @@ impl AppsManager {
         self.apps.iter().map(|l_app| l_app.name).collect()
     }
 
+    /// Returns the names of the apps flagged for autostart.
+    ///
+    /// # Returns
+    /// A vector of app names in registration order.
+    pub(crate) fn list_autostart(&self) -> Vec<&'static str, K_MAX_APPS> {
+        self.apps
+            .iter()
+            .filter(|l_app| l_app.autostart)
+            .map(|l_app| l_app.name)
+            .collect()
+    }
+
+    /// Enables or disables the autostart of an app and saves the configuration.
+    ///
+    /// The names of all the apps flagged for autostart are written to the persistent
+    /// configuration area, so the setting survives a reset (see
+    /// [`AppsManager::load_autostart`]). The running state of the app is not changed.
+    ///
+    /// # Arguments
+    /// * `p_app` - App name to configure.
+    /// * `p_enabled` - `true` to start the app at boot.
+    ///
+    /// # Errors
+    /// Returns [`crate::KernelError::AppNotFound`] if no registered app matches `p_app`,
+    /// [`crate::KernelError::AutostartConfigTooLarge`] if the names do not fit in the storage or
+    /// [`crate::KernelError::HalError`] if writing the storage fails. The flag is left
+    /// unchanged on error.
+    pub(crate) fn set_autostart(&mut self, p_app: &str, p_enabled: bool) -> KernelResult<()> {
+        let l_app = self
+            .apps
+            .iter_mut()
+            .find(|l_app| l_app.name == p_app)
+            .ok_or(KernelError::AppNotFound)?;
+        let l_previous = l_app.autostart;
+        l_app.autostart = p_enabled;
+
+        let l_result = self.save_autostart();
+        if l_result.is_err()
+            && let Some(l_app) = self.apps.iter_mut().find(|l_app| l_app.name == p_app)
+        {
+            l_app.autostart = l_previous;
+        }
+        l_result
+    }
+
+    /// Writes the autostart record to the persistent configuration area.
+    ///
+    /// The record is the magic word, the number of names, each name prefixed by its length,
+    /// then the CRC-32 of all the previous bytes. Integers are little-endian.
+    ///
+    /// # Errors
+    /// Returns [`crate::KernelError::AutostartConfigTooLarge`] if the record does not fit in
+    /// [`K_CONFIG_STORAGE_SIZE`], or [`crate::KernelError::HalError`] if the write fails.
+    fn save_autostart(&self) -> KernelResult<()> {
+        // Names with their length byte, plus the magic word, the count and the CRC
+        let l_names = self.list_autostart();
+        let l_size = l_names.iter().map(|l_name| 1 + l_name.len()).sum::<usize>() + 9;
+        if l_size > K_CONFIG_STORAGE_SIZE {
+            return Err(KernelError::AutostartConfigTooLarge);
+        }
+
+        let mut l_record: Vec<u8, K_CONFIG_STORAGE_SIZE> = Vec::new();
+        l_record
+            .extend_from_slice(&K_AUTOSTART_MAGIC.to_le_bytes())
+            .unwrap();
+        l_record.push(l_names.len() as u8).unwrap();
+        for l_name in l_names {
+            l_record.push(l_name.len() as u8).unwrap();
+            l_record.extend_from_slice(l_name.as_bytes()).unwrap();
+        }
+        let l_crc = Kernel::hal().crc32(&l_record);
+        l_record.extend_from_slice(&l_crc.to_le_bytes()).unwrap();
+
+        // Erasing the flash sector outlasts the watchdog timeout
+        watchdog_suspended(|| Kernel::hal().config_write(&l_record)).map_err(KernelError::HalError)
+    }
+
+    /// Applies the autostart configuration saved by [`AppsManager::set_autostart`].
+    ///
+    /// Registered apps are flagged for autostart if, and only if, their name is in the saved
+    /// record. Names of apps which are not registered are ignored. When the storage is empty
+    /// or corrupted (bad magic word, inconsistent lengths or CRC mismatch), the compiled
+    /// configuration is kept.
+    ///
+    /// # Returns
+    /// `true` if a saved configuration was applied.
+    pub(crate) fn load_autostart(&mut self) -> bool {
+        let mut l_record = [0u8; K_CONFIG_STORAGE_SIZE];
+        if Kernel::hal().config_read(&mut l_record).is_err() {
+            return false;
+        }
+
+        let Some(l_names) = parse_autostart(&l_record, |l_data| Kernel::hal().crc32(l_data)) else {
+            return false;
+        };
+        for l_app in self.apps.iter_mut() {
+            l_app.autostart = l_names.contains(&l_app.name.as_bytes());
+        }
+        true
+    }
+
     /// Returns the current status for a given app name.
     ///
     /// # Arguments
@@ -163,4 +456,136 @@ impl AppsManager {
             .ok_or(crate::KernelError::AppNotFound)?
             .periodicity)
     }
+
+    /// Returns the name of the app currently holding a given scheduler id.
+    ///
+    /// # Arguments
+    /// * `p_app_id` - Scheduler id to query, e.g. the owner of a device lock.
+    ///
+    /// # Returns
+    /// The name of the running app assigned to `p_app_id`.
+    ///
+    /// # Errors
+    /// Returns [`crate::KernelError::AppNotFound`] if no app currently holds `p_app_id`
+    /// (for instance if it has been stopped).
+    pub fn get_app_name_by_id(&self, p_app_id: u32) -> KernelResult<&'static str> {
+        Ok(self
+            .apps
+            .iter()
+            .find(|l_app| l_app.id == Some(p_app_id))
+            .ok_or(crate::KernelError::AppNotFound)?
+            .name)
+    }
+
+    /// Returns the current status of the app holding a given scheduler id.
+    ///
+    /// # Arguments
+    /// * `p_app_id` - Scheduler id to query.
+    ///
+    /// # Returns
+    /// The current [`AppStatus`] for the matching app.
+    ///
+    /// # Errors
+    /// Returns [`crate::KernelError::AppNotFound`] if no app currently holds `p_app_id`.
+    pub fn get_status_by_id(&self, p_app_id: u32) -> KernelResult<AppStatus> {
+        Ok(self
+            .apps
+            .iter()
+            .find(|l_app| l_app.id == Some(p_app_id))
+            .ok_or(crate::KernelError::AppNotFound)?
+            .app_status)
+    }
+}
+
+/// Extracts the app names of an autostart record.
+///
+/// # Parameters
+/// - `record`: Content of the persistent configuration area.
+/// - `crc32`: CRC function the record was signed with, the hardware CRC unit on target.
+///
+/// # Returns
+/// The names as byte slices, or `None` if the record is not valid.
+fn parse_autostart(
+    p_record: &[u8],
+    p_crc32: impl Fn(&[u8]) -> u32,
+) -> Option<Vec<&[u8], K_MAX_APPS>> {
+    if p_record.get(..4)? != K_AUTOSTART_MAGIC.to_le_bytes() {
+        return None;
+    }
+
+    let l_count = *p_record.get(4)? as usize;
+    let mut l_names = Vec::new();
+    let mut l_pos = 5;
+    for _ in 0..l_count {
+        let l_len = *p_record.get(l_pos)? as usize;
+        l_names
+            .push(p_record.get(l_pos + 1..l_pos + 1 + l_len)?)
+            .ok()?;
+        l_pos += 1 + l_len;
+    }
+
+    let l_crc = u32::from_le_bytes(p_record.get(l_pos..l_pos + 4)?.try_into().ok()?);
+    (p_crc32(&p_record[..l_pos]) == l_crc).then_some(l_names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Stand-in for the hardware CRC unit, any checksum catches the corruptions below.
+    fn checksum(p_data: &[u8]) -> u32 {
+        p_data
+            .iter()
+            .fold(0u32, |l_acc, l_byte| l_acc.rotate_left(5) ^ *l_byte as u32)
+    }
+
+    /// Builds a record the way [`AppsManager::save_autostart`] does.
+    fn record(p_names: &[&str]) -> Vec<u8, K_CONFIG_STORAGE_SIZE> {
+        let mut l_record = Vec::new();
+        l_record
+            .extend_from_slice(&K_AUTOSTART_MAGIC.to_le_bytes())
+            .unwrap();
+        l_record.push(p_names.len() as u8).unwrap();
+        for l_name in p_names {
+            l_record.push(l_name.len() as u8).unwrap();
+            l_record.extend_from_slice(l_name.as_bytes()).unwrap();
+        }
+        let l_crc = checksum(&l_record);
+        l_record.extend_from_slice(&l_crc.to_le_bytes()).unwrap();
+        // Trailing bytes of the storage area are ignored
+        l_record.extend_from_slice(&[0xFF; 8]).unwrap();
+        l_record
+    }
+
+    #[test]
+    fn valid_record_gives_the_names() {
+        let l_record = record(&["led_blink", "monitor"]);
+        let l_names = parse_autostart(&l_record, checksum).unwrap();
+        assert_eq!(l_names.as_slice(), &[&b"led_blink"[..], &b"monitor"[..]]);
+        assert!(parse_autostart(&record(&[]), checksum).unwrap().is_empty());
+    }
+
+    #[test]
+    fn erased_storage_is_rejected() {
+        assert!(parse_autostart(&[0xFF; 32], checksum).is_none());
+        assert!(parse_autostart(&[], checksum).is_none());
+    }
+
+    #[test]
+    fn corrupted_record_is_rejected() {
+        let mut l_record = record(&["led_blink"]);
+        l_record[6] ^= 0x01;
+        assert!(parse_autostart(&l_record, checksum).is_none());
+    }
+
+    #[test]
+    fn truncated_record_is_rejected() {
+        let l_record = record(&["led_blink"]);
+        assert!(parse_autostart(&l_record[..10], checksum).is_none());
+
+        // A length byte pointing past the end of the storage
+        let mut l_record = record(&["led_blink"]);
+        l_record[5] = 0xF0;
+        assert!(parse_autostart(&l_record, checksum).is_none());
+    }
 }
@@ -1,17 +1,24 @@
 use crate::apps::AppsManager;
+use crate::build_info::K_KERNEL_VERSION;
 use crate::console_output::ConsoleFormatting;
 use crate::data::Kernel;
 use crate::devices::DevicesManager;
-use crate::errors_mgt::ErrorsManager;
-use crate::ident::{K_KERNEL_MASTER_ID, K_KERNEL_NAME, K_KERNEL_VERSION};
-use crate::kernel_apps::init_kernel_apps;
-use crate::scheduler::Scheduler;
-use crate::terminal::Terminal;
-use crate::{KernelTimeData, Milliseconds, init_systick};
+use crate::errors_mgt::{ErrorsManager, set_panic_to_terminal};
+use crate::ident::{K_KERNEL_MASTER_ID, K_KERNEL_NAME};
+use crate::kernel_apps::{init_kernel_apps, set_console_heartbeat, set_heartbeat_led_name};
+use crate::power::set_low_power_idle;
+use crate::reset_reason::{capture_reset_reason, last_reset_reason};
+use crate::scheduler::{AppCall, Scheduler};
+use crate::screen_saver::set_screen_saver_timeout;
+use crate::systick::set_watchdog_timeout;
+use crate::terminal::{InputFilter, Terminal};
+use crate::{
+    K_MAX_DISPLAYS, KernelError, KernelResult, KernelTimeData, Milliseconds, init_systick,
+};
 use display::FontSize::Font24;
-use display::{Colors, Display};
-use hal_interface::Hal;
-use heapless::format;
+use display::{Colors, Display, K_DEFAULT_FRAME_BUFFER_BASE, K_FRAME_BUFFER_MEMORY_SIZE};
+use hal_interface::{Hal, InterfaceReadAction, InterfaceReadResult};
+use heapless::{Vec, format};
 
 /// Configuration parameters for the kernel boot process.
 pub struct BootConfig {
@@ -25,20 +32,80 @@ pub struct BootConfig {
     pub system_terminal: &'static str,
     /// Optional name of the LED interface to use for error indication.
     pub err_led_name: Option<&'static str>,
-    /// Optional name of the display interface to use for system output.
+    /// Optional window during which identical error messages are printed only once, 1 second
+    /// is used if not provided. `Milliseconds(0)` prints every error.
+    pub error_repeat_window: Option<Milliseconds>,
+    /// Also write panic messages to the terminal UART and display mirror before the reset.
+    /// Semihosting output is always done first; the terminal write is best-effort.
+    pub panic_to_terminal: bool,
+    /// Optional name of the display interface to use for system output. When `None`, display
+    /// functionality is disabled and display syscalls return
+    /// [`crate::KernelError::DisplayNotConfigured`].
     pub display_name: Option<&'static str>,
+    /// Additional LCD interfaces, each driven by its own display, as `(name, frame buffer
+    /// base)`. Each display owns the [`display::K_FRAME_BUFFER_MEMORY_SIZE`] bytes starting
+    /// at its base, which must not overlap the memory of another display; the primary display
+    /// uses [`display::K_DEFAULT_FRAME_BUFFER_BASE`]. The selector of an additional display is
+    /// its position in this list plus one, it is not used by the terminal mirror. At most
+    /// [`crate::K_MAX_DISPLAYS`] - 1 entries are accepted.
+    pub extra_displays: &'static [(&'static str, u32)],
+    /// Optional name of the LED interface blinked by the `heartbeat` app.
+    pub heartbeat_led_name: Option<&'static str>,
+    /// Optional prompt string of the system terminal, `>` is used if not provided.
+    pub terminal_prompt: Option<&'static str>,
+    /// Optional tab width of the system terminal, 4 columns are used if not provided.
+    pub terminal_tab_width: Option<u8>,
+    /// Optional filter applied to printable characters typed at the prompt, all printable
+    /// ASCII characters are accepted if not provided.
+    pub terminal_input_filter: Option<InputFilter>,
+    /// Names of additional interfaces (e.g. keypad) feeding the terminal prompt.
+    pub terminal_input_sources: &'static [&'static str],
+    /// Put the core to sleep (`WFI`) in [`crate::idle`] between interrupts. Some debuggers
+    /// lose the connection to a sleeping core, so this can be disabled during development.
+    pub low_power_idle: bool,
+    /// Period of the built-in task flushing pending display changes to the panel, so draws
+    /// become visible even if an app never flushes. `None` disables the task. Ignored when
+    /// no display is configured.
+    pub display_refresh_period: Option<Milliseconds>,
+    /// Optional name of an input GPIO checked at boot. When the pin is high (active-high,
+    /// e.g. a push button pulling the pin up when pressed), the kernel boots in safe mode:
+    /// autostart apps are not started and the system drops straight to the prompt. Apps can
+    /// still be started manually. A pin that cannot be read is reported and ignored.
+    pub safe_mode_pin: Option<&'static str>,
+    /// Optional software watchdog timeout. The system panics and resets when the scheduler
+    /// does not complete a cycle within this delay, e.g. because a task is hung. Must be
+    /// longer than the scheduler period and than the longest legitimate task run, as a slow
+    /// task trips the watchdog too. The kernel tasks and apps run for at most a few hundred
+    /// milliseconds, the flash writes of the persistent configuration suspend the watchdog.
+    /// `None` disables the watchdog.
+    pub watchdog_timeout: Option<Milliseconds>,
+    /// Period of the built-in task writing an "alive" line with the uptime on the terminal,
+    /// e.g. to check liveness in captured serial logs. The line is suppressed while the
+    /// terminal is at the prompt. `None` disables the task, it can still be enabled with the
+    /// `console_heartbeat` command.
+    pub console_heartbeat_period: Option<Milliseconds>,
+    /// Idle duration after which the display backlight is switched off, until the next
+    /// terminal input or display syscall (see the `screen_saver` module). `Milliseconds(0)`
+    /// disables the screen saver. Ignored when no display is configured.
+    pub screen_saver_timeout: Milliseconds,
 }
 
+/// Name of the scheduler task flushing the display.
+const K_DISPLAY_REFRESH_APP_NAME: &str = "DISPLAY_REFRESH";
+
 /// Initializes and starts the kernel.
 ///
 /// This function performs the following steps:
-/// 1. Initializes global kernel data (scheduler, hal, terminal, etc.).
+/// 1. Captures the cause of the previous reset, then initializes global kernel data
+///    (scheduler, hal, terminal, etc.).
 /// 2. Configures the HAL locker with the kernel master ID.
 /// 3. Initializes the error manager and display.
 /// 4. Starts the system terminal and logs boot information.
-/// 5. Initializes and starts the SysTick timer.
+/// 5. Initializes and starts the SysTick timer, and configures the software watchdog.
 /// 6. Starts the kernel scheduler.
-/// 7. Registers core kernel applications.
+/// 7. Registers the display refresh task, if enabled, and core kernel applications. Autostart
+///    apps are skipped in safe mode (see [`BootConfig::safe_mode_pin`]).
+/// 8. Starts the console heartbeat task, if enabled.
 ///
 /// # Parameters
 /// - `p_config`: The [`BootConfig`] containing all necessary parameters for booting.
@@ -50,6 +117,7 @@ pub fn boot(p_config: BootConfig) {
     //////////////////////////
     // Kernel initialization
     //////////////////////////
+    capture_reset_reason();
     let l_sched = Scheduler::new(p_config.sched_period);
     Kernel::init_kernel_data(
         p_config.hal,
@@ -67,21 +135,58 @@ pub fn boot(p_config: BootConfig) {
     // Errors Manager initialization
     ////////////////////////////////////
     Kernel::errors().init(p_config.err_led_name).unwrap();
+    if let Some(l_window) = p_config.error_repeat_window {
+        Kernel::errors().set_repeat_window(l_window);
+    }
+    set_panic_to_terminal(p_config.panic_to_terminal);
 
     //////////////////////////
     // Display initialization
     //////////////////////////
-    Kernel::display()
-        .init(p_config.display_name.unwrap(), Kernel::hal(), Colors::Black)
-        .unwrap();
-    Kernel::display().set_font(Font24).unwrap();
+    let mut l_fb_bases: Vec<u32, K_MAX_DISPLAYS> = Vec::new();
+    if let Some(l_display_name) = p_config.display_name {
+        Kernel::display()
+            .init(
+                l_display_name,
+                Kernel::hal(),
+                Colors::Black,
+                K_DEFAULT_FRAME_BUFFER_BASE,
+            )
+            .unwrap();
+        Kernel::display().set_font(Font24).unwrap();
+        l_fb_bases.push(K_DEFAULT_FRAME_BUFFER_BASE).unwrap();
+    }
+    for &(l_display_name, l_fb_base) in p_config.extra_displays {
+        // Two drivers on the same interface or frame buffers would overwrite each other
+        if Kernel::display_by_name(l_display_name).is_ok() {
+            panic!("Display {} configured twice", l_display_name);
+        }
+        if l_fb_bases
+            .iter()
+            .any(|l_base| l_base.abs_diff(l_fb_base) < K_FRAME_BUFFER_MEMORY_SIZE)
+        {
+            panic!(
+                "Display {} frame buffers overlap another display",
+                l_display_name
+            );
+        }
+        let mut l_display = Display::new(K_KERNEL_MASTER_ID);
+        l_display
+            .init(l_display_name, Kernel::hal(), Colors::Black, l_fb_base)
+            .unwrap();
+        l_display.set_font(Font24).unwrap();
+        Kernel::add_display(l_display).unwrap();
+        l_fb_bases.push(l_fb_base).unwrap();
+    }
 
     ////////////////////////////
     // Terminal start
     ////////////////////////////
     let l_terminal = Kernel::terminal();
     l_terminal.set_display_mode().unwrap();
-    l_terminal.set_display_mirror(true).unwrap();
+    if p_config.display_name.is_some() {
+        l_terminal.set_display_mirror(true).unwrap();
+    }
     l_terminal.write(&ConsoleFormatting::Clear).unwrap();
     l_terminal
         .write(&ConsoleFormatting::StrNewLineAfter("Booting..."))
@@ -100,11 +205,19 @@ pub fn boot(p_config: BootConfig) {
                 .as_str(),
         ))
         .unwrap();
+    l_terminal
+        .write(&ConsoleFormatting::StrNewLineAfter(
+            format!(30; "Last reset: {}", last_reset_reason().name())
+                .unwrap()
+                .as_str(),
+        ))
+        .unwrap();
 
     ////////////////////////////////////
     // Systick initialization
     ////////////////////////////////////
-    init_systick(Some(p_config.kernel_time_data.systick_period));
+    init_systick(Some(p_config.kernel_time_data.systick_period)).unwrap();
+    set_watchdog_timeout(p_config.watchdog_timeout, p_config.sched_period).unwrap();
 
     //Boot completed
     l_terminal.set_color(Colors::Green).unwrap();
@@ -118,9 +231,91 @@ pub fn boot(p_config: BootConfig) {
         .unwrap();
 
     // Set terminal in prompt mode
+    l_terminal.set_tx_buffered(true).unwrap();
     l_terminal.set_display_mirror(false).unwrap();
+    if let Some(l_prompt) = p_config.terminal_prompt {
+        l_terminal.set_prompt(l_prompt);
+    }
+    if let Some(l_width) = p_config.terminal_tab_width {
+        l_terminal.set_tab_width(l_width);
+    }
+    if let Some(l_filter) = p_config.terminal_input_filter {
+        l_terminal.set_input_filter(l_filter);
+    }
     l_terminal.set_prompt_mode().unwrap();
+    for l_source in p_config.terminal_input_sources {
+        let l_id = Kernel::hal().get_interface_id(l_source).unwrap();
+        l_terminal.register_input_source(l_id).unwrap();
+    }
+
+    // Start display refresh task
+    if let Some(l_period) = p_config.display_refresh_period
+        && p_config.display_name.is_some()
+    {
+        Kernel::scheduler()
+            .add_periodic_app(
+                K_DISPLAY_REFRESH_APP_NAME,
+                AppCall::App(display_refresh),
+                None,
+                l_period,
+                None,
+                false,
+            )
+            .unwrap();
+    }
 
     // Initialize kernel applications
-    init_kernel_apps().unwrap();
+    set_heartbeat_led_name(p_config.heartbeat_led_name);
+    let l_safe_mode = p_config.safe_mode_pin.is_some_and(safe_mode_requested);
+    if l_safe_mode {
+        l_terminal
+            .write(&ConsoleFormatting::StrNewLineBoth(
+                "Safe mode: autostart apps skipped",
+            ))
+            .unwrap();
+    }
+    init_kernel_apps(l_safe_mode).unwrap();
+    set_console_heartbeat(p_config.console_heartbeat_period).unwrap();
+    if p_config.display_name.is_some() {
+        set_screen_saver_timeout(p_config.screen_saver_timeout).unwrap();
+    }
+
+    // Configure idle hook
+    set_low_power_idle(p_config.low_power_idle);
+}
+
+/// Reads the safe mode pin.
+///
+/// # Parameters
+/// - `pin_name`: Name of the GPIO interface to read.
+///
+/// # Returns
+/// `true` if the pin is high. Read errors are reported by the error handler and return `false`,
+/// so a misconfigured pin never blocks the autostart apps.
+fn safe_mode_requested(p_pin_name: &'static str) -> bool {
+    let l_result = Kernel::hal()
+        .get_interface_id(p_pin_name)
+        .and_then(|l_id| {
+            Kernel::hal().interface_read(l_id, K_KERNEL_MASTER_ID, InterfaceReadAction::GpioRead)
+        })
+        .map_err(KernelError::HalError);
+
+    match l_result {
+        Ok(InterfaceReadResult::GpioRead(l_high)) => l_high,
+        Ok(_) => false,
+        Err(l_err) => {
+            Kernel::errors().error_handler(&l_err);
+            false
+        }
+    }
+}
+
+/// Scheduler task body: flush pending display changes to the panel.
+///
+/// Does nothing when no region was modified since the last flush.
+///
+/// # Errors
+/// Returns [`KernelError::DisplayError`] if the flush fails.
+fn display_refresh() -> KernelResult<()> {
+    Kernel::display().flush().map_err(KernelError::DisplayError)
 }
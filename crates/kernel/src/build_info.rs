@@ -0,0 +1,13 @@
+//! Build-time information embedded in the firmware.
+//!
+//! Values are captured at compile time so they can be reported without any runtime dependency.
+
+/// Kernel version, taken from the crate manifest.
+pub const K_KERNEL_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Build profile the firmware was compiled with.
+pub const K_BUILD_PROFILE: &str = if cfg!(debug_assertions) {
+    "debug"
+} else {
+    "release"
+};
@@ -0,0 +1,102 @@
+//! Blinking caret for the display terminal mirror.
+//!
+//! While the terminal is in prompt mode with a display mirror, a kernel task toggles a block
+//! caret at the display text cursor every [`K_CARET_PERIOD`]. The pixels under the caret are
+//! saved before it is drawn and written back when it is hidden, so blinking does not alter the
+//! screen content. The terminal hides the caret before each mirror write, as the text cursor
+//! may move away from the caret cell.
+//!
+//! The task is started and stopped by the terminal, see `Terminal::update_caret_task`.
+
+use display::Colors;
+use spin::Mutex;
+
+use crate::data::Kernel;
+use crate::{KernelError, KernelResult, Milliseconds, critical_section};
+
+/// Name of the caret blink task in the scheduler.
+pub(crate) const K_CARET_APP_NAME: &str = "CARET_BLINK";
+/// Toggle period of the caret.
+pub(crate) const K_CARET_PERIOD: Milliseconds = Milliseconds(500);
+/// Color of the caret block.
+const K_CARET_COLOR: Colors = Colors::White;
+/// Number of pixels of the largest built-in character cell (`Font24`, unscaled).
+const K_CARET_MAX_PIXELS: usize = 17 * 24;
+
+/// Caret drawing state.
+struct CaretState {
+    /// Cell covered by the caret as `(x, y, width, height)`, `None` when hidden.
+    shown_at: Option<(u16, u16, u16, u16)>,
+    /// Pixels saved from under the caret.
+    saved: [u32; K_CARET_MAX_PIXELS],
+}
+
+/// Caret state shared by the blink task and the terminal.
+static G_CARET: Mutex<CaretState> = Mutex::new(CaretState {
+    shown_at: None,
+    saved: [0; K_CARET_MAX_PIXELS],
+});
+
+/// Periodic task toggling the caret.
+///
+/// When hidden, the character cell at the display cursor is saved and covered by a block
+/// drawn with inverted colors. When shown, the saved cell is restored. Nothing is drawn if
+/// the cursor cell does not fit on the screen or is larger than [`K_CARET_MAX_PIXELS`].
+///
+/// # Errors
+/// Returns [`KernelError::DisplayError`] if drawing the caret or restoring the cell fails.
+pub(crate) fn caret_blink() -> KernelResult<()> {
+    critical_section(|| {
+        let mut l_caret = G_CARET.lock();
+        if l_caret.shown_at.is_some() {
+            return restore(&mut l_caret);
+        }
+
+        let l_display = Kernel::display();
+        let (l_x, l_y) = l_display
+            .get_cursor_pos()
+            .map_err(KernelError::DisplayError)?;
+        let (l_width, l_height) = l_display.get_char_cell();
+        let l_len = l_width as usize * l_height as usize;
+
+        // Out of screen cell or cell larger than the built-in fonts, nothing to blink
+        if l_len > K_CARET_MAX_PIXELS
+            || l_display
+                .save_region(l_x, l_y, l_width, l_height, &mut l_caret.saved[..l_len])
+                .is_err()
+        {
+            return Ok(());
+        }
+
+        l_display
+            .draw_char_bg(b' ', l_x, l_y, Colors::Black, K_CARET_COLOR)
+            .map_err(KernelError::DisplayError)?;
+        l_caret.shown_at = Some((l_x, l_y, l_width, l_height));
+        Ok(())
+    })
+}
+
+/// Hides the caret if it is shown, restoring the pixels under it.
+///
+/// # Errors
+/// Returns [`KernelError::DisplayError`] if restoring the cell fails.
+pub(crate) fn hide_caret() -> KernelResult<()> {
+    critical_section(|| restore(&mut G_CARET.lock()))
+}
+
+/// Restores the cell saved under the caret and marks the caret as hidden.
+///
+/// # Parameters
+/// - `caret`: Locked caret state.
+///
+/// # Errors
+/// Returns [`KernelError::DisplayError`] if restoring the cell fails.
+fn restore(p_caret: &mut CaretState) -> KernelResult<()> {
+    if let Some((l_x, l_y, l_width, l_height)) = p_caret.shown_at.take() {
+        let l_len = l_width as usize * l_height as usize;
+        Kernel::display()
+            .restore_region(l_x, l_y, l_width, l_height, &p_caret.saved[..l_len])
+            .map_err(KernelError::DisplayError)?;
+    }
+    Ok(())
+}
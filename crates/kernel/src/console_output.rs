@@ -1,11 +1,29 @@
 use crate::console_output::ConsoleOutputType::{Display, Usart};
 use crate::data::Kernel;
 use crate::ident::K_KERNEL_MASTER_ID;
-use crate::{KernelError, syscall_devices};
+use crate::{K_PRIMARY_DISPLAY, KernelError, critical_section, syscall_devices};
 
 use crate::{KernelResult, SysCallDisplayArgs, SysCallHalActions, syscall_display, syscall_hal};
+use core::cell::Cell;
+use core::sync::atomic::{AtomicU32, Ordering};
+use cortex_m::peripheral::SCB;
+use cortex_m::peripheral::scb::{Exception, VectActive};
 use display::Colors;
 use hal_interface::{InterfaceWriteActions, UartWriteActions};
+use heapless::{Deque, Vec, format};
+use spin::Mutex;
+
+/// Default number of columns between two tab stops.
+pub const K_DEFAULT_TAB_WIDTH: u8 = 4;
+/// Spaces written in one call when expanding a tab.
+const K_TAB_SPACES: &str = "                ";
+/// Size of the USART transmit queue in bytes.
+pub const K_TX_QUEUE_SIZE: usize = 1024;
+/// Maximum number of bytes sent by one call to [`ConsoleOutput::drain`].
+///
+/// The UART driver transmits in blocking mode, this bounds the time spent by the drain task
+/// (about 11 ms at 115200 bauds).
+const K_TX_DRAIN_CHUNK: usize = 128;
 
 /// Console output formatting directives used by higher-level console printing APIs.
 ///
@@ -51,13 +69,24 @@ pub enum ConsoleOutputType {
 /// It is created via [`ConsoleOutput::new`] which locks the underlying resource
 /// (a named HAL UART/USART interface or the display device) using `K_KERNEL_MASTER_ID`.
 ///
-/// The struct also tracks the `current_color` used for display rendering (ignored for USART).
+/// The struct also tracks the `current_color` used for display rendering (ignored for USART),
+/// and the current column used to expand tab characters to the next multiple of `tab_width`.
+///
+/// USART output can be buffered, see [`ConsoleOutput::set_tx_buffered`]: writes are then
+/// queued and sent later by [`ConsoleOutput::drain`], so a verbose app does not stall on the
+/// serial line. When the queue is full, the writer blocks until enough bytes are sent, except
+/// in interrupt handlers where the bytes which do not fit are dropped.
 ///
 /// Call [`ConsoleOutput::release`] to unlock the underlying destination when done.
 pub struct ConsoleOutput {
     pub interface_id: Option<usize>,
     pub output: ConsoleOutputType,
     pub current_color: Colors,
+    pub tab_width: u8,
+    column: Cell<u16>,
+    tx_buffered: Cell<bool>,
+    tx_queue: Mutex<Deque<u8, K_TX_QUEUE_SIZE>>,
+    tx_dropped: AtomicU32,
 }
 
 impl ConsoleOutput {
@@ -72,6 +101,8 @@ impl ConsoleOutput {
     /// - `current_color`: The active display color used when `output` is `Display`
     ///   (ignored for USART).
     ///
+    /// The tab width is set to [`K_DEFAULT_TAB_WIDTH`].
+    ///
     /// # Returns
     /// - `ConsoleOutput`.
     pub fn new(p_output: ConsoleOutputType, p_current_color: Colors) -> Self {
@@ -79,6 +110,11 @@ impl ConsoleOutput {
             interface_id: None,
             output: p_output,
             current_color: p_current_color,
+            tab_width: K_DEFAULT_TAB_WIDTH,
+            column: Cell::new(0),
+            tx_buffered: Cell::new(false),
+            tx_queue: Mutex::new(Deque::new()),
+            tx_dropped: AtomicU32::new(0),
         }
     }
 
@@ -112,7 +148,10 @@ impl ConsoleOutput {
                 .map_err(KernelError::HalError)?;
         } else {
             // Try to lock the display device
-            Kernel::devices().lock(crate::DeviceType::Display, K_KERNEL_MASTER_ID)?;
+            Kernel::devices().lock(
+                crate::DeviceType::Display(K_PRIMARY_DISPLAY),
+                K_KERNEL_MASTER_ID,
+            )?;
         }
 
         Ok(())
@@ -137,6 +176,9 @@ impl ConsoleOutput {
     /// For Display output, the character is written at the current cursor position using
     /// `current_color`.
     ///
+    /// A tab character is expanded to spaces up to the next multiple of `tab_width`, unless
+    /// `tab_width` is `0`.
+    ///
     /// # Parameters
     /// - `data`: The character to write.
     ///
@@ -148,20 +190,25 @@ impl ConsoleOutput {
     /// - For USART: errors from `syscall_hal(...)` are propagated.
     /// - For Display: errors from `syscall_display(...)` are propagated.
     pub(crate) fn write_char(&self, p_data: char) -> KernelResult<()> {
+        if p_data == '\t' && self.tab_width > 0 {
+            return self.write_tab();
+        }
+
         match self.output {
-            Usart(_) => syscall_hal(
-                self.interface_id.unwrap(),
-                SysCallHalActions::Write(InterfaceWriteActions::UartWrite(
-                    UartWriteActions::SendChar(p_data as u8),
-                )),
-                K_KERNEL_MASTER_ID,
-            )?,
+            Usart(_) => self.send_bytes(&[p_data as u8])?,
             Display => syscall_display(
                 SysCallDisplayArgs::WriteCharAtCursor(p_data, Some(self.current_color)),
                 K_KERNEL_MASTER_ID,
             )?,
         }
 
+        // Track column
+        match p_data {
+            '\r' | '\n' => self.column.set(0),
+            '\x08' => self.column.set(self.column.get().saturating_sub(1)),
+            _ => self.column.set(self.column.get().wrapping_add(1)),
+        }
+
         Ok(())
     }
 
@@ -171,6 +218,9 @@ impl ConsoleOutput {
     /// For Display output, the string is written at the current cursor position using
     /// `current_color`.
     ///
+    /// Tab characters are expanded as in [`ConsoleOutput::write_char`]; the text between tabs
+    /// is still written in one call.
+    ///
     /// # Parameters
     /// - `data`: The string slice to write.
     ///
@@ -182,20 +232,236 @@ impl ConsoleOutput {
     /// - For USART: errors from `syscall_hal(...)` are propagated.
     /// - For Display: errors from `syscall_display(...)` are propagated.
     pub(crate) fn write_str(&self, p_data: &str) -> KernelResult<()> {
+        if self.tab_width == 0 {
+            return self.write_segment(p_data);
+        }
+
+        let mut l_segments = p_data.split('\t');
+        self.write_segment(l_segments.next().unwrap_or_default())?;
+        for l_segment in l_segments {
+            self.write_tab()?;
+            self.write_segment(l_segment)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes a string slice without tab expansion and updates the column.
+    ///
+    /// # Parameters
+    /// - `data`: The string slice to write.
+    ///
+    /// # Errors
+    /// Propagates errors from the underlying syscall.
+    fn write_segment(&self, p_data: &str) -> KernelResult<()> {
+        if p_data.is_empty() {
+            return Ok(());
+        }
+
         match self.output {
-            Usart(_) => syscall_hal(
-                self.interface_id.unwrap(),
-                SysCallHalActions::Write(InterfaceWriteActions::UartWrite(
-                    UartWriteActions::SendString(p_data),
-                )),
-                K_KERNEL_MASTER_ID,
-            )?,
+            Usart(_) => self.send_bytes(p_data.as_bytes())?,
             Display => syscall_display(
                 SysCallDisplayArgs::WriteStrAtCursor(p_data, Some(self.current_color)),
                 K_KERNEL_MASTER_ID,
             )?,
         }
 
+        // Column restarts after the last line break
+        match p_data.rfind(['\r', '\n']) {
+            Some(l_index) => self.column.set((p_data.len() - l_index - 1) as u16),
+            None => self
+                .column
+                .set(self.column.get().wrapping_add(p_data.len() as u16)),
+        }
+
+        Ok(())
+    }
+
+    /// Writes raw bytes to the configured output, without tab expansion.
+    ///
+    /// For USART output, the bytes are sent unchanged, so control sequences (cursor
+    /// positioning, colors...) reach the host terminal. For Display output, which cannot
+    /// interpret control sequences, only printable ASCII bytes, `'\r'` and `'\n'` are
+    /// written and the other bytes are skipped.
+    ///
+    /// The tracked column is reset to `0`, as the effect of the bytes on the cursor is unknown.
+    ///
+    /// # Parameters
+    /// - `bytes`: The bytes to write.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying syscall fails:
+    /// - For USART: errors from `syscall_hal(...)` are propagated.
+    /// - For Display: errors from `syscall_display(...)` are propagated.
+    pub(crate) fn write_raw(&self, p_bytes: &[u8]) -> KernelResult<()> {
+        match self.output {
+            Usart(_) => self.send_bytes(p_bytes)?,
+            Display => {
+                for l_byte in p_bytes
+                    .iter()
+                    .filter(|l_b| l_b.is_ascii_graphic() || matches!(l_b, b' ' | b'\r' | b'\n'))
+                {
+                    syscall_display(
+                        SysCallDisplayArgs::WriteCharAtCursor(
+                            *l_byte as char,
+                            Some(self.current_color),
+                        ),
+                        K_KERNEL_MASTER_ID,
+                    )?;
+                }
+            }
+        }
+
+        self.column.set(0);
+        Ok(())
+    }
+
+    /// Writes spaces up to the next tab stop.
+    ///
+    /// # Errors
+    /// Propagates errors from the underlying syscall.
+    fn write_tab(&self) -> KernelResult<()> {
+        let l_width = self.tab_width as u16;
+        let mut l_count = (l_width - self.column.get() % l_width) as usize;
+        while l_count > 0 {
+            let l_chunk = l_count.min(K_TAB_SPACES.len());
+            self.write_segment(&K_TAB_SPACES[..l_chunk])?;
+            l_count -= l_chunk;
+        }
+        Ok(())
+    }
+
+    /// Sends bytes to the USART, or queues them when the output is buffered.
+    ///
+    /// When the queue cannot hold all the bytes, it is flushed first, so bytes are not dropped
+    /// and their order is kept. An interrupt handler may have preempted
+    /// [`ConsoleOutput::drain`], so it never flushes: the bytes which do not fit are dropped
+    /// and counted, the count is reported by the next drain which empties the queue.
+    ///
+    /// # Parameters
+    /// - `bytes`: The bytes to send.
+    ///
+    /// # Errors
+    /// Propagates errors from `syscall_hal(...)`.
+    fn send_bytes(&self, p_bytes: &[u8]) -> KernelResult<()> {
+        if !self.tx_buffered.get() {
+            return self.transmit(p_bytes);
+        }
+
+        let mut l_remaining = p_bytes;
+        while !l_remaining.is_empty() {
+            // The queue is shared with writers running in interrupt context
+            let l_queued = critical_section(|| {
+                let mut l_queue = self.tx_queue.lock();
+                l_remaining
+                    .iter()
+                    .take_while(|l_byte| l_queue.push_back(**l_byte).is_ok())
+                    .count()
+            });
+            l_remaining = &l_remaining[l_queued..];
+
+            if !l_remaining.is_empty() {
+                if !may_block() {
+                    self.tx_dropped
+                        .fetch_add(l_remaining.len() as u32, Ordering::Relaxed);
+                    return Ok(());
+                }
+                self.flush()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sends bytes to the USART immediately.
+    ///
+    /// # Errors
+    /// Propagates errors from `syscall_hal(...)`.
+    fn transmit(&self, p_bytes: &[u8]) -> KernelResult<()> {
+        syscall_hal(
+            self.interface_id.unwrap(),
+            SysCallHalActions::Write(InterfaceWriteActions::UartWrite(
+                UartWriteActions::SendBytes(p_bytes),
+            )),
+            K_KERNEL_MASTER_ID,
+        )
+    }
+
+    /// Enables or disables the USART transmit queue.
+    ///
+    /// While disabled (the default), every write is transmitted before returning. Buffering
+    /// must only be enabled once something calls [`ConsoleOutput::drain`] periodically. The
+    /// queue is flushed when buffering is disabled. Has no effect on a display output.
+    ///
+    /// # Parameters
+    /// - `buffered`: `true` to queue the writes, `false` to transmit them immediately.
+    ///
+    /// # Errors
+    /// Propagates errors from [`ConsoleOutput::flush`] when disabling.
+    pub(crate) fn set_tx_buffered(&self, p_buffered: bool) -> KernelResult<()> {
+        if let Usart(_) = self.output {
+            if !p_buffered {
+                self.flush()?;
+            }
+            self.tx_buffered.set(p_buffered);
+        }
+        Ok(())
+    }
+
+    /// Returns whether the USART output is currently buffered.
+    pub(crate) fn is_tx_buffered(&self) -> bool {
+        self.tx_buffered.get()
+    }
+
+    /// Sends up to [`K_TX_DRAIN_CHUNK`] queued bytes to the USART.
+    ///
+    /// Bytes are removed from the queue inside a critical section and transmitted outside of
+    /// it, so interrupts are not blocked during the transmission.
+    /// Once the queue is empty, the number of bytes dropped by interrupt handlers since the
+    /// last report, if any, is sent.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if bytes are still queued after the call.
+    ///
+    /// # Errors
+    /// Propagates errors from `syscall_hal(...)`, the bytes taken from the queue are lost.
+    pub(crate) fn drain(&self) -> KernelResult<bool> {
+        let mut l_chunk: Vec<u8, K_TX_DRAIN_CHUNK> = Vec::new();
+        let l_pending = critical_section(|| {
+            let mut l_queue = self.tx_queue.lock();
+            while !l_chunk.is_full()
+                && let Some(l_byte) = l_queue.pop_front()
+            {
+                l_chunk.push(l_byte).unwrap();
+            }
+            !l_queue.is_empty()
+        });
+
+        if !l_chunk.is_empty() {
+            self.transmit(&l_chunk)?;
+        }
+
+        if !l_pending {
+            let l_dropped = self.tx_dropped.swap(0, Ordering::Relaxed);
+            if l_dropped > 0 {
+                self.transmit(
+                    format!(40; "\r\n[{} bytes dropped]\r\n", l_dropped)
+                        .unwrap()
+                        .as_bytes(),
+                )?;
+            }
+        }
+        Ok(l_pending)
+    }
+
+    /// Sends all queued bytes, blocking until the queue is empty.
+    ///
+    /// Meant for critical messages which must be out before going on, e.g. before a reset.
+    ///
+    /// # Errors
+    /// Propagates errors from `syscall_hal(...)`.
+    pub(crate) fn flush(&self) -> KernelResult<()> {
+        while self.drain()? {}
         Ok(())
     }
 
@@ -203,7 +469,7 @@ impl ConsoleOutput {
     ///
     /// - For USART output, emits the ANSI escape sequence `ESC[2JESC[H` to clear the screen
     ///   and move the cursor to the home position.
-    /// - For Display output, clears the display using a black background.
+    /// - For Display output, clears the display to its default background color.
     ///
     /// # Returns
     /// - `Ok(())` if the clear operation succeeds.
@@ -213,17 +479,10 @@ impl ConsoleOutput {
     /// - For USART: errors from `syscall_hal(...)` are propagated.
     /// - For Display: errors from `syscall_display(...)` are propagated.
     pub fn clear_terminal(&self) -> KernelResult<()> {
+        self.column.set(0);
         match self.output {
-            Usart(_) => syscall_hal(
-                self.interface_id.unwrap(),
-                SysCallHalActions::Write(InterfaceWriteActions::UartWrite(
-                    UartWriteActions::SendString("\x1B[2J\x1B[H"),
-                )),
-                K_KERNEL_MASTER_ID,
-            )?,
-            Display => {
-                syscall_display(SysCallDisplayArgs::Clear(Colors::Black), K_KERNEL_MASTER_ID)?
-            }
+            Usart(_) => self.send_bytes(b"\x1B[2J\x1B[H")?,
+            Display => syscall_display(SysCallDisplayArgs::ClearDefault, K_KERNEL_MASTER_ID)?,
         }
 
         Ok(())
@@ -243,7 +502,8 @@ impl ConsoleOutput {
 
     /// Releases/unlocks the currently held console output destination.
     ///
-    /// This undoes the exclusive lock acquired by [`ConsoleOutput::new`]:
+    /// Queued USART bytes are sent first. This undoes the exclusive lock acquired by
+    /// [`ConsoleOutput::new`]:
     /// - For [`ConsoleOutputType::Usart`], unlocks the underlying peripheral interface
     ///   associated with `interface_id`.
     /// - For [`ConsoleOutputType::Display`], unlocks the display device.
@@ -257,6 +517,7 @@ impl ConsoleOutput {
     /// # Errors
     /// Propagates any error returned by `syscall_devices(...)` while unlocking.
     pub fn release(&mut self) -> KernelResult<()> {
+        self.set_tx_buffered(false)?;
         match self.output {
             Usart(_) => syscall_devices(
                 crate::DeviceType::Peripheral(self.interface_id.unwrap()),
@@ -264,10 +525,21 @@ impl ConsoleOutput {
                 K_KERNEL_MASTER_ID,
             ),
             Display => syscall_devices(
-                crate::DeviceType::Display,
+                crate::DeviceType::Display(K_PRIMARY_DISPLAY),
                 crate::SysCallDevicesArgs::Unlock,
                 K_KERNEL_MASTER_ID,
             ),
         }
     }
 }
+
+/// Returns whether the caller may wait for the USART transmission.
+///
+/// Thread mode and the scheduler tasks, run from PendSV, never preempt
+/// [`ConsoleOutput::drain`]. Other exception and interrupt handlers may.
+fn may_block() -> bool {
+    matches!(
+        SCB::vect_active(),
+        VectActive::ThreadMode | VectActive::Exception(Exception::PendSV)
+    )
+}
@@ -2,11 +2,19 @@ use crate::apps::AppsManager;
 use crate::devices::DevicesManager;
 use crate::errors_mgt::ErrorsManager;
 use crate::scheduler::Scheduler;
+use crate::stack::stack_fill;
 use crate::terminal::Terminal;
-use crate::{Mhz, Milliseconds};
+use crate::{KernelError, KernelResult, Mhz, Milliseconds};
 use cortex_m::Peripherals;
 use display::Display;
 use hal_interface::Hal;
+use heapless::Vec;
+
+/// Maximum number of displays managed by the kernel, primary display included.
+pub const K_MAX_DISPLAYS: usize = 2;
+
+/// Selector of the primary display, the one used by the terminal mirror and error screens.
+pub const K_PRIMARY_DISPLAY: usize = 0;
 
 pub static mut G_KERNEL_DATA: Kernel = Kernel {
     cortex_peripherals: None,
@@ -15,7 +23,7 @@ pub static mut G_KERNEL_DATA: Kernel = Kernel {
     terminal: None,
     scheduler: None,
     errors: None,
-    display: None,
+    displays: Vec::new(),
     apps: None,
     devices: None,
 };
@@ -73,8 +81,9 @@ pub struct KernelTimeData {
 /// * `errors` - An optional field for the error manager, which tracks and manages system errors
 ///   or exceptions. Provides mechanisms for error logging or recovery during runtime.
 ///
-/// * `display` - An optional field representing the display driver, used for rendering
-///   graphical or textual information to the screen.
+/// * `displays` - The display drivers, used for rendering graphical or textual information to
+///   the screens. The primary display is always at index [`K_PRIMARY_DISPLAY`], even when it is
+///   not initialized, additional displays follow in registration order.
 ///
 /// * `apps` - An optional field for the applications manager, which handles the registration,
 ///   lifecycle, and execution of user applications.
@@ -100,7 +109,7 @@ pub struct Kernel {
     terminal: Option<Terminal>,
     scheduler: Option<Scheduler>,
     errors: Option<ErrorsManager>,
-    display: Option<Display>,
+    displays: Vec<Display, K_MAX_DISPLAYS>,
     apps: Option<AppsManager>,
     devices: Option<DevicesManager>,
 }
@@ -111,7 +120,7 @@ impl Kernel {
     /// # Arguments
     ///
     /// * `hal` - A hardware abstraction layer (`Hal`) instance for interacting with low-level hardware features.
-    /// * `display` - A `Display` instance to handle graphical or textual output.
+    /// * `display` - The primary `Display` instance to handle graphical or textual output.
     /// * `kernel_time_data` - A `KernelTimeData` instance to manage kernel-related timing and scheduling.
     /// * `terminal` - A `Terminal` instance to handle terminal input/output interactions.
     /// * `scheduler` - A `Scheduler` instance responsible for managing task scheduling.
@@ -144,7 +153,9 @@ impl Kernel {
     ) {
         unsafe {
             G_KERNEL_DATA.hal = Some(p_hal);
-            G_KERNEL_DATA.display = Some(p_display);
+            let mut l_displays = Vec::new();
+            l_displays.push(p_display).ok();
+            G_KERNEL_DATA.displays = l_displays;
             G_KERNEL_DATA.kernel_time_data = Some(p_kernel_time_data);
             G_KERNEL_DATA.terminal = Some(p_terminal);
             G_KERNEL_DATA.scheduler = Some(p_scheduler);
@@ -189,29 +200,99 @@ impl Kernel {
         }
     }
 
-    /// Provides a mutable reference to the global display driver.
+    /// Provides a mutable reference to the primary display driver.
     ///
-    /// This function retrieves a mutable reference to the global `Display` object stored within
-    /// the `KERNEL_DATA` structure. If the `Display` driver has already been initialized,
-    /// it safely accesses the `Display`. If the driver is not initialized, it panics with an error message.
+    /// This function retrieves a mutable reference to the `Display` object stored at index
+    /// [`K_PRIMARY_DISPLAY`] within the `KERNEL_DATA` structure. If the `Display` driver has
+    /// already been registered, it safely accesses the `Display`. If the driver is not
+    /// registered, it panics with an error message.
     ///
     /// # Safety
     /// - The function uses `unsafe` to access a static mutable reference. Static mutable references
     ///   can lead to undefined behavior if improperly used. Ensure no simultaneous mutable and immutable
     ///   borrows occur to maintain memory safety.
-    /// - This function assumes that the global `KERNEL_DATA.display` has been properly initialized
+    /// - This function assumes that the global `KERNEL_DATA.displays` has been properly initialized
     ///
     #[allow(static_mut_refs)]
     pub fn display() -> &'static mut Display {
         unsafe {
-            if G_KERNEL_DATA.display.is_some() {
-                G_KERNEL_DATA.display.as_mut().unwrap()
+            if let Some(l_display) = G_KERNEL_DATA.displays.get_mut(K_PRIMARY_DISPLAY) {
+                l_display
             } else {
                 panic!("Display driver not initialized");
             }
         }
     }
 
+    /// Provides a mutable reference to the display driver matching a selector.
+    ///
+    /// # Parameters
+    /// - `display`: Index of the display, [`K_PRIMARY_DISPLAY`] for the primary display.
+    ///
+    /// # Errors
+    /// Returns [`KernelError::DisplayNotConfigured`] if no display is registered at this index.
+    #[allow(static_mut_refs)]
+    pub fn display_at(p_display: usize) -> KernelResult<&'static mut Display> {
+        unsafe {
+            G_KERNEL_DATA
+                .displays
+                .get_mut(p_display)
+                .ok_or(KernelError::DisplayNotConfigured)
+        }
+    }
+
+    /// Provides a mutable reference to the initialized display driving an LCD interface.
+    ///
+    /// # Parameters
+    /// - `name`: Name of the LCD interface, as given to [`Display::init`].
+    ///
+    /// # Errors
+    /// Returns [`KernelError::DisplayNotConfigured`] if no display drives this interface.
+    pub fn display_by_name(p_name: &str) -> KernelResult<&'static mut Display> {
+        Kernel::display_at(Kernel::display_index(p_name)?)
+    }
+
+    /// Returns the selector of the initialized display driving an LCD interface.
+    ///
+    /// # Parameters
+    /// - `name`: Name of the LCD interface, as given to [`Display::init`].
+    ///
+    /// # Errors
+    /// Returns [`KernelError::DisplayNotConfigured`] if no display drives this interface.
+    #[allow(static_mut_refs)]
+    pub fn display_index(p_name: &str) -> KernelResult<usize> {
+        unsafe {
+            G_KERNEL_DATA
+                .displays
+                .iter()
+                .position(|l_display| l_display.get_name() == Some(p_name))
+                .ok_or(KernelError::DisplayNotConfigured)
+        }
+    }
+
+    /// Registers an additional display after the primary one.
+    ///
+    /// # Parameters
+    /// - `display`: The display driver, usually already initialized.
+    ///
+    /// # Returns
+    /// The selector of the new display.
+    ///
+    /// # Errors
+    /// Returns [`KernelError::TooManyDisplays`] if [`K_MAX_DISPLAYS`] displays are already
+    /// registered.
+    #[allow(static_mut_refs)]
+    pub(crate) fn add_display(p_display: Display) -> KernelResult<usize> {
+        unsafe {
+            let l_name = p_display.get_name().unwrap_or("?");
+            G_KERNEL_DATA
+                .displays
+                .push(p_display)
+                .map_err(|_| KernelError::TooManyDisplays(l_name))?;
+            Ok(G_KERNEL_DATA.displays.len() - 1)
+        }
+    }
+
     /// Retrieves a mutable reference to the Cortex-M peripherals if they have been initialized.
     ///
     /// # Returns
@@ -271,6 +352,17 @@ impl Kernel {
         }
     }
 
+    /// Provides mutable access to the global `Terminal` instance without panicking.
+    ///
+    /// Used on paths which must not panic, such as the panic handler.
+    ///
+    /// # Returns
+    /// A mutable reference to the global `Terminal` instance, or `None` if it is not initialized.
+    #[allow(static_mut_refs)]
+    pub(crate) fn try_terminal() -> Option<&'static mut Terminal> {
+        unsafe { G_KERNEL_DATA.terminal.as_mut() }
+    }
+
     /// Returns a mutable reference to the global `Scheduler` instance if it is initialized.
     ///
     /// # Safety
@@ -298,6 +390,18 @@ impl Kernel {
         }
     }
 
+    /// Returns a mutable reference to the global `Scheduler` instance if it is initialized.
+    ///
+    /// Used on paths which may run before the kernel data is initialized.
+    ///
+    /// # Returns
+    /// A mutable reference to the global `Scheduler` instance, or `None` if it is not
+    /// initialized.
+    #[allow(static_mut_refs)]
+    pub(crate) fn try_scheduler() -> Option<&'static mut Scheduler> {
+        unsafe { G_KERNEL_DATA.scheduler.as_mut() }
+    }
+
     /// Returns a static reference to the `KernelTimeData` if it has been initialized.
     ///
     /// # Safety
@@ -426,6 +530,9 @@ impl Kernel {
 /// that the kernel depends on. It accesses the global `KERNEL_DATA` structure and assigns the
 /// retrieved peripherals object to the `cortex_peripherals` field.
 ///
+/// The free stack is first filled with a pattern for [`crate::stack_high_water`], so this
+/// function must be called first, before any deep call.
+///
 /// # Safety
 ///
 /// This function performs an unsafe operation to directly modify the global `KERNEL_DATA` structure.
@@ -441,7 +548,29 @@ impl Kernel {
 /// which occurs if the peripherals have already been taken elsewhere in the program.
 ///
 pub fn cortex_init() {
+    stack_fill();
     unsafe {
         G_KERNEL_DATA.cortex_peripherals = Some(Peripherals::take().unwrap());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scheduler_started;
+
+    #[test]
+    #[allow(static_mut_refs)]
+    fn scheduler_started_follows_the_scheduler_state() {
+        // Only test touching the kernel data, the states are checked in sequence
+        assert!(!scheduler_started());
+
+        unsafe { G_KERNEL_DATA.scheduler = Some(Scheduler::new(Milliseconds(50))) };
+        assert!(!scheduler_started());
+
+        Kernel::scheduler().started = true;
+        assert!(scheduler_started());
+
+        unsafe { G_KERNEL_DATA.scheduler = None };
+    }
+}
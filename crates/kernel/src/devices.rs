@@ -1,4 +1,8 @@
-use crate::{KernelError, KernelResult, data::Kernel, ident::K_KERNEL_MASTER_ID};
+use crate::{
+    KernelError, KernelResult,
+    data::{K_MAX_DISPLAYS, K_PRIMARY_DISPLAY, Kernel},
+    ident::K_KERNEL_MASTER_ID,
+};
 
 /// Device locking and authorization utilities.
 ///
@@ -11,11 +15,15 @@ use crate::{KernelError, KernelResult, data::Kernel, ident::K_KERNEL_MASTER_ID};
 /// Lock ownership is represented by a caller identifier (`caller_id: u32`). The
 /// [`K_KERNEL_MASTER_ID`] is treated as a privileged owner that can take over (lock) and release
 /// (unlock) devices regardless of current ownership.
+///
+/// Each display is a separate device, selected by its index (see [`crate::syscall_display_on`]):
+/// locking one display does not restrict access to the others. The terminal mirror and the
+/// error screens only use the primary display, [`K_PRIMARY_DISPLAY`].
 pub enum DeviceType {
     /// The system terminal device.
     Terminal,
-    /// The system display device.
-    Display,
+    /// A display device by selector, [`K_PRIMARY_DISPLAY`] for the primary display.
+    Display(usize),
     /// A HAL-defined peripheral/interface by numeric identifier.
     Peripheral(usize),
 }
@@ -29,10 +37,15 @@ impl DeviceType {
     /// # Errors
     /// - For [`DeviceType::Peripheral`], returns `Err(KernelError::HalError(_))` if the HAL cannot
     ///   resolve the interface name.
+    /// - For [`DeviceType::Display`], returns `Err(KernelError::DisplayNotConfigured)` if no
+    ///   display is registered at this index.
     pub fn name(&self) -> KernelResult<&'static str> {
         match self {
             DeviceType::Terminal => Ok("Terminal"),
-            DeviceType::Display => Ok("Display"),
+            DeviceType::Display(K_PRIMARY_DISPLAY) => Ok("Display"),
+            DeviceType::Display(l_index) => Ok(Kernel::display_at(*l_index)?
+                .get_name()
+                .unwrap_or("Display")),
             DeviceType::Peripheral(l_id) => {
                 hal_interface::interface_name(*l_id).map_err(KernelError::HalError)
             }
@@ -81,12 +94,12 @@ impl LockState {
 ///
 /// Built-in devices:
 /// - Terminal: stored in `terminal_state`
-/// - Display: stored in `display_state`
+/// - Displays: stored in `display_states`, one entry per display selector
 ///
 /// Peripherals (`DeviceType::Peripheral`) are managed by the HAL through [`Kernel::hal()`].
 pub struct DevicesManager {
     terminal_state: LockState,
-    display_state: LockState,
+    display_states: [LockState; K_MAX_DISPLAYS],
 }
 
 impl DevicesManager {
@@ -97,10 +110,21 @@ impl DevicesManager {
     pub fn new() -> Self {
         DevicesManager {
             terminal_state: LockState::Unlocked,
-            display_state: LockState::Unlocked,
+            display_states: [LockState::Unlocked; K_MAX_DISPLAYS],
         }
     }
 
+    /// Returns the lock state of a registered display.
+    ///
+    /// # Errors
+    /// Returns [`KernelError::DisplayNotConfigured`] if no display is registered at `index`.
+    fn display_state(&mut self, p_index: usize) -> KernelResult<&mut LockState> {
+        Kernel::display_at(p_index)?;
+        self.display_states
+            .get_mut(p_index)
+            .ok_or(KernelError::DisplayNotConfigured)
+    }
+
     /// Checks whether the given device is currently locked.
     ///
     /// # Parameters
@@ -113,10 +137,16 @@ impl DevicesManager {
     /// # Errors
     /// - For [`DeviceType::Peripheral`], returns `Err(KernelError::HalError(_))` if the HAL query
     ///   fails.
+    /// - For [`DeviceType::Display`], returns `Err(KernelError::DisplayNotConfigured)` if no
+    ///   display is registered at this index.
     pub fn is_locked(&self, p_device_type: DeviceType) -> KernelResult<bool> {
         match p_device_type {
             DeviceType::Terminal => Ok(self.terminal_state.is_locked()),
-            DeviceType::Display => Ok(self.display_state.is_locked()),
+            DeviceType::Display(l_index) => {
+                // Registered displays are always within the lock table
+                Kernel::display_at(l_index)?;
+                Ok(self.display_states[l_index].is_locked())
+            }
             DeviceType::Peripheral(l_id) => Ok(Kernel::hal()
                 .is_interface_locked(l_id)
                 .map_err(KernelError::HalError)?
@@ -147,6 +177,8 @@ impl DevicesManager {
     ///   caller is not [`K_KERNEL_MASTER_ID`]. The error message uses [`DeviceType::name`].
     /// - `Err(KernelError::HalError(_))` for HAL failures when locking peripherals or when resolving
     ///   a peripheral name for error reporting.
+    /// - `Err(KernelError::DisplayNotConfigured)` for a display selector without registered
+    ///   display.
     pub fn lock(&mut self, p_device_type: DeviceType, p_caller_id: u32) -> KernelResult<()> {
         match p_device_type {
             DeviceType::Terminal => match self.terminal_state {
@@ -165,22 +197,25 @@ impl DevicesManager {
                     }
                 }
             },
-            DeviceType::Display => match self.display_state {
-                LockState::Unlocked => {
-                    self.display_state = LockState::Locked(p_caller_id);
-                    Ok(())
-                }
-                LockState::Locked(l_id) => {
-                    if p_caller_id == l_id {
-                        Ok(())
-                    } else if p_caller_id == K_KERNEL_MASTER_ID {
-                        self.display_state = LockState::Locked(p_caller_id);
+            DeviceType::Display(l_index) => {
+                let l_state = self.display_state(l_index)?;
+                match *l_state {
+                    LockState::Unlocked => {
+                        *l_state = LockState::Locked(p_caller_id);
                         Ok(())
-                    } else {
-                        Err(KernelError::DeviceLocked(p_device_type.name()?))
+                    }
+                    LockState::Locked(l_id) => {
+                        if p_caller_id == l_id {
+                            Ok(())
+                        } else if p_caller_id == K_KERNEL_MASTER_ID {
+                            *l_state = LockState::Locked(p_caller_id);
+                            Ok(())
+                        } else {
+                            Err(KernelError::DeviceLocked(p_device_type.name()?))
+                        }
                     }
                 }
-            },
+            }
             DeviceType::Peripheral(l_id) => Kernel::hal()
                 .lock_interface(l_id, p_caller_id)
                 .map_err(KernelError::HalError),
@@ -208,6 +243,8 @@ impl DevicesManager {
     ///   caller is not [`K_KERNEL_MASTER_ID`]. The error message uses [`DeviceType::name`].
     /// - `Err(KernelError::HalError(_))` for HAL failures when unlocking peripherals or when
     ///   resolving a peripheral name for error reporting.
+    /// - `Err(KernelError::DisplayNotConfigured)` for a display selector without registered
+    ///   display.
     pub fn unlock(&mut self, p_device_type: DeviceType, p_caller_id: u32) -> KernelResult<()> {
         match p_device_type {
             DeviceType::Terminal => match self.terminal_state {
@@ -221,17 +258,20 @@ impl DevicesManager {
                 }
                 LockState::Unlocked => Ok(()),
             },
-            DeviceType::Display => match self.display_state {
-                LockState::Locked(l_id) => {
-                    if p_caller_id == l_id || p_caller_id == K_KERNEL_MASTER_ID {
-                        self.display_state = LockState::Unlocked;
-                        Ok(())
-                    } else {
-                        Err(KernelError::DeviceNotOwned(p_device_type.name()?))
+            DeviceType::Display(l_index) => {
+                let l_state = self.display_state(l_index)?;
+                match *l_state {
+                    LockState::Locked(l_id) => {
+                        if p_caller_id == l_id || p_caller_id == K_KERNEL_MASTER_ID {
+                            *l_state = LockState::Unlocked;
+                            Ok(())
+                        } else {
+                            Err(KernelError::DeviceNotOwned(p_device_type.name()?))
+                        }
                     }
+                    LockState::Unlocked => Ok(()),
                 }
-                LockState::Unlocked => Ok(()),
-            },
+            }
             DeviceType::Peripheral(l_id) => Kernel::hal()
                 .unlock_interface(l_id, p_caller_id)
                 .map_err(KernelError::HalError),
@@ -259,6 +299,8 @@ impl DevicesManager {
     ///   caller is not [`K_KERNEL_MASTER_ID`]. The error message uses [`DeviceType::name`].
     /// - `Err(KernelError::HalError(_))` for HAL failures when authorizing peripherals or when
     ///   resolving a peripheral name for error reporting.
+    /// - `Err(KernelError::DisplayNotConfigured)` for a display selector without registered
+    ///   display.
     pub fn authorize(&mut self, p_device_type: DeviceType, p_caller_id: u32) -> KernelResult<()> {
         match p_device_type {
             DeviceType::Terminal => match self.terminal_state {
@@ -271,7 +313,7 @@ impl DevicesManager {
                 }
                 LockState::Unlocked => Ok(()),
             },
-            DeviceType::Display => match self.display_state {
+            DeviceType::Display(l_index) => match *self.display_state(l_index)? {
                 LockState::Locked(l_id) => {
                     if p_caller_id == l_id || p_caller_id == K_KERNEL_MASTER_ID {
                         Ok(())
@@ -286,4 +328,44 @@ impl DevicesManager {
                 .map_err(KernelError::HalError),
         }
     }
+
+    /// Checks that `caller_id` currently holds the lock on the given device.
+    ///
+    /// This is stricter than [`DevicesManager::authorize`]: an unlocked device is rejected, and
+    /// [`K_KERNEL_MASTER_ID`] gets no special treatment. It is used to guard operations giving
+    /// raw access to a device.
+    ///
+    /// # Parameters
+    /// - `device_type`: The device to check.
+    /// - `caller_id`: The caller identifier expected to own the lock.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the device is locked by `caller_id`.
+    ///
+    /// # Errors
+    /// - `Err(KernelError::DeviceNotOwned(_))` if the device is unlocked or locked by another owner.
+    /// - `Err(KernelError::HalError(_))` for HAL failures when querying a peripheral lock state or
+    ///   resolving its name.
+    /// - `Err(KernelError::DisplayNotConfigured)` for a display selector without registered
+    ///   display.
+    pub fn check_owner(&mut self, p_device_type: DeviceType, p_caller_id: u32) -> KernelResult<()> {
+        let l_owned = match p_device_type {
+            DeviceType::Terminal => self.terminal_state == LockState::Locked(p_caller_id),
+            DeviceType::Display(l_index) => {
+                *self.display_state(l_index)? == LockState::Locked(p_caller_id)
+            }
+            DeviceType::Peripheral(l_id) => {
+                Kernel::hal()
+                    .is_interface_locked(l_id)
+                    .map_err(KernelError::HalError)?
+                    == Some(p_caller_id)
+            }
+        };
+
+        if l_owned {
+            Ok(())
+        } else {
+            Err(KernelError::DeviceNotOwned(p_device_type.name()?))
+        }
+    }
 }
@@ -3,6 +3,11 @@
 //! This module provides:
 //! - A `HardFault` exception handler that prints the exception frame over semihosting.
 //! - A custom `#[panic_handler]` that prints panic information, waits, then resets the MCU.
+//!   Semihosting is the primary channel; the message can also be written to the terminal (see
+//!   [`crate::BootConfig::panic_to_terminal`]).
+//! - An [`emergency_stop`] function that holds the system in a safe state without resetting.
+//! - A [`kernel_assert!`](crate::kernel_assert) macro reporting broken invariants to the error
+//!   handler, instead of the HardFault or panic of an `unwrap()`.
 //! - An `ErrorsManager` used by the kernel to react to runtime errors by updating an error LED,
 //!   printing to the terminal, and interacting with the scheduler (abort/retry and LED blink task).
 //!
@@ -10,21 +15,34 @@
 //! - **Fatal**: LED forced ON, then the system panics (and resets via the panic handler).
 //! - **Critical**: LED forced ON, message printed, current task aborted.
 //! - **Error**: LED blinks for a limited duration (scheduled periodic task), message printed.
+//!
+//! # Repeated errors
+//! An `Error`-level message identical to the last printed one is not printed again within the
+//! repeat window (see `ErrorsManager::set_repeat_window`). The number of suppressed occurrences
+//! is printed as a summary with the next printed error. `Critical` and `Fatal` errors are never
+//! suppressed.
 
 use crate::KernelErrorLevel::{Critical, Error, Fatal};
 use crate::console_output::ConsoleFormatting;
-use crate::console_output::ConsoleFormatting::StrNewLineBoth;
+use crate::console_output::ConsoleFormatting::{StrNewLineBefore, StrNewLineBoth};
 use crate::data::Kernel;
 use crate::ident::{K_KERNEL_MASTER_ID, K_KERNEL_NAME};
+use crate::reset_reason::{ResetReason, record_reset_reason};
+use crate::scheduler::AppCall;
+use crate::systick::{now_ms, watchdog_disable};
 use crate::{
-    KernelError, KernelErrorLevel, KernelResult, Milliseconds, SysCallHalActions, syscall_devices,
-    syscall_hal,
+    DeviceType, K_PRIMARY_DISPLAY, KernelError, KernelErrorLevel, KernelResult, Milliseconds,
+    SysCallHalActions, critical_section, syscall_devices, syscall_hal,
 };
+use core::fmt::Write;
 use core::panic::PanicInfo;
+use core::sync::atomic::{AtomicBool, Ordering};
+#[cfg_attr(test, allow(unused_imports))]
 use cortex_m_rt::{ExceptionFrame, exception};
 use cortex_m_semihosting::hprintln;
 use display::Colors;
 use hal_interface::{GpioWriteAction, InterfaceWriteActions};
+use heapless::{String, format};
 
 /// Cortex-M HardFault exception handler.
 ///
@@ -36,7 +54,8 @@ use hal_interface::{GpioWriteAction, InterfaceWriteActions};
 ///
 /// # Errors
 /// - No recoverable errors are returned. Printing is best-effort via semihosting.
-#[exception]
+#[cfg_attr(not(test), exception)]
+#[cfg_attr(test, allow(dead_code, non_snake_case))]
 unsafe fn HardFault(p_exception_frame: &ExceptionFrame) -> ! {
     hprintln!("{:#?}", p_exception_frame);
 
@@ -44,10 +63,29 @@ unsafe fn HardFault(p_exception_frame: &ExceptionFrame) -> ! {
     loop {}
 }
 
+/// Whether the panic handler also writes the panic message to the terminal.
+static G_PANIC_TO_TERMINAL: AtomicBool = AtomicBool::new(false);
+/// Set when the panic handler is entered, to detect a panic raised by the handler itself.
+static G_PANIC_IN_PROGRESS: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable writing panic messages to the terminal.
+///
+/// # Parameters
+/// - `enable`: `true` to write panic messages to the terminal in addition to semihosting.
+pub(crate) fn set_panic_to_terminal(p_enable: bool) {
+    G_PANIC_TO_TERMINAL.store(p_enable, Ordering::Relaxed);
+}
+
 /// Kernel-wide panic handler.
 ///
 /// Prints the kernel name and panic information using semihosting, then waits and resets the MCU.
 ///
+/// If enabled with [`set_panic_to_terminal`], the message is also written to the terminal UART
+/// and display mirror, so it is visible without a debugger attached. This write is
+/// best-effort: the kernel state may be inconsistent at this point, so it may fail, be
+/// truncated, or hang if the HAL is wedged. It is done after the semihosting output and
+/// skipped if the terminal was not started or if the handler itself panicked.
+///
 /// # Parameters
 /// - `info`: Rust panic payload and location information.
 ///
@@ -56,20 +94,139 @@ unsafe fn HardFault(p_exception_frame: &ExceptionFrame) -> ! {
 ///
 /// # Errors
 /// - No recoverable errors are returned. Output is best-effort via semihosting.
-#[panic_handler]
+#[cfg_attr(not(test), panic_handler)]
+#[cfg_attr(test, allow(dead_code))]
 fn panic(p_info: &PanicInfo) -> ! {
     // Print the panic message
     hprintln!("{} has panicked !!!!!", K_KERNEL_NAME);
     hprintln!("{}", p_info);
     hprintln!("\r\nSystem will reboot in 5 seconds...");
 
+    // Best-effort copy on the terminal, not attempted again if it panicked
+    if G_PANIC_TO_TERMINAL.load(Ordering::Relaxed)
+        && !G_PANIC_IN_PROGRESS.swap(true, Ordering::Relaxed)
+        && let Some(l_terminal) = Kernel::try_terminal()
+    {
+        let mut l_msg: String<256> = String::new();
+        write!(l_msg, "{}", p_info).unwrap_or(());
+        l_terminal.write_panic(&[
+            format!(40; "{} has panicked !!!!!", K_KERNEL_NAME)
+                .unwrap_or_default()
+                .as_str(),
+            l_msg.as_str(),
+            "System will reboot in 5 seconds...",
+        ]);
+    }
+
     // Wait for 3 seconds
     cortex_m::asm::delay(216_000_000 * 5);
 
-    // Reset the system
+    // Reset the system, keeping the cause for the next boot
+    record_reset_reason(ResetReason::Panic);
     cortex_m::peripheral::SCB::sys_reset();
 }
 
+/// Checks an invariant and reports a failure to the kernel error handler.
+///
+/// `kernel_assert!(cond, level, msg)` evaluates `cond` and, if it is `false`, handles a
+/// [`KernelError::AssertionFailed`] carrying `level` and `msg`, as any other runtime error:
+/// an `Error` is printed and blinks the error LED, a `Critical` error also aborts the running
+/// task. A `Fatal` level still panics and resets the MCU, as an `unwrap()` failure does today,
+/// but with a readable message.
+///
+/// The macro evaluates to the value of `cond`, so the caller can skip the code relying on the
+/// invariant when it does not hold.
+///
+/// # Parameters
+/// - `cond`: Invariant to check, a `bool` expression.
+/// - `level`: [`KernelErrorLevel`] of the failure.
+/// - `msg`: `&'static str` describing the broken invariant.
+#[macro_export]
+macro_rules! kernel_assert {
+    ($cond:expr, $level:expr, $msg:expr) => {{
+        let l_holds: bool = $cond;
+        if !l_holds {
+            $crate::assertion_failed($level, $msg);
+        }
+        l_holds
+    }};
+}
+
+/// Reports a failed [`kernel_assert!`](crate::kernel_assert) to the kernel error handler.
+///
+/// # Parameters
+/// - `level`: Severity of the failure.
+/// - `msg`: Description of the broken invariant.
+#[doc(hidden)]
+pub fn assertion_failed(p_level: KernelErrorLevel, p_msg: &'static str) {
+    Kernel::errors().error_handler(&KernelError::AssertionFailed(p_level, p_msg));
+}
+
+/// Immediately stops all kernel activity and holds the system in a safe state.
+///
+/// Unlike a panic, this does not reset the MCU: the system stays stopped until an external
+/// reset. This function:
+/// 1. Pauses the scheduler, so no task body runs anymore, and disables the software
+///    watchdog, as the scheduler may never complete its current cycle.
+/// 2. Forces the error LED ON and records a fatal error.
+/// 3. Takes the terminal and display locks for the kernel, flushes the queued terminal output
+///    and switches the terminal to display mode, so user input is ignored.
+/// 4. Clears the terminal (and the display when one is configured) and prints `reason`.
+/// 5. Sleeps forever, only waking up to serve interrupts.
+///
+/// Only the kernel or a privileged app should call this function. Recovery requires a reset.
+///
+/// The kernel does not drive a hardware watchdog yet: if one is added, it must be fed from
+/// the final loop so that the safe state is held instead of resetting.
+///
+/// # Parameters
+/// - `reason`: Message printed on the terminal to explain the stop.
+///
+/// # Returns
+/// - Never returns (`!`).
+///
+/// # Errors
+/// - LED, lock and terminal operations are best-effort and ignored on failure, so the system
+///   always reaches the stopped state.
+pub fn emergency_stop(p_reason: &'static str) -> ! {
+    critical_section(|| Kernel::scheduler().pause_all());
+    watchdog_disable();
+
+    // Force the error LED ON
+    let l_errors = Kernel::errors();
+    l_errors.has_error = Some(Fatal);
+    l_errors.set_err_led(true).unwrap_or(());
+
+    // Take the output devices and ignore user input
+    critical_section(|| {
+        Kernel::devices()
+            .lock(DeviceType::Terminal, K_KERNEL_MASTER_ID)
+            .unwrap_or(());
+        Kernel::devices()
+            .lock(DeviceType::Display(K_PRIMARY_DISPLAY), K_KERNEL_MASTER_ID)
+            .unwrap_or(());
+    });
+    let l_terminal = Kernel::terminal();
+    l_terminal.set_tx_buffered(false).unwrap_or(());
+    l_terminal.set_display_mode().unwrap_or(());
+
+    // Blank the outputs and print the reason, the display is only used when configured
+    l_terminal.set_display_mirror(true).unwrap_or(());
+    l_terminal.write(&ConsoleFormatting::Clear).unwrap_or(());
+    l_terminal.set_color(Colors::Red).unwrap_or(());
+    l_terminal
+        .write(&StrNewLineBoth("EMERGENCY STOP"))
+        .unwrap_or(());
+    l_terminal.write(&StrNewLineBoth(p_reason)).unwrap_or(());
+    l_terminal
+        .write(&StrNewLineBoth("Reset required to recover"))
+        .unwrap_or(());
+
+    loop {
+        cortex_m::asm::wfi();
+    }
+}
+
 /// Centralized manager for kernel error handling.
 ///
 /// Tracks whether an error has occurred and its highest severity, and optionally controls an
@@ -79,14 +236,24 @@ pub struct ErrorsManager {
     err_led_id: Option<usize>,
     /// Highest-severity error observed so far (if any).
     has_error: Option<KernelErrorLevel>,
+    /// Last printed `Error`-level message and the uptime in ms at which it was printed.
+    last_error: Option<(String<256>, u32)>,
+    /// Number of occurrences of `last_error` suppressed since it was printed.
+    repeat_count: u32,
+    /// Window during which identical `Error`-level messages are suppressed.
+    repeat_window: Milliseconds,
 }
 
 impl ErrorsManager {
     /// Name of the periodic scheduler task used to blink the error LED.
     const K_LED_BLINK_APP_NAME: &'static str = "ERR_LED_BLINK";
+    /// Default window during which identical `Error`-level messages are suppressed.
+    const K_DEFAULT_REPEAT_WINDOW: Milliseconds = Milliseconds(1000);
 
     /// Create a new `ErrorsManager` with no configured LED and no recorded errors.
     ///
+    /// The repeat window is set to 1 second.
+    ///
     /// # Parameters
     /// - None.
     ///
@@ -99,6 +266,9 @@ impl ErrorsManager {
         ErrorsManager {
             err_led_id: None,
             has_error: None,
+            last_error: None,
+            repeat_count: 0,
+            repeat_window: Self::K_DEFAULT_REPEAT_WINDOW,
         }
     }
 
@@ -141,6 +311,14 @@ impl ErrorsManager {
         Ok(())
     }
 
+    /// Set the window during which identical `Error`-level messages are suppressed.
+    ///
+    /// # Parameters
+    /// - `window`: New window. `Milliseconds(0)` disables the suppression.
+    pub fn set_repeat_window(&mut self, p_window: Milliseconds) {
+        self.repeat_window = p_window;
+    }
+
     /// Set the error LED state if an LED is configured.
     ///
     /// # Parameters
@@ -173,7 +351,9 @@ impl ErrorsManager {
     /// - **Critical**: Turn LED ON, store severity (unless already Fatal), print message, abort
     ///   the currently running task.
     /// - **Error**: Store severity (unless already Critical/Fatal), schedule a temporary LED blink
-    ///   task (or extend its duration), clear terminal, print message.
+    ///   task (or extend its duration), clear terminal, print message. A message identical to the
+    ///   last printed one within the repeat window is counted instead of printed; the count is
+    ///   printed as `(previous error repeated N times)` before the next printed message.
     ///
     /// # Parameters
     /// - `err`: The error to handle.
@@ -194,50 +374,90 @@ impl ErrorsManager {
             }
             Critical => {
                 self.set_err_led(true).unwrap_or(());
-                if self.has_error != Some(Fatal) {
-                    self.has_error = Some(Critical);
-                }
-                Kernel::terminal().set_display_mirror(true).unwrap();
+                critical_section(|| {
+                    if self.has_error != Some(Fatal) {
+                        self.has_error = Some(Critical);
+                    }
+                });
+                // Mirror is unavailable without display, the error is still printed on the terminal
+                let l_mirrored = Kernel::terminal().is_display_mirror();
+                Kernel::terminal().set_display_mirror(true).unwrap_or(());
                 Kernel::terminal().set_color(Colors::Magenta).unwrap();
                 Kernel::terminal()
                     .write(&StrNewLineBoth(p_err.to_string().as_str()))
                     .unwrap_or(());
-                Kernel::scheduler().abort_task_on_error();
-                Kernel::terminal().set_display_mirror(false).unwrap();
+                critical_section(|| Kernel::scheduler().abort_task_on_error());
+                // Keep a mirror enabled with the display command
+                if !l_mirrored {
+                    Kernel::terminal().set_display_mirror(false).unwrap();
+                }
             }
             Error => {
-                if self.has_error != Some(Fatal) && self.has_error != Some(Critical) {
-                    self.has_error = Some(Error);
-                }
+                critical_section(|| {
+                    if self.has_error != Some(Fatal) && self.has_error != Some(Critical) {
+                        self.has_error = Some(Error);
+                    }
+
+                    if self.err_led_id.is_some() {
+                        if Kernel::scheduler()
+                            .app_exists(Self::K_LED_BLINK_APP_NAME)
+                            .is_none()
+                        {
+                            // Try to add the error LED app in scheduler, no action if it fails
+                            Kernel::scheduler()
+                                .add_periodic_app(
+                                    Self::K_LED_BLINK_APP_NAME,
+                                    AppCall::App(blink_err_led),
+                                    Some(reset_err_led),
+                                    Milliseconds(100),
+                                    Some(Milliseconds(10000)),
+                                    false,
+                                )
+                                .unwrap_or(0);
+                        } else {
+                            Kernel::scheduler()
+                                .set_new_task_duration(
+                                    Self::K_LED_BLINK_APP_NAME,
+                                    Milliseconds(10000),
+                                )
+                                .unwrap_or(());
+                        }
+                    }
+                });
 
-                if self.err_led_id.is_some() {
-                    if Kernel::scheduler()
-                        .app_exists(Self::K_LED_BLINK_APP_NAME)
-                        .is_none()
-                    {
-                        // Try to add the error LED app in scheduler, no action if it fails
-                        Kernel::scheduler()
-                            .add_periodic_app(
-                                Self::K_LED_BLINK_APP_NAME,
-                                blink_err_led,
-                                Some(reset_err_led),
-                                Milliseconds(100),
-                                Some(Milliseconds(10000)),
-                                false,
-                            )
-                            .unwrap_or(0);
+                // Suppress identical messages within the repeat window
+                let l_msg = p_err.to_string();
+                let l_now = now_ms();
+                let l_repeated = critical_section(|| {
+                    let l_suppress = self.last_error.as_ref().is_some_and(|(l_last, l_tick)| {
+                        *l_last == l_msg
+                            && l_now.wrapping_sub(*l_tick) < self.repeat_window.to_u32()
+                    });
+                    if l_suppress {
+                        self.repeat_count += 1;
+                        None
                     } else {
-                        Kernel::scheduler()
-                            .set_new_task_duration(Self::K_LED_BLINK_APP_NAME, Milliseconds(10000))
+                        self.last_error = Some((l_msg.clone(), l_now));
+                        Some(core::mem::take(&mut self.repeat_count))
+                    }
+                });
+
+                if let Some(l_count) = l_repeated {
+                    Kernel::terminal().write(&ConsoleFormatting::Clear).unwrap();
+                    Kernel::terminal().set_color(Colors::Red).unwrap();
+                    if l_count > 0 {
+                        Kernel::terminal()
+                            .write(&StrNewLineBefore(
+                                format!(40; "(previous error repeated {} times)", l_count)
+                                    .unwrap()
+                                    .as_str(),
+                            ))
                             .unwrap_or(());
                     }
+                    Kernel::terminal()
+                        .write(&StrNewLineBoth(l_msg.as_str()))
+                        .unwrap_or(())
                 }
-
-                Kernel::terminal().write(&ConsoleFormatting::Clear).unwrap();
-                Kernel::terminal().set_color(Colors::Red).unwrap();
-                Kernel::terminal()
-                    .write(&StrNewLineBoth(p_err.to_string().as_str()))
-                    .unwrap_or(())
             }
         }
     }
@@ -267,6 +487,35 @@ impl ErrorsManager {
         }
     }
 
+    /// Return the highest-severity error recorded since boot or since the last clear.
+    ///
+    /// # Returns
+    /// - `Some(level)` if an error has been recorded.
+    /// - `None` if no error is pending.
+    pub fn current_error(&self) -> Option<KernelErrorLevel> {
+        self.has_error
+    }
+
+    /// Acknowledge the recorded error and turn the error LED off.
+    ///
+    /// If the error LED blink task is running, it is shortened to end on its next execution;
+    /// its end callback then leaves the LED off.
+    ///
+    /// # Returns
+    /// - `Ok(())` if no LED is configured or if the HAL write succeeds.
+    ///
+    /// # Errors
+    /// - Propagates errors from `reset_err_led` / underlying HAL writes.
+    pub fn clear_error(&mut self) -> KernelResult<()> {
+        critical_section(|| {
+            self.has_error = None;
+            Kernel::scheduler()
+                .set_new_task_duration(Self::K_LED_BLINK_APP_NAME, Milliseconds(100))
+                .unwrap_or(());
+        });
+        self.reset_err_led()
+    }
+
     pub(in crate::errors_mgt) fn get_err_led_id(&self) -> usize {
         self.err_led_id.unwrap_or(0)
     }
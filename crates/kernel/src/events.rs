@@ -0,0 +1,88 @@
+//! Event bus between apps.
+//!
+//! Apps exchange notifications without knowing each other: a producer publishes a value on a
+//! numeric topic with [`publish`], and every handler registered on that topic with
+//! [`subscribe`] is called with the value. Topic numbers are chosen by the apps, the kernel
+//! gives them no meaning.
+//!
+//! Handlers run synchronously, in the context of the publisher: from the scheduler interrupt
+//! when published by an app, or from a peripheral interrupt when published by a HAL callback.
+//! They must therefore be short and must not block. Publishing on a topic without subscriber
+//! does nothing.
+
+use heapless::Vec;
+use spin::Mutex;
+
+use crate::{KernelError, KernelResult, critical_section};
+
+/// Maximum number of subscriptions, all topics included.
+pub const K_MAX_SUBSCRIPTIONS: usize = 16;
+
+/// Event handler, called with the published value.
+pub type EventHandler = fn(u32);
+
+/// Registered subscriptions, as `(topic, handler)` pairs.
+static G_SUBSCRIPTIONS: Mutex<Vec<(u16, EventHandler), K_MAX_SUBSCRIPTIONS>> =
+    Mutex::new(Vec::new());
+
+/// Registers a handler for a topic.
+///
+/// Subscribing the same handler twice to a topic has no effect.
+///
+/// # Parameters
+/// - `topic`: Topic to listen to.
+/// - `handler`: Function called with the value of each event published on `topic`.
+///
+/// # Errors
+/// Returns [`KernelError::SubscriptionTableFull`] if [`K_MAX_SUBSCRIPTIONS`] subscriptions
+/// are already registered.
+pub fn subscribe(p_topic: u16, p_handler: EventHandler) -> KernelResult<()> {
+    critical_section(|| {
+        let mut l_subs = G_SUBSCRIPTIONS.lock();
+        if l_subs
+            .iter()
+            .any(|(l_t, l_h)| *l_t == p_topic && core::ptr::fn_addr_eq(*l_h, p_handler))
+        {
+            return Ok(());
+        }
+        l_subs
+            .push((p_topic, p_handler))
+            .map_err(|_| KernelError::SubscriptionTableFull(p_topic))
+    })
+}
+
+/// Removes a handler from a topic. Nothing is done if it was not subscribed.
+///
+/// # Parameters
+/// - `topic`: Topic the handler listens to.
+/// - `handler`: Handler to remove.
+pub fn unsubscribe(p_topic: u16, p_handler: EventHandler) {
+    critical_section(|| {
+        G_SUBSCRIPTIONS
+            .lock()
+            .retain(|(l_t, l_h)| !(*l_t == p_topic && core::ptr::fn_addr_eq(*l_h, p_handler)));
+    });
+}
+
+/// Publishes an event, calling all handlers subscribed to its topic in subscription order.
+///
+/// The handlers are called after the subscription table is released, so they can publish
+/// or (un)subscribe themselves.
+///
+/// # Parameters
+/// - `topic`: Topic of the event.
+/// - `value`: Value passed to the handlers.
+pub fn publish(p_topic: u16, p_value: u32) {
+    let l_handlers: Vec<EventHandler, K_MAX_SUBSCRIPTIONS> = critical_section(|| {
+        G_SUBSCRIPTIONS
+            .lock()
+            .iter()
+            .filter(|(l_t, _)| *l_t == p_topic)
+            .map(|(_, l_h)| *l_h)
+            .collect()
+    });
+
+    for l_handler in l_handlers {
+        l_handler(p_value);
+    }
+}
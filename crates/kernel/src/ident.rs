@@ -1,4 +1,3 @@
 pub const K_KERNEL_NAME: &str = "SmolOS";
-pub const K_KERNEL_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub const K_KERNEL_MASTER_ID: u32 = 0xCAFEBEAF;
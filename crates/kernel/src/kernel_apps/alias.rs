@@ -0,0 +1,87 @@
+//! Command alias application.
+//!
+//! Lists the terminal command aliases (`alias`) or defines a new one
+//! (`alias <name> <app>`). Registered app names always take precedence over aliases.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec, format};
+use spin::Mutex;
+
+use crate::data::Kernel;
+use crate::terminal::{Alias, K_MAX_ALIASES};
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the alias app.
+static G_ALIAS_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+/// Captured parameters for the alias app.
+static G_ALIAS_PARAM_STORAGE: Mutex<Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>> =
+    Mutex::new(Vec::new());
+
+/// Kernel app entry point for the alias command.
+///
+/// Without parameters, prints the defined aliases. With two parameters, defines the
+/// first one as an alias of the second one.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails or if the alias cannot be stored.
+pub fn alias() -> KernelResult<()> {
+    let l_storage = G_ALIAS_PARAM_STORAGE.lock();
+    let l_id = G_ALIAS_ID_STORAGE.load(Ordering::Relaxed);
+
+    match l_storage.len() {
+        0 => {
+            // Copy the table as the terminal is also used to print it
+            let l_aliases: Vec<Alias, K_MAX_ALIASES> =
+                Kernel::terminal().aliases().iter().cloned().collect();
+            if l_aliases.is_empty() {
+                syscall_terminal(
+                    ConsoleFormatting::StrNewLineBefore("No alias defined"),
+                    l_id,
+                )?;
+            }
+            for (l_alias, l_target) in l_aliases {
+                syscall_terminal(
+                    ConsoleFormatting::StrNewLineBefore(
+                        format!(80; "{} -> {}", l_alias, l_target).unwrap().as_str(),
+                    ),
+                    l_id,
+                )?;
+            }
+        }
+        2 => {
+            if Kernel::apps().get_app_status(&l_storage[0]).is_ok() {
+                syscall_terminal(
+                    ConsoleFormatting::StrNewLineBefore(
+                        "Warning: an app has this name, the alias will be ignored",
+                    ),
+                    l_id,
+                )?;
+            }
+            Kernel::terminal().add_alias(&l_storage[0], &l_storage[1])?;
+        }
+        _ => {
+            syscall_terminal(
+                ConsoleFormatting::StrNewLineBefore("Usage: alias [<name> <app>]"),
+                l_id,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Capture parameters and app id for the alias command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters for the command.
+pub fn alias_init(
+    p_app_id: u32,
+    p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_ALIAS_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    *G_ALIAS_PARAM_STORAGE.lock() = p_param;
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//! Autostart configuration application.
+//!
+//! Enables or disables the start of an app at boot: `boot <name> on|off`. The setting is
+//! saved in flash and applied at the next boots. Without parameters, the apps currently
+//! flagged for autostart are listed.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec, format};
+use spin::Mutex;
+
+use crate::data::Kernel;
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelError, KernelResult,
+    syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the boot app.
+static G_BOOT_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+/// Captured parameters for the boot app.
+static G_BOOT_PARAM_STORAGE: Mutex<Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>> =
+    Mutex::new(Vec::new());
+
+/// Kernel app entry point for the boot command.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails, or if saving the configuration fails.
+pub fn boot() -> KernelResult<()> {
+    let l_storage = G_BOOT_PARAM_STORAGE.lock();
+    let l_id = G_BOOT_ID_STORAGE.load(Ordering::Relaxed);
+
+    let l_enabled = match (l_storage.len(), l_storage.get(1).map(|l_p| l_p.as_str())) {
+        (0, _) => {
+            syscall_terminal(ConsoleFormatting::StrNewLineBefore("Autostart:"), l_id)?;
+            for l_name in Kernel::apps().list_autostart() {
+                syscall_terminal(
+                    ConsoleFormatting::StrNewLineBefore(
+                        format!(40; "  {}", l_name).unwrap().as_str(),
+                    ),
+                    l_id,
+                )?;
+            }
+            return Ok(());
+        }
+        (2, Some("on")) => true,
+        (2, Some("off")) => false,
+        _ => {
+            syscall_terminal(
+                ConsoleFormatting::StrNewLineBefore("Usage: boot [<name> on|off]"),
+                l_id,
+            )?;
+            return Ok(());
+        }
+    };
+
+    let l_name = l_storage[0].as_str();
+    let l_state = if l_enabled { "enabled" } else { "disabled" };
+    match Kernel::apps().set_autostart(l_name, l_enabled) {
+        Ok(()) => syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore(
+                format!(60; "Autostart of {} {}", l_name, l_state)
+                    .unwrap()
+                    .as_str(),
+            ),
+            l_id,
+        ),
+        Err(KernelError::AppNotFound) => syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore(
+                format!(60; "Unknown app {}", l_name).unwrap().as_str(),
+            ),
+            l_id,
+        ),
+        Err(l_err) => Err(l_err),
+    }
+}
+
+/// Capture parameters and app id for the boot command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters for the command.
+pub fn boot_init(
+    p_app_id: u32,
+    p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_BOOT_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    *G_BOOT_PARAM_STORAGE.lock() = p_param;
+    Ok(())
+}
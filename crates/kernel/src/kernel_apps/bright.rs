@@ -0,0 +1,68 @@
+//! Backlight control application.
+//!
+//! Sets the LCD backlight brightness from the terminal: `bright <percent>`.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec};
+use spin::Mutex;
+
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, SysCallDisplayArgs,
+    syscall_display, syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the bright app.
+static G_BRIGHT_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+/// Captured parameters for the bright app.
+static G_BRIGHT_PARAM_STORAGE: Mutex<Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>> =
+    Mutex::new(Vec::new());
+
+/// Kernel app entry point for the bright command.
+///
+/// Expects a single parameter: the brightness in percent (0 to 100). The value is
+/// clamped by the display driver to its configured limits.
+///
+/// # Errors
+/// Returns an error if writing to the terminal or setting the backlight fails.
+pub fn bright() -> KernelResult<()> {
+    let l_storage = G_BRIGHT_PARAM_STORAGE.lock();
+
+    if l_storage.len() != 1 {
+        syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore("Usage: bright <0-100>"),
+            G_BRIGHT_ID_STORAGE.load(Ordering::Relaxed),
+        )?;
+        return Ok(());
+    }
+
+    match l_storage[0].parse::<u8>() {
+        Ok(l_percent) if l_percent <= 100 => {
+            syscall_display(
+                SysCallDisplayArgs::SetBacklight(l_percent),
+                G_BRIGHT_ID_STORAGE.load(Ordering::Relaxed),
+            )?;
+        }
+        _ => {
+            syscall_terminal(
+                ConsoleFormatting::StrNewLineBefore("Invalid brightness"),
+                G_BRIGHT_ID_STORAGE.load(Ordering::Relaxed),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Capture parameters and app id for the bright command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters for the command.
+pub fn bright_init(
+    p_app_id: u32,
+    p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_BRIGHT_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    *G_BRIGHT_PARAM_STORAGE.lock() = p_param;
+    Ok(())
+}
@@ -0,0 +1,55 @@
+//! HAL capabilities application.
+//!
+//! Lists the optional hardware features of the board and whether they are available, for
+//! field diagnostics.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use hal_interface::K_HAL_CAPABILITIES;
+use heapless::{String, Vec, format};
+
+use crate::data::Kernel;
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the caps app.
+static G_CAPS_APP_ID: AtomicU32 = AtomicU32::new(0);
+
+/// Kernel app entry point for the caps command.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails.
+pub fn caps() -> KernelResult<()> {
+    let l_id = G_CAPS_APP_ID.load(Ordering::Relaxed);
+
+    for l_cap in K_HAL_CAPABILITIES {
+        let l_available = if Kernel::hal().has_capability(l_cap) {
+            "yes"
+        } else {
+            "no"
+        };
+        syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore(
+                format!(40; "{:<20} {}", l_cap.name(), l_available)
+                    .unwrap()
+                    .as_str(),
+            ),
+            l_id,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Initialize the caps app by storing its scheduler id.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters (unused).
+pub fn caps_init(
+    p_app_id: u32,
+    _p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_CAPS_APP_ID.store(p_app_id, Ordering::Relaxed);
+    Ok(())
+}
@@ -0,0 +1,72 @@
+//! Output color application.
+//!
+//! Sets the color of subsequent terminal output rendered on the display: `color <name>`.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use display::{Colors, K_COLOR_NAMES};
+use heapless::{String, Vec, format};
+use spin::Mutex;
+
+use crate::data::Kernel;
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, SysCallDisplayArgs,
+    syscall_display, syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the color app.
+static G_COLOR_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+/// Captured parameters for the color app.
+static G_COLOR_PARAM_STORAGE: Mutex<Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>> =
+    Mutex::new(Vec::new());
+
+/// Kernel app entry point for the color command.
+///
+/// Expects a single parameter: the color name. The color is applied to the terminal
+/// output and used as default display drawing color until changed. Unknown names print
+/// the list of valid colors.
+///
+/// # Errors
+/// Returns an error if writing to the terminal or the display fails.
+pub fn color() -> KernelResult<()> {
+    let l_storage = G_COLOR_PARAM_STORAGE.lock();
+    let l_id = G_COLOR_ID_STORAGE.load(Ordering::Relaxed);
+
+    match l_storage
+        .first()
+        .and_then(|l_name| Colors::from_name(l_name))
+    {
+        Some(l_color) if l_storage.len() == 1 => {
+            Kernel::terminal().set_color(l_color)?;
+            syscall_display(SysCallDisplayArgs::SetColor(l_color), l_id)?;
+        }
+        _ => {
+            let mut l_names: String<80> = String::new();
+            for l_name in K_COLOR_NAMES {
+                l_names.push(' ').unwrap_or(());
+                l_names.push_str(l_name).unwrap_or(());
+            }
+            syscall_terminal(
+                ConsoleFormatting::StrNewLineBefore(
+                    format!(100; "Valid colors:{}", l_names).unwrap().as_str(),
+                ),
+                l_id,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Capture parameters and app id for the color command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters for the command.
+pub fn color_init(
+    p_app_id: u32,
+    p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_COLOR_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    *G_COLOR_PARAM_STORAGE.lock() = p_param;
+    Ok(())
+}
@@ -0,0 +1,78 @@
+//! Console switch application.
+//!
+//! Moves the interactive terminal to another USART interface: `console <name>`.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use hal_interface::{K_MAX_INTERFACES, interface_name};
+use heapless::{String, Vec, format};
+use spin::Mutex;
+
+use crate::data::Kernel;
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the console app.
+static G_CONSOLE_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+/// Captured parameters for the console app.
+static G_CONSOLE_PARAM_STORAGE: Mutex<Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>> =
+    Mutex::new(Vec::new());
+
+/// Kernel app entry point for the console command.
+///
+/// Switches the terminal to the requested interface and prints a confirmation there. The
+/// prompt is printed on the new interface when the app exits.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails or if the terminal cannot be moved to
+/// the interface (e.g. not a USART, or locked by another app).
+pub fn console() -> KernelResult<()> {
+    let l_storage = G_CONSOLE_PARAM_STORAGE.lock();
+    let l_id = G_CONSOLE_ID_STORAGE.load(Ordering::Relaxed);
+
+    if l_storage.len() != 1 {
+        return syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore("Usage: console <name>"),
+            l_id,
+        );
+    }
+
+    // The terminal keeps the interface name, use the static one from the HAL
+    let Some(l_name) = (0..K_MAX_INTERFACES)
+        .map_while(|l_if_id| interface_name(l_if_id).ok())
+        .find(|l_n| *l_n == l_storage[0].as_str())
+    else {
+        return syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore(
+                format!(60; "Unknown interface {}", l_storage[0])
+                    .unwrap()
+                    .as_str(),
+            ),
+            l_id,
+        );
+    };
+
+    Kernel::terminal().switch_interface(l_name)?;
+    syscall_terminal(
+        ConsoleFormatting::StrNewLineBefore(
+            format!(60; "Terminal moved to {}", l_name)
+                .unwrap()
+                .as_str(),
+        ),
+        l_id,
+    )
+}
+
+/// Capture parameters and app id for the console command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters for the command.
+pub fn console_init(
+    p_app_id: u32,
+    p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_CONSOLE_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    *G_CONSOLE_PARAM_STORAGE.lock() = p_param;
+    Ok(())
+}
@@ -0,0 +1,162 @@
+//! Console heartbeat.
+//!
+//! A kernel task writes an "alive" line with the uptime on the terminal at a fixed period,
+//! as a liveness indicator for logs captured from the serial console. The period is set at
+//! boot through [`crate::BootConfig::console_heartbeat_period`] and changed at runtime with
+//! `console_heartbeat [off|<seconds>]`.
+//!
+//! The line is only written while the terminal is in display mode: at the prompt, it would be
+//! mixed with the user input, so it is suppressed.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec, format};
+use spin::Mutex;
+
+use crate::data::Kernel;
+use crate::scheduler::AppCall;
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, Milliseconds, now_ms,
+    syscall_terminal,
+};
+
+/// Name of the scheduler task writing the heartbeat line.
+const K_CONSOLE_HEARTBEAT_APP_NAME: &str = "CONSOLE_HEARTBEAT";
+/// Usage message of the console_heartbeat command.
+const K_CONSOLE_HEARTBEAT_USAGE: &str = "Usage: console_heartbeat [off|<seconds>]";
+
+/// Period of the heartbeat task, `None` when it is disabled.
+static G_CONSOLE_HEARTBEAT_PERIOD: Mutex<Option<Milliseconds>> = Mutex::new(None);
+
+/// Last assigned scheduler ID for the console_heartbeat app.
+static G_CONSOLE_HEARTBEAT_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+/// Captured parameters for the console_heartbeat app.
+static G_CONSOLE_HEARTBEAT_PARAM_STORAGE: Mutex<
+    Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+> = Mutex::new(Vec::new());
+
+/// Starts, restarts or stops the heartbeat task.
+///
+/// # Parameters
+/// - `period`: Delay between two heartbeat lines, or `None` to stop the task.
+///
+/// # Errors
+/// Returns an error if the task cannot be scheduled, e.g.
+/// [`crate::KernelError::InvalidDuration`] if the period is shorter than the scheduler period. The
+/// heartbeat is then disabled.
+pub(crate) fn set_console_heartbeat(p_period: Option<Milliseconds>) -> KernelResult<()> {
+    if Kernel::scheduler()
+        .app_exists(K_CONSOLE_HEARTBEAT_APP_NAME)
+        .is_some()
+    {
+        Kernel::scheduler().remove_periodic_app(K_CONSOLE_HEARTBEAT_APP_NAME)?;
+    }
+    *G_CONSOLE_HEARTBEAT_PERIOD.lock() = None;
+
+    if let Some(l_period) = p_period {
+        Kernel::scheduler().add_periodic_app(
+            K_CONSOLE_HEARTBEAT_APP_NAME,
+            AppCall::App(console_heartbeat_task),
+            None,
+            l_period,
+            None,
+            false,
+        )?;
+        *G_CONSOLE_HEARTBEAT_PERIOD.lock() = Some(l_period);
+    }
+
+    Ok(())
+}
+
+/// Scheduler task body: write the heartbeat line, unless the terminal is at the prompt.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails.
+fn console_heartbeat_task() -> KernelResult<()> {
+    let l_terminal = Kernel::terminal();
+    if l_terminal.is_prompt_mode() {
+        return Ok(());
+    }
+
+    l_terminal.write(&ConsoleFormatting::StrNewLineBefore(
+        format!(40; "Alive, uptime {} s", now_ms() / 1000)
+            .unwrap()
+            .as_str(),
+    ))
+}
+
+/// Kernel app entry point for the console_heartbeat command.
+///
+/// Without parameter, prints the heartbeat period. With `off`, stops the heartbeat. With a
+/// number of seconds, (re)starts it with this period.
+///
+/// # Errors
+/// Returns an error if the heartbeat task cannot be scheduled or if writing to the terminal
+/// fails.
+pub fn console_heartbeat() -> KernelResult<()> {
+    let l_storage = G_CONSOLE_HEARTBEAT_PARAM_STORAGE.lock();
+    let l_id = G_CONSOLE_HEARTBEAT_ID_STORAGE.load(Ordering::Relaxed);
+
+    let l_period = match (l_storage.first().map(|l_p| l_p.as_str()), l_storage.len()) {
+        (None, _) => {
+            let l_current = *G_CONSOLE_HEARTBEAT_PERIOD.lock();
+            return match l_current {
+                Some(l_period) => syscall_terminal(
+                    ConsoleFormatting::StrNewLineBefore(
+                        format!(50; "Console heartbeat every {} s", l_period.to_u32() / 1000)
+                            .unwrap()
+                            .as_str(),
+                    ),
+                    l_id,
+                ),
+                None => syscall_terminal(
+                    ConsoleFormatting::StrNewLineBefore("Console heartbeat off"),
+                    l_id,
+                ),
+            };
+        }
+        (Some("off"), 1) => None,
+        (Some(l_seconds), 1) => {
+            let l_parsed = l_seconds
+                .parse::<u32>()
+                .ok()
+                .filter(|l_s| *l_s > 0)
+                .and_then(|l_s| Milliseconds(1000).checked_mul(l_s));
+            if l_parsed.is_none() {
+                return syscall_terminal(
+                    ConsoleFormatting::StrNewLineBefore(K_CONSOLE_HEARTBEAT_USAGE),
+                    l_id,
+                );
+            }
+            l_parsed
+        }
+        _ => {
+            return syscall_terminal(
+                ConsoleFormatting::StrNewLineBefore(K_CONSOLE_HEARTBEAT_USAGE),
+                l_id,
+            );
+        }
+    };
+
+    set_console_heartbeat(l_period)?;
+    syscall_terminal(
+        ConsoleFormatting::StrNewLineBefore(match l_period {
+            Some(_) => "Console heartbeat on",
+            None => "Console heartbeat off",
+        }),
+        l_id,
+    )
+}
+
+/// Capture parameters and app id for the console_heartbeat command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters for the command.
+pub fn console_heartbeat_init(
+    p_app_id: u32,
+    p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_CONSOLE_HEARTBEAT_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    *G_CONSOLE_HEARTBEAT_PARAM_STORAGE.lock() = p_param;
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//! Scheduler cycle counter application.
+//!
+//! Prints the scheduler cycle counter, or restarts it from zero: `cycles [reset]`.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec, format};
+use spin::Mutex;
+
+use crate::data::Kernel;
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the cycles app.
+static G_CYCLES_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+/// Captured parameters for the cycles app.
+static G_CYCLES_PARAM_STORAGE: Mutex<Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>> =
+    Mutex::new(Vec::new());
+
+/// Kernel app entry point for the cycles command.
+///
+/// Without parameter, prints the current scheduler cycle. With `reset`, restarts the counter
+/// from zero, which restarts the phase of every periodic task.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails.
+pub fn cycles() -> KernelResult<()> {
+    let l_storage = G_CYCLES_PARAM_STORAGE.lock();
+    let l_id = G_CYCLES_ID_STORAGE.load(Ordering::Relaxed);
+
+    match (l_storage.first().map(|l_p| l_p.as_str()), l_storage.len()) {
+        (None, _) => syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore(
+                format!(40; "Cycle: {}", Kernel::scheduler().get_cycle_count())
+                    .unwrap()
+                    .as_str(),
+            ),
+            l_id,
+        ),
+        (Some("reset"), 1) => {
+            Kernel::scheduler().reset_cycle_count();
+            syscall_terminal(
+                ConsoleFormatting::StrNewLineBefore("Cycle counter reset, task phases restarted"),
+                l_id,
+            )
+        }
+        _ => syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore("Usage: cycles [reset]"),
+            l_id,
+        ),
+    }
+}
+
+/// Capture parameters and app id for the cycles command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters for the command.
+pub fn cycles_init(
+    p_app_id: u32,
+    p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_CYCLES_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    *G_CYCLES_PARAM_STORAGE.lock() = p_param;
+    Ok(())
+}
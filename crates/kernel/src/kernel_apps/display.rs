@@ -0,0 +1,115 @@
+//! Display role application.
+//!
+//! Switches the LCD between its two uses: `display mirror` mirrors the terminal on it,
+//! `display app` releases it for apps drawing on the screen. `display list` prints the
+//! configured displays with their selectors.
+//!
+//! The display mirror holds the display lock for the kernel, so switching to `app` releases
+//! the mirror first, which makes the display available to the next app locking it. Switching
+//! to `mirror` is refused while an app holds the lock.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec, format};
+use spin::Mutex;
+
+use crate::data::Kernel;
+use crate::{
+    ConsoleFormatting, DeviceType, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, K_MAX_DISPLAYS,
+    K_PRIMARY_DISPLAY, KernelResult, SysCallDevicesArgs, SysCallDisplayArgs, syscall_devices,
+    syscall_display, syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the display app.
+static G_DISPLAY_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+/// Captured parameters for the display app.
+static G_DISPLAY_PARAM_STORAGE: Mutex<Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>> =
+    Mutex::new(Vec::new());
+
+/// Kernel app entry point for the display command.
+///
+/// The screen is cleared on both switches, so the mirror starts on a blank screen and the
+/// next app does not inherit the terminal text.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails, or if the display cannot be cleared
+/// or attached to the terminal.
+pub fn display() -> KernelResult<()> {
+    let l_storage = G_DISPLAY_PARAM_STORAGE.lock();
+    let l_id = G_DISPLAY_ID_STORAGE.load(Ordering::Relaxed);
+
+    match (l_storage.len(), l_storage.first().map(|l_p| l_p.as_str())) {
+        (1, Some("mirror")) => {
+            if Kernel::terminal().is_display_mirror() {
+                return syscall_terminal(
+                    ConsoleFormatting::StrNewLineBefore("Display already mirrors the terminal"),
+                    l_id,
+                );
+            }
+
+            // The mirror would take the display away from its owner
+            let mut l_locked = false;
+            syscall_devices(
+                DeviceType::Display(K_PRIMARY_DISPLAY),
+                SysCallDevicesArgs::GetState(&mut l_locked),
+                l_id,
+            )?;
+            if l_locked {
+                return syscall_terminal(
+                    ConsoleFormatting::StrNewLineBefore("Display is used by an app"),
+                    l_id,
+                );
+            }
+
+            syscall_display(SysCallDisplayArgs::ClearDefault, l_id)?;
+            Kernel::terminal().set_display_mirror(true)?;
+            syscall_terminal(
+                ConsoleFormatting::StrNewLineBefore("Display mirrors the terminal"),
+                l_id,
+            )
+        }
+        (1, Some("app")) => {
+            // Releases the display lock held by the mirror
+            Kernel::terminal().set_display_mirror(false)?;
+            syscall_display(SysCallDisplayArgs::ClearDefault, l_id)?;
+            syscall_terminal(
+                ConsoleFormatting::StrNewLineBefore("Display available for apps"),
+                l_id,
+            )
+        }
+        (1, Some("list")) => {
+            // An uninitialized primary display (board without LCD) is listed as well
+            for (l_index, l_display) in (0..K_MAX_DISPLAYS)
+                .map_while(|l_index| Kernel::display_at(l_index).ok())
+                .enumerate()
+            {
+                syscall_terminal(
+                    ConsoleFormatting::StrNewLineBefore(
+                        format!(40; "{}: {}", l_index, l_display.get_name().unwrap_or("not configured"))
+                            .unwrap()
+                            .as_str(),
+                    ),
+                    l_id,
+                )?;
+            }
+            Ok(())
+        }
+        _ => syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore("Usage: display mirror|app|list"),
+            l_id,
+        ),
+    }
+}
+
+/// Capture parameters and app id for the display command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters for the command.
+pub fn display_init(
+    p_app_id: u32,
+    p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_DISPLAY_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    *G_DISPLAY_PARAM_STORAGE.lock() = p_param;
+    Ok(())
+}
@@ -0,0 +1,75 @@
+//! Error state application.
+//!
+//! Prints the highest-severity error recorded by the kernel: `err`, or acknowledges it and
+//! turns the error LED off: `err clear`.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec, format};
+use spin::Mutex;
+
+use crate::data::Kernel;
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelErrorLevel, KernelResult,
+    syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the err app.
+static G_ERR_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+/// Captured parameters for the err app.
+static G_ERR_PARAM_STORAGE: Mutex<Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>> =
+    Mutex::new(Vec::new());
+
+/// Kernel app entry point for the err command.
+///
+/// Without parameter, prints the current error level. With the `clear` parameter, clears
+/// the recorded error and turns the error LED off.
+///
+/// # Errors
+/// Returns an error if writing to the terminal or to the error LED fails.
+pub fn err() -> KernelResult<()> {
+    let l_storage = G_ERR_PARAM_STORAGE.lock();
+    let l_id = G_ERR_ID_STORAGE.load(Ordering::Relaxed);
+
+    match (l_storage.first().map(|l_p| l_p.as_str()), l_storage.len()) {
+        (None, _) => {
+            let l_level = match Kernel::errors().current_error() {
+                Some(KernelErrorLevel::Fatal) => "Fatal",
+                Some(KernelErrorLevel::Critical) => "Critical",
+                Some(KernelErrorLevel::Error) => "Error",
+                None => "None",
+            };
+            syscall_terminal(
+                ConsoleFormatting::StrNewLineBefore(
+                    format!(40; "Current error: {}", l_level).unwrap().as_str(),
+                ),
+                l_id,
+            )?;
+        }
+        (Some("clear"), 1) => {
+            Kernel::errors().clear_error()?;
+            syscall_terminal(ConsoleFormatting::StrNewLineBefore("Error cleared"), l_id)?;
+        }
+        _ => {
+            syscall_terminal(
+                ConsoleFormatting::StrNewLineBefore("Usage: err [clear]"),
+                l_id,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Capture parameters and app id for the err command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters for the command.
+pub fn err_init(
+    p_app_id: u32,
+    p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_ERR_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    *G_ERR_PARAM_STORAGE.lock() = p_param;
+    Ok(())
+}
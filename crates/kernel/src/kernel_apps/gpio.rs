@@ -0,0 +1,113 @@
+//! GPIO control application.
+//!
+//! Drives an output pin from the terminal for hardware bring-up: `gpio <name> set|clear|toggle`.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use hal_interface::{
+    GpioWriteAction, HalError, InterfaceWriteActions, K_MAX_INTERFACES, interface_name,
+};
+use heapless::{String, Vec, format};
+use spin::Mutex;
+
+use crate::{
+    ConsoleFormatting, DeviceType, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelError,
+    KernelResult, SysCallDevicesArgs, SysCallHalActions, syscall_devices, syscall_hal,
+    syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the gpio app.
+static G_GPIO_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+/// Captured parameters for the gpio app.
+static G_GPIO_PARAM_STORAGE: Mutex<Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>> =
+    Mutex::new(Vec::new());
+
+/// Kernel app entry point for the gpio command.
+///
+/// Expects two parameters: the HAL interface name and the action (`set`, `clear` or
+/// `toggle`). The interface is locked for the duration of the write and released afterwards.
+/// Writing to an interface which is not an output GPIO is reported on the terminal.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails, if the interface is locked by another
+/// app, or if the HAL write fails for another reason than a wrong interface type.
+pub fn gpio() -> KernelResult<()> {
+    let l_storage = G_GPIO_PARAM_STORAGE.lock();
+    let l_id = G_GPIO_ID_STORAGE.load(Ordering::Relaxed);
+
+    let l_action = match (l_storage.len(), l_storage.get(1).map(|l_p| l_p.as_str())) {
+        (2, Some("set")) => GpioWriteAction::Set,
+        (2, Some("clear")) => GpioWriteAction::Clear,
+        (2, Some("toggle")) => GpioWriteAction::Toggle,
+        _ => {
+            syscall_terminal(
+                ConsoleFormatting::StrNewLineBefore("Usage: gpio <name> set|clear|toggle"),
+                l_id,
+            )?;
+            return Ok(());
+        }
+    };
+
+    // Resolve the interface ID, IDs are contiguous
+    let l_name = l_storage[0].as_str();
+    let Some(l_if_id) = (0..K_MAX_INTERFACES)
+        .map_while(|l_if_id| interface_name(l_if_id).ok().map(|l_n| (l_if_id, l_n)))
+        .find(|(_, l_n)| *l_n == l_name)
+        .map(|(l_if_id, _)| l_if_id)
+    else {
+        syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore(
+                format!(60; "Unknown interface {}", l_name)
+                    .unwrap()
+                    .as_str(),
+            ),
+            l_id,
+        )?;
+        return Ok(());
+    };
+
+    // Lock the interface only for the duration of the write
+    syscall_devices(
+        DeviceType::Peripheral(l_if_id),
+        SysCallDevicesArgs::Lock,
+        l_id,
+    )?;
+    let l_result = syscall_hal(
+        l_if_id,
+        SysCallHalActions::Write(InterfaceWriteActions::GpioWrite(l_action)),
+        l_id,
+    );
+    syscall_devices(
+        DeviceType::Peripheral(l_if_id),
+        SysCallDevicesArgs::Unlock,
+        l_id,
+    )?;
+
+    match l_result {
+        Ok(()) => Ok(()),
+        Err(KernelError::HalError(
+            HalError::IncompatibleAction(..) | HalError::ReadOnlyInterface(_),
+        )) => syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore(
+                format!(60; "Interface {} is not an output GPIO", l_name)
+                    .unwrap()
+                    .as_str(),
+            ),
+            l_id,
+        ),
+        Err(l_err) => Err(l_err),
+    }
+}
+
+/// Capture parameters and app id for the gpio command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters for the command.
+pub fn gpio_init(
+    p_app_id: u32,
+    p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_GPIO_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    *G_GPIO_PARAM_STORAGE.lock() = p_param;
+    Ok(())
+}
@@ -0,0 +1,128 @@
+//! Heartbeat application.
+//!
+//! Blinks a dedicated GPIO in a "lub-dub" pattern to give an at-a-glance liveness signal,
+//! distinct from the error LED. The GPIO name is provided at boot through
+//! [`crate::BootConfig::heartbeat_led_name`]; when no name is configured or the interface
+//! cannot be found, the app runs as a no-op.
+
+use core::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use hal_interface::{GpioWriteAction, InterfaceWriteActions};
+use heapless::{String, Vec};
+use spin::Mutex;
+
+use crate::{
+    DeviceType, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, SysCallDevicesArgs,
+    SysCallHalActions, syscall_devices, syscall_hal,
+};
+
+/// LED state for each step of the heartbeat pattern (two short pulses, then a pause).
+const K_HEARTBEAT_PATTERN: [bool; 8] = [true, false, true, false, false, false, false, false];
+
+/// Name of the GPIO interface used as heartbeat LED, set during boot.
+static G_HEARTBEAT_LED_NAME: Mutex<Option<&'static str>> = Mutex::new(None);
+
+/// Cached interface ID of the heartbeat LED, `None` when the heartbeat is disabled.
+static G_HEARTBEAT_LED_ID: Mutex<Option<usize>> = Mutex::new(None);
+
+/// App/owner identifier used when locking and writing to the LED interface.
+static G_HEARTBEAT_APP_ID: AtomicU32 = AtomicU32::new(0);
+
+/// Current step in [`K_HEARTBEAT_PATTERN`].
+static G_HEARTBEAT_STEP: AtomicUsize = AtomicUsize::new(0);
+
+/// Configure the name of the GPIO interface used by the heartbeat app.
+///
+/// Must be called before the app is started to be taken into account.
+///
+/// # Parameters
+/// - `name`: HAL name of the LED interface, or `None` to disable the heartbeat.
+pub fn set_heartbeat_led_name(p_name: Option<&'static str>) {
+    *G_HEARTBEAT_LED_NAME.lock() = p_name;
+}
+
+/// Initialize the heartbeat app by resolving the LED interface ID and locking it.
+///
+/// If no LED name is configured, or if the HAL does not know the interface, the heartbeat
+/// is disabled and this function returns `Ok(())`.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters (unused).
+///
+/// # Errors
+/// Returns an error if the LED interface exists but cannot be locked.
+pub fn heartbeat_init(
+    p_app_id: u32,
+    _p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_HEARTBEAT_APP_ID.store(p_app_id, Ordering::Relaxed);
+    G_HEARTBEAT_STEP.store(0, Ordering::Relaxed);
+    *G_HEARTBEAT_LED_ID.lock() = None;
+
+    // Resolve LED interface, a missing interface simply disables the heartbeat
+    let Some(l_name) = *G_HEARTBEAT_LED_NAME.lock() else {
+        return Ok(());
+    };
+    let mut l_id = 0;
+    if syscall_hal(0, SysCallHalActions::GetID(l_name, &mut l_id), p_app_id).is_err() {
+        return Ok(());
+    }
+
+    // Try to get a lock on the interface
+    syscall_devices(
+        DeviceType::Peripheral(l_id),
+        SysCallDevicesArgs::Lock,
+        p_app_id,
+    )?;
+    *G_HEARTBEAT_LED_ID.lock() = Some(l_id);
+
+    Ok(())
+}
+
+/// Periodic heartbeat handler, drives the LED according to [`K_HEARTBEAT_PATTERN`].
+///
+/// # Errors
+/// Returns an error if the underlying HAL syscall fails.
+pub fn heartbeat() -> KernelResult<()> {
+    let l_led_id = *G_HEARTBEAT_LED_ID.lock();
+    if let Some(l_id) = l_led_id {
+        let l_step = G_HEARTBEAT_STEP.load(Ordering::Relaxed);
+        G_HEARTBEAT_STEP.store((l_step + 1) % K_HEARTBEAT_PATTERN.len(), Ordering::Relaxed);
+
+        syscall_hal(
+            l_id,
+            SysCallHalActions::Write(InterfaceWriteActions::GpioWrite(
+                if K_HEARTBEAT_PATTERN[l_step] {
+                    GpioWriteAction::Set
+                } else {
+                    GpioWriteAction::Clear
+                },
+            )),
+            G_HEARTBEAT_APP_ID.load(Ordering::Relaxed),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Stop the heartbeat by clearing the LED and unlocking the peripheral.
+///
+/// # Errors
+/// Returns any error from HAL writes or device unlock.
+pub fn heartbeat_end() -> KernelResult<()> {
+    let l_led_id = G_HEARTBEAT_LED_ID.lock().take();
+    if let Some(l_id) = l_led_id {
+        syscall_hal(
+            l_id,
+            SysCallHalActions::Write(InterfaceWriteActions::GpioWrite(GpioWriteAction::Clear)),
+            G_HEARTBEAT_APP_ID.load(Ordering::Relaxed),
+        )?;
+        syscall_devices(
+            DeviceType::Peripheral(l_id),
+            SysCallDevicesArgs::Unlock,
+            G_HEARTBEAT_APP_ID.load(Ordering::Relaxed),
+        )?;
+    }
+
+    Ok(())
+}
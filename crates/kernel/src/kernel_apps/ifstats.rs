@@ -0,0 +1,64 @@
+//! Interface statistics application.
+//!
+//! Prints, for each HAL interface, the number of operations and errors since boot. A high
+//! error ratio points to a flaky peripheral without needing external tools.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use hal_interface::{K_MAX_INTERFACES, interface_name};
+use heapless::{String, Vec, format};
+
+use crate::data::Kernel;
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelError, KernelResult,
+    syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the ifstats app.
+static G_IFSTATS_APP_ID: AtomicU32 = AtomicU32::new(0);
+
+/// Kernel app entry point for the ifstats command.
+///
+/// # Errors
+/// Returns an error if reading the statistics or writing to the terminal fails.
+pub fn ifstats() -> KernelResult<()> {
+    let l_id = G_IFSTATS_APP_ID.load(Ordering::Relaxed);
+
+    syscall_terminal(
+        ConsoleFormatting::StrNewLineBefore("INTERFACE            OPS        ERRORS"),
+        l_id,
+    )?;
+
+    // Interface IDs are contiguous, stop at the first unknown one
+    for l_if_id in 0..K_MAX_INTERFACES {
+        let Ok(l_name) = interface_name(l_if_id) else {
+            break;
+        };
+        let (l_ops, l_errors) = Kernel::hal()
+            .interface_stats(l_if_id)
+            .map_err(KernelError::HalError)?;
+
+        syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore(
+                format!(60; "{:<20} {:<10} {}", l_name, l_ops, l_errors)
+                    .unwrap()
+                    .as_str(),
+            ),
+            l_id,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Initialize the ifstats app by storing its scheduler id.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters (unused).
+pub fn ifstats_init(
+    p_app_id: u32,
+    _p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_IFSTATS_APP_ID.store(p_app_id, Ordering::Relaxed);
+    Ok(())
+}
@@ -0,0 +1,74 @@
+//! Interrupt latency application.
+//!
+//! Prints the worst SysTick interrupt latency measured since startup, or restarts the
+//! measurement: `latency [reset]`. See [`max_isr_latency_cycles`] for what is measured.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec, format};
+use spin::Mutex;
+
+use crate::data::Kernel;
+use crate::systick::reset_isr_latency;
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult,
+    max_isr_latency_cycles, syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the latency app.
+static G_LATENCY_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+/// Captured parameters for the latency app.
+static G_LATENCY_PARAM_STORAGE: Mutex<Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>> =
+    Mutex::new(Vec::new());
+
+/// Kernel app entry point for the latency command.
+///
+/// Without parameter, prints the worst latency in core cycles and in microseconds. With
+/// `reset`, restarts the measurement.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails.
+pub fn latency() -> KernelResult<()> {
+    let l_storage = G_LATENCY_PARAM_STORAGE.lock();
+    let l_id = G_LATENCY_ID_STORAGE.load(Ordering::Relaxed);
+
+    match (l_storage.first().map(|l_p| l_p.as_str()), l_storage.len()) {
+        (None, _) => {
+            let l_cycles = max_isr_latency_cycles();
+            let l_core_frequency = Kernel::time_data().core_frequency.to_u32().max(1);
+            let l_us = l_cycles as u64 * 1_000_000 / l_core_frequency as u64;
+            syscall_terminal(
+                ConsoleFormatting::StrNewLineBefore(
+                    format!(60; "Max SysTick latency: {} cycles ({} us)", l_cycles, l_us)
+                        .unwrap()
+                        .as_str(),
+                ),
+                l_id,
+            )
+        }
+        (Some("reset"), 1) => {
+            reset_isr_latency();
+            syscall_terminal(
+                ConsoleFormatting::StrNewLineBefore("Latency measurement restarted"),
+                l_id,
+            )
+        }
+        _ => syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore("Usage: latency [reset]"),
+            l_id,
+        ),
+    }
+}
+
+/// Capture parameters and app id for the latency command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters for the command.
+pub fn latency_init(
+    p_app_id: u32,
+    p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_LATENCY_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    *G_LATENCY_PARAM_STORAGE.lock() = p_param;
+    Ok(())
+}
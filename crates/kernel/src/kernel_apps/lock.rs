@@ -0,0 +1,165 @@
+//! Device lock applications.
+//!
+//! Take and release the terminal or display lock from the shell to exercise the devices
+//! ownership rules: `lock <terminal|display>` and `unlock <terminal|display>`.
+//!
+//! App IDs change at each start, so the ID used by a successful `lock` is recorded as the
+//! shell owner of the device and reused by `unlock`. When the shell does not own the device,
+//! `unlock` uses its own ID and is refused if another app holds the lock.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec, format};
+use spin::Mutex;
+
+use crate::{
+    ConsoleFormatting, DeviceType, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, K_PRIMARY_DISPLAY,
+    KernelResult, SysCallDevicesArgs, syscall_devices, syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the lock app.
+static G_LOCK_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+/// Captured parameters for the lock app.
+static G_LOCK_PARAM_STORAGE: Mutex<Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>> =
+    Mutex::new(Vec::new());
+/// Last assigned scheduler ID for the unlock app.
+static G_UNLOCK_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+/// Captured parameters for the unlock app.
+static G_UNLOCK_PARAM_STORAGE: Mutex<Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>> =
+    Mutex::new(Vec::new());
+/// Caller ID holding the lock on behalf of the shell, for the terminal and the display.
+static G_SHELL_OWNERS: Mutex<[Option<u32>; 2]> = Mutex::new([None; 2]);
+
+/// Kernel app entry point for the lock command.
+///
+/// Locks the device with the app ID and prints the resulting lock state. A refused lock is
+/// reported by the kernel error handler.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails.
+pub fn lock() -> KernelResult<()> {
+    let l_id = G_LOCK_ID_STORAGE.load(Ordering::Relaxed);
+    let Some(l_index) = parse_device(&G_LOCK_PARAM_STORAGE.lock()) else {
+        return syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore("Usage: lock <terminal|display>"),
+            l_id,
+        );
+    };
+
+    // Errors are already reported by the syscall
+    if syscall_devices(device(l_index), SysCallDevicesArgs::Lock, l_id).is_ok() {
+        G_SHELL_OWNERS.lock()[l_index] = Some(l_id);
+    }
+
+    print_state(l_index, l_id)
+}
+
+/// Kernel app entry point for the unlock command.
+///
+/// Unlocks the device with the ID recorded by `lock`, or with the app ID if the shell does
+/// not own the device, then prints the resulting lock state. A refused unlock is reported by
+/// the kernel error handler.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails, including when the terminal is still
+/// locked by another app.
+pub fn unlock() -> KernelResult<()> {
+    let l_id = G_UNLOCK_ID_STORAGE.load(Ordering::Relaxed);
+    let Some(l_index) = parse_device(&G_UNLOCK_PARAM_STORAGE.lock()) else {
+        return syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore("Usage: unlock <terminal|display>"),
+            l_id,
+        );
+    };
+
+    let l_owner = G_SHELL_OWNERS.lock()[l_index].unwrap_or(l_id);
+
+    // Errors are already reported by the syscall
+    if syscall_devices(device(l_index), SysCallDevicesArgs::Unlock, l_owner).is_ok() {
+        G_SHELL_OWNERS.lock()[l_index] = None;
+    }
+
+    print_state(l_index, l_id)
+}
+
+/// Capture parameters and app id for the lock command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters for the command.
+pub fn lock_init(
+    p_app_id: u32,
+    p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_LOCK_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    *G_LOCK_PARAM_STORAGE.lock() = p_param;
+    Ok(())
+}
+
+/// Capture parameters and app id for the unlock command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters for the command.
+pub fn unlock_init(
+    p_app_id: u32,
+    p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_UNLOCK_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    *G_UNLOCK_PARAM_STORAGE.lock() = p_param;
+    Ok(())
+}
+
+/// Parses the device parameter.
+///
+/// # Parameters
+/// - `param`: Parameters of the command, a single device name is expected.
+///
+/// # Returns
+/// The index of the device in the shell owners table, or `None` if the parameters are invalid.
+fn parse_device(p_param: &[String<K_MAX_APP_PARAM_SIZE>]) -> Option<usize> {
+    match p_param {
+        [l_name] if l_name == "terminal" => Some(0),
+        [l_name] if l_name == "display" => Some(1),
+        _ => None,
+    }
+}
+
+/// Returns the device matching an index of the shell owners table.
+///
+/// # Parameters
+/// - `index`: Index returned by [`parse_device`].
+fn device(p_index: usize) -> DeviceType {
+    if p_index == 0 {
+        DeviceType::Terminal
+    } else {
+        DeviceType::Display(K_PRIMARY_DISPLAY)
+    }
+}
+
+/// Queries and prints the lock state of a device.
+///
+/// # Parameters
+/// - `index`: Index of the device in the shell owners table.
+/// - `caller_id`: ID used for the query and the terminal write.
+///
+/// # Errors
+/// Returns an error if the query or the terminal write fails.
+fn print_state(p_index: usize, p_caller_id: u32) -> KernelResult<()> {
+    let l_device = device(p_index);
+    let l_name = l_device.name()?;
+    let mut l_locked = false;
+    syscall_devices(
+        l_device,
+        SysCallDevicesArgs::GetState(&mut l_locked),
+        p_caller_id,
+    )?;
+
+    syscall_terminal(
+        ConsoleFormatting::StrNewLineBefore(
+            format!(40; "{}: {}", l_name, if l_locked { "Locked" } else { "Unlocked" })
+                .unwrap()
+                .as_str(),
+        ),
+        p_caller_id,
+    )
+}
@@ -0,0 +1,68 @@
+//! Log dump application.
+//!
+//! Prints the content of the kernel log ring buffer on the terminal, oldest entry first.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec};
+use spin::Mutex;
+
+use crate::logger::log_entries;
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the log app.
+static G_LOG_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+/// Captured parameters for the log app.
+static G_LOG_PARAM_STORAGE: Mutex<Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>> =
+    Mutex::new(Vec::new());
+
+/// Kernel app entry point for the log command.
+///
+/// Dumps all stored log entries on the terminal. No parameter is accepted.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails.
+pub fn log() -> KernelResult<()> {
+    let l_storage = G_LOG_PARAM_STORAGE.lock();
+
+    if !l_storage.is_empty() {
+        syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore("Invalid action"),
+            G_LOG_ID_STORAGE.load(Ordering::Relaxed),
+        )?;
+        return Ok(());
+    }
+
+    let l_entries = log_entries();
+    if l_entries.is_empty() {
+        syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore("Log is empty"),
+            G_LOG_ID_STORAGE.load(Ordering::Relaxed),
+        )?;
+        return Ok(());
+    }
+
+    for l_entry in l_entries.iter() {
+        syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore(l_entry.as_str()),
+            G_LOG_ID_STORAGE.load(Ordering::Relaxed),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Capture parameters and app id for the log command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters for the command.
+pub fn log_init(
+    p_app_id: u32,
+    p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_LOG_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    *G_LOG_PARAM_STORAGE.lock() = p_param;
+    Ok(())
+}
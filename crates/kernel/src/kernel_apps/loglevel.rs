@@ -0,0 +1,95 @@
+//! Log verbosity application.
+//!
+//! Prints or sets the runtime log threshold: `loglevel [off|error|warn|info|debug]`.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec, format};
+use spin::Mutex;
+
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, LogLevel,
+    get_log_level, set_log_level, syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the loglevel app.
+static G_LOGLEVEL_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+/// Captured parameters for the loglevel app.
+static G_LOGLEVEL_PARAM_STORAGE: Mutex<Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>> =
+    Mutex::new(Vec::new());
+
+/// Accepted level names, as `(name, level)` pairs.
+const K_LOG_LEVEL_NAMES: [(&str, LogLevel); 5] = [
+    ("off", LogLevel::Off),
+    ("error", LogLevel::Error),
+    ("warn", LogLevel::Warning),
+    ("info", LogLevel::Info),
+    ("debug", LogLevel::Debug),
+];
+
+/// Kernel app entry point for the loglevel command.
+///
+/// Without parameter, prints the current threshold. With a level name, sets the threshold
+/// and confirms the new level on the terminal.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails.
+pub fn loglevel() -> KernelResult<()> {
+    let l_storage = G_LOGLEVEL_PARAM_STORAGE.lock();
+    let l_id = G_LOGLEVEL_ID_STORAGE.load(Ordering::Relaxed);
+
+    let l_level = match (l_storage.first(), l_storage.len()) {
+        (None, _) => get_log_level(),
+        (Some(l_name), 1) => {
+            match K_LOG_LEVEL_NAMES
+                .iter()
+                .find(|(l_n, _)| *l_n == l_name.as_str())
+            {
+                Some((_, l_level)) => {
+                    set_log_level(*l_level);
+                    *l_level
+                }
+                None => {
+                    syscall_terminal(
+                        ConsoleFormatting::StrNewLineBefore(
+                            "Usage: loglevel [off|error|warn|info|debug]",
+                        ),
+                        l_id,
+                    )?;
+                    return Ok(());
+                }
+            }
+        }
+        _ => {
+            syscall_terminal(
+                ConsoleFormatting::StrNewLineBefore("Usage: loglevel [off|error|warn|info|debug]"),
+                l_id,
+            )?;
+            return Ok(());
+        }
+    };
+
+    // Level names cover all variants
+    let l_name = K_LOG_LEVEL_NAMES
+        .iter()
+        .find(|(_, l_l)| *l_l == l_level)
+        .map(|(l_n, _)| *l_n)
+        .unwrap_or_default();
+    syscall_terminal(
+        ConsoleFormatting::StrNewLineBefore(format!(40; "Log level: {}", l_name).unwrap().as_str()),
+        l_id,
+    )
+}
+
+/// Capture parameters and app id for the loglevel command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters for the command.
+pub fn loglevel_init(
+    p_app_id: u32,
+    p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_LOGLEVEL_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    *G_LOGLEVEL_PARAM_STORAGE.lock() = p_param;
+    Ok(())
+}
@@ -1,73 +1,535 @@
-use crate::{AppConfig, AppStatus, CallPeriodicity, KernelResult, Milliseconds, apps};
+use crate::{AppConfig, AppStatus, CallMethod, CallPeriodicity, KernelResult, Milliseconds, apps};
 
-use self::reboot::K_REBOOT_DELAY;
+use self::monitor::K_MONITOR_BASE_PERIOD;
+use self::reboot::{K_REBOOT_DURATION, K_REBOOT_PERIOD_MS};
+use self::screenshot::K_SCREENSHOT_PERIOD;
 
+pub(crate) use self::console_heartbeat::set_console_heartbeat;
+pub use self::heartbeat::set_heartbeat_led_name;
+
+mod alias;
 mod app_ctrl;
+mod boot;
+mod bright;
+mod caps;
+mod color;
+mod console;
+mod console_heartbeat;
+mod cycles;
+mod display;
+mod err;
 mod err_gen;
+mod gpio;
+mod heartbeat;
+mod ifstats;
+mod latency;
 mod led_blink;
+mod lock;
+mod log;
+mod loglevel;
+mod monitor;
+mod peek;
 mod reboot;
+mod resetreason;
+mod screenshot;
+mod selftest;
+mod slots;
+mod stack;
+mod tasks;
+mod testpattern;
+mod time;
+mod tone;
+mod top;
+mod version;
 
 /// Default kernel apps compiled into the firmware.
 ///
 /// Each entry defines:
 /// - the app `name` used for lookup/control,
 /// - its scheduling `periodicity`,
-/// - the function to execute (`app_fn`),
+/// - the function to execute (`app_fn`) and its optional parameter (`param`),
 /// - optional lifecycle hooks (`init_fn`, `end_fn`),
+/// - whether the display is cleared when the app exits (`clear_display_on_exit`),
+/// - whether the app is started during initialization (`autostart`),
 /// - and the current status/id fields used by the scheduler.
-const K_DEFAULT_APPS: [AppConfig; 4] = [
+const K_DEFAULT_APPS: [AppConfig; 35] = [
     AppConfig {
         name: "app_ctrl",
         periodicity: CallPeriodicity::Once,
-        app_fn: app_ctrl::app_ctrl,
+        app_fn: CallMethod::Call(app_ctrl::app_ctrl),
+        param: None,
         init_fn: Some(app_ctrl::app_ctrl_init),
         end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
         app_status: AppStatus::Stopped,
         id: None,
     },
     AppConfig {
         name: "led_blink",
         periodicity: CallPeriodicity::Periodic(Milliseconds(1000)),
-        app_fn: led_blink::led_blink,
+        app_fn: CallMethod::Call(led_blink::led_blink),
+        param: None,
         init_fn: Some(led_blink::init_led_blink),
         end_fn: Some(led_blink::stop_led_blink),
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: true,
         app_status: AppStatus::Stopped,
         id: None,
     },
     AppConfig {
         name: "reboot",
         periodicity: CallPeriodicity::PeriodicUntil(
-            Milliseconds(1000),
-            Milliseconds((K_REBOOT_DELAY + 1) as u32 * 1000),
+            Milliseconds(K_REBOOT_PERIOD_MS),
+            K_REBOOT_DURATION,
         ),
-        app_fn: reboot::reboot_periodic,
+        app_fn: CallMethod::Call(reboot::reboot_periodic),
+        param: None,
         init_fn: Some(reboot::reboot_init),
         end_fn: Some(reboot::reboot_end),
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
         app_status: AppStatus::Stopped,
         id: None,
     },
     AppConfig {
         name: "err_gen",
         periodicity: CallPeriodicity::Once,
-        app_fn: err_gen::err_gen,
+        app_fn: CallMethod::Call(err_gen::err_gen),
+        param: None,
         init_fn: Some(err_gen::err_gen_init),
         end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "heartbeat",
+        periodicity: CallPeriodicity::Periodic(Milliseconds(250)),
+        app_fn: CallMethod::Call(heartbeat::heartbeat),
+        param: None,
+        init_fn: Some(heartbeat::heartbeat_init),
+        end_fn: Some(heartbeat::heartbeat_end),
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: true,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "log",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(log::log),
+        param: None,
+        init_fn: Some(log::log_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "version",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(version::version),
+        param: None,
+        init_fn: Some(version::version_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "bright",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(bright::bright),
+        param: None,
+        init_fn: Some(bright::bright_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "peek",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(peek::peek),
+        param: None,
+        init_fn: Some(peek::peek_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "selftest",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(selftest::selftest),
+        param: None,
+        init_fn: Some(selftest::selftest_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "top",
+        periodicity: CallPeriodicity::PeriodicUntil(Milliseconds(2000), Milliseconds(20000)),
+        app_fn: CallMethod::Call(top::top),
+        param: None,
+        init_fn: Some(top::top_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "alias",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(alias::alias),
+        param: None,
+        init_fn: Some(alias::alias_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "tasks",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(tasks::tasks),
+        param: None,
+        init_fn: Some(tasks::tasks_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "color",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(color::color),
+        param: None,
+        init_fn: Some(color::color_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "ifstats",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(ifstats::ifstats),
+        param: None,
+        init_fn: Some(ifstats::ifstats_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "testpattern",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(testpattern::testpattern),
+        param: None,
+        init_fn: Some(testpattern::testpattern_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "err",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(err::err),
+        param: None,
+        init_fn: Some(err::err_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "caps",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(caps::caps),
+        param: None,
+        init_fn: Some(caps::caps_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "gpio",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(gpio::gpio),
+        param: None,
+        init_fn: Some(gpio::gpio_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "loglevel",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(loglevel::loglevel),
+        param: None,
+        init_fn: Some(loglevel::loglevel_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "cycles",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(cycles::cycles),
+        param: None,
+        init_fn: Some(cycles::cycles_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "lock",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(lock::lock),
+        param: None,
+        init_fn: Some(lock::lock_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "unlock",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(lock::unlock),
+        param: None,
+        init_fn: Some(lock::unlock_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "screenshot",
+        periodicity: CallPeriodicity::Periodic(K_SCREENSHOT_PERIOD),
+        app_fn: CallMethod::Call(screenshot::screenshot),
+        param: None,
+        init_fn: Some(screenshot::screenshot_init),
+        end_fn: Some(screenshot::screenshot_end),
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "console",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(console::console),
+        param: None,
+        init_fn: Some(console::console_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "tone",
+        periodicity: CallPeriodicity::Periodic(Milliseconds(50)),
+        app_fn: CallMethod::Call(tone::tone),
+        param: None,
+        init_fn: Some(tone::tone_init),
+        end_fn: Some(tone::tone_end),
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "slots",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(slots::slots),
+        param: None,
+        init_fn: Some(slots::slots_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "resetreason",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(resetreason::resetreason),
+        param: None,
+        init_fn: Some(resetreason::resetreason_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "monitor",
+        periodicity: CallPeriodicity::Periodic(K_MONITOR_BASE_PERIOD),
+        app_fn: CallMethod::Call(monitor::monitor),
+        param: None,
+        init_fn: Some(monitor::monitor_init),
+        end_fn: Some(monitor::monitor_end),
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "stack",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(stack::stack),
+        param: None,
+        init_fn: Some(stack::stack_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "boot",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(boot::boot),
+        param: None,
+        init_fn: Some(boot::boot_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "display",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(display::display),
+        param: None,
+        init_fn: Some(display::display_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "time",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(time::time),
+        param: None,
+        init_fn: Some(time::time_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "latency",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(latency::latency),
+        param: None,
+        init_fn: Some(latency::latency_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
+        app_status: AppStatus::Stopped,
+        id: None,
+    },
+    AppConfig {
+        name: "console_heartbeat",
+        periodicity: CallPeriodicity::Once,
+        app_fn: CallMethod::Call(console_heartbeat::console_heartbeat),
+        param: None,
+        init_fn: Some(console_heartbeat::console_heartbeat_init),
+        end_fn: None,
+        on_shutdown: None,
+        clear_display_on_exit: false,
+        autostart: false,
         app_status: AppStatus::Stopped,
         id: None,
     },
 ];
 
-/// List of default apps that should be started automatically during initialization.
-const K_DEFAULT_APPS_START_LIST: [&str; 1] = ["led_blink"];
-
-/// Register default kernel apps and start those included in [`K_DEFAULT_APPS_START_LIST`].
-pub fn init_kernel_apps() -> KernelResult<()> {
+/// Register default kernel apps and start those flagged with `autostart`.
+///
+/// The autostart flags saved with the `boot` command replace the compiled ones, unless the
+/// saved configuration is missing or corrupted.
+///
+/// # Parameters
+/// - `skip_autostart`: Register the apps without starting any of them (safe mode).
+pub fn init_kernel_apps(p_skip_autostart: bool) -> KernelResult<()> {
     for l_app in K_DEFAULT_APPS.iter() {
         apps().add_app(*l_app)?;
+    }
+    apps().load_autostart();
 
-        // Check if the app is in the start list
-        if K_DEFAULT_APPS_START_LIST.contains(&l_app.name) {
-            apps().start_app(l_app.name)?;
+    // Start the apps flagged for autostart
+    if !p_skip_autostart {
+        for l_name in apps().list_autostart() {
+            apps().start_app(l_name)?;
         }
     }
 
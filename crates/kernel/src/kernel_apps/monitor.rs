@@ -0,0 +1,187 @@
+//! Sensor monitor application.
+//!
+//! Periodically reads an input interface and prints its value on the terminal:
+//! `monitor <name> <period_ms>`. The app is called every [`K_MONITOR_BASE_PERIOD`] and reads
+//! the interface once every `period_ms`, rounded down to a multiple of the base period. It
+//! runs until stopped with Ctrl-C at the prompt.
+//!
+//! GPIO inputs are the only scalar read supported by the HAL, their level is printed as 0 or 1.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use hal_interface::{
+    HalError, InterfaceReadAction, InterfaceReadResult, K_MAX_INTERFACES, interface_name,
+};
+use heapless::{String, Vec, format};
+use spin::Mutex;
+
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelError, KernelResult,
+    Milliseconds, SysCallHalActions, syscall_hal, syscall_terminal,
+};
+
+/// Call period of the monitor app, also the shortest accepted read period.
+pub(super) const K_MONITOR_BASE_PERIOD: Milliseconds = Milliseconds(100);
+
+/// App identifier used for HAL reads and terminal output.
+static G_MONITOR_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+
+/// Interface ID of the monitored input, `None` when the app is not running.
+static G_MONITOR_IF_ID: Mutex<Option<usize>> = Mutex::new(None);
+
+/// Number of app calls between two reads.
+static G_MONITOR_DIVIDER: AtomicU32 = AtomicU32::new(1);
+
+/// Number of app calls since the last read.
+static G_MONITOR_COUNTER: AtomicU32 = AtomicU32::new(0);
+
+/// Kernel app entry point for the monitor command.
+///
+/// Reads and prints the interface value once every configured period.
+///
+/// # Errors
+/// Returns an error if the HAL read or the terminal output fails.
+pub fn monitor() -> KernelResult<()> {
+    let Some(l_if_id) = *G_MONITOR_IF_ID.lock() else {
+        return Ok(());
+    };
+
+    // Read on the first call, then once every divider calls
+    let l_counter = G_MONITOR_COUNTER.load(Ordering::Relaxed);
+    G_MONITOR_COUNTER.store(
+        (l_counter + 1) % G_MONITOR_DIVIDER.load(Ordering::Relaxed),
+        Ordering::Relaxed,
+    );
+    if l_counter != 0 {
+        return Ok(());
+    }
+
+    let l_id = G_MONITOR_ID_STORAGE.load(Ordering::Relaxed);
+    let l_value = read_value(l_if_id, l_id)?;
+    syscall_terminal(
+        ConsoleFormatting::StrNewLineBefore(
+            format!(40; "{}: {}", interface_name(l_if_id).unwrap_or("?"), l_value)
+                .unwrap()
+                .as_str(),
+        ),
+        l_id,
+    )
+}
+
+/// Initialize the monitor app by resolving the interface and the read period.
+///
+/// The interface is read once to check it can be monitored, so an unsupported interface is
+/// rejected before the app is scheduled.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters for the command, the interface name and the period in ms.
+///
+/// # Errors
+/// - [`KernelError::AppParamMissing`] if the parameters are invalid.
+/// - [`KernelError::UnknownInterface`] if the interface does not exist.
+/// - [`KernelError::UnsupportedInterface`] if the interface cannot be monitored.
+/// - Any other error from the HAL read.
+///
+/// The reason is printed on the terminal before failing.
+pub fn monitor_init(
+    p_app_id: u32,
+    p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_MONITOR_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    G_MONITOR_COUNTER.store(0, Ordering::Relaxed);
+    *G_MONITOR_IF_ID.lock() = None;
+
+    let l_period = match (p_param.len(), p_param.get(1).map(|l_p| l_p.parse::<u32>())) {
+        (2, Some(Ok(l_period))) if l_period >= K_MONITOR_BASE_PERIOD.to_u32() => l_period,
+        _ => {
+            syscall_terminal(
+                ConsoleFormatting::StrNewLineBefore(
+                    format!(60; "Usage: monitor <name> <period_ms>, period >= {} ms",
+                        K_MONITOR_BASE_PERIOD.to_u32())
+                    .unwrap()
+                    .as_str(),
+                ),
+                p_app_id,
+            )?;
+            return Err(KernelError::AppParamMissing("monitor"));
+        }
+    };
+
+    // Resolve the interface ID, IDs are contiguous
+    let l_name = p_param[0].as_str();
+    let Some(l_if_id) = (0..K_MAX_INTERFACES)
+        .map_while(|l_if_id| interface_name(l_if_id).ok().map(|l_n| (l_if_id, l_n)))
+        .find(|(_, l_n)| *l_n == l_name)
+        .map(|(l_if_id, _)| l_if_id)
+    else {
+        syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore(
+                format!(60; "Unknown interface {}", l_name)
+                    .unwrap()
+                    .as_str(),
+            ),
+            p_app_id,
+        )?;
+        return Err(KernelError::UnknownInterface("monitor"));
+    };
+
+    match read_value(l_if_id, p_app_id) {
+        Ok(_) => {}
+        Err(KernelError::HalError(
+            HalError::IncompatibleAction(..) | HalError::WriteOnlyInterface(_),
+        )) => {
+            syscall_terminal(
+                ConsoleFormatting::StrNewLineBefore(
+                    format!(60; "Interface {} cannot be monitored", l_name)
+                        .unwrap()
+                        .as_str(),
+                ),
+                p_app_id,
+            )?;
+            return Err(KernelError::UnsupportedInterface(
+                "monitor",
+                interface_name(l_if_id).unwrap_or("?"),
+            ));
+        }
+        Err(l_err) => return Err(l_err),
+    }
+
+    G_MONITOR_DIVIDER.store(l_period / K_MONITOR_BASE_PERIOD.to_u32(), Ordering::Relaxed);
+    *G_MONITOR_IF_ID.lock() = Some(l_if_id);
+
+    Ok(())
+}
+
+/// Stop monitoring.
+///
+/// # Errors
+/// This function does not fail.
+pub fn monitor_end() -> KernelResult<()> {
+    *G_MONITOR_IF_ID.lock() = None;
+    Ok(())
+}
+
+/// Reads the current value of an interface.
+///
+/// # Parameters
+/// - `if_id`: Interface to read.
+/// - `id`: Caller id used for the HAL read.
+///
+/// # Errors
+/// Returns [`KernelError::HalError`] if the interface cannot be read.
+fn read_value(p_if_id: usize, p_id: u32) -> KernelResult<u8> {
+    let mut l_result = InterfaceReadResult::GpioRead(false);
+    syscall_hal(
+        p_if_id,
+        SysCallHalActions::Read(InterfaceReadAction::GpioRead, &mut l_result),
+        p_id,
+    )?;
+
+    match l_result {
+        InterfaceReadResult::GpioRead(l_state) => Ok(l_state as u8),
+        _ => Err(KernelError::HalError(HalError::IncompatibleAction(
+            "GPIO Read",
+            interface_name(p_if_id).unwrap_or("?"),
+        ))),
+    }
+}
@@ -0,0 +1,134 @@
+//! Memory dump application.
+//!
+//! Prints a memory region in hex and ASCII from the terminal: `peek <addr> <len>`.
+//! The address is parsed as hexadecimal (with or without `0x` prefix), the length as
+//! decimal unless prefixed by `0x`.
+//!
+//! Only obviously invalid requests are rejected (null address, length above
+//! [`K_PEEK_MAX_LEN`], address overflow). Reading an unmapped or reserved address still
+//! triggers a HardFault.
+
+use core::fmt::Write;
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec};
+use spin::Mutex;
+
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, syscall_terminal,
+    syscall_terminal_pager,
+};
+
+/// Maximum number of bytes dumped by a single command.
+const K_PEEK_MAX_LEN: u32 = 256;
+/// Number of bytes displayed per line.
+const K_PEEK_BYTES_PER_LINE: u32 = 16;
+
+/// Last assigned scheduler ID for the peek app.
+static G_PEEK_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+/// Captured parameters for the peek app.
+static G_PEEK_PARAM_STORAGE: Mutex<Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>> =
+    Mutex::new(Vec::new());
+
+/// Parses a numeric parameter.
+///
+/// # Parameters
+/// - `param`: String to parse.
+/// - `default_radix`: Radix used when no `0x` prefix is present.
+///
+/// # Returns
+/// The parsed value, or `None` if the string is not a valid number.
+fn parse_number(p_param: &str, p_default_radix: u32) -> Option<u32> {
+    match p_param
+        .strip_prefix("0x")
+        .or_else(|| p_param.strip_prefix("0X"))
+    {
+        Some(l_hex) => u32::from_str_radix(l_hex, 16).ok(),
+        None => u32::from_str_radix(p_param, p_default_radix).ok(),
+    }
+}
+
+/// Kernel app entry point for the peek command.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails.
+pub fn peek() -> KernelResult<()> {
+    let l_storage = G_PEEK_PARAM_STORAGE.lock();
+    let l_id = G_PEEK_ID_STORAGE.load(Ordering::Relaxed);
+
+    if l_storage.len() != 2 {
+        syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore("Usage: peek <addr> <len>"),
+            l_id,
+        )?;
+        return Ok(());
+    }
+
+    let (Some(l_addr), Some(l_len)) = (
+        parse_number(&l_storage[0], 16),
+        parse_number(&l_storage[1], 10),
+    ) else {
+        syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore("Invalid parameter"),
+            l_id,
+        )?;
+        return Ok(());
+    };
+
+    // Reject obviously invalid requests
+    if l_addr == 0 || l_len == 0 || l_len > K_PEEK_MAX_LEN || l_addr.checked_add(l_len).is_none() {
+        syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore("Invalid memory region"),
+            l_id,
+        )?;
+        return Ok(());
+    }
+
+    // A full dump does not fit on the display
+    syscall_terminal_pager(true, l_id)?;
+
+    // Dump memory, one line per 16 bytes
+    let mut l_offset = 0;
+    while l_offset < l_len {
+        let l_line_len = K_PEEK_BYTES_PER_LINE.min(l_len - l_offset);
+        let mut l_line: String<80> = String::new();
+        let mut l_ascii: String<16> = String::new();
+
+        write!(l_line, "{:08X}:", l_addr + l_offset).unwrap_or(());
+        for l_i in 0..K_PEEK_BYTES_PER_LINE {
+            if l_i < l_line_len {
+                let l_byte =
+                    unsafe { core::ptr::read_volatile((l_addr + l_offset + l_i) as *const u8) };
+                write!(l_line, " {:02X}", l_byte).unwrap_or(());
+                l_ascii
+                    .push(if l_byte.is_ascii_graphic() || l_byte == b' ' {
+                        l_byte as char
+                    } else {
+                        '.'
+                    })
+                    .unwrap_or(());
+            } else {
+                l_line.push_str("   ").unwrap_or(());
+            }
+        }
+        write!(l_line, "  |{}|", l_ascii).unwrap_or(());
+
+        syscall_terminal(ConsoleFormatting::StrNewLineBefore(l_line.as_str()), l_id)?;
+        l_offset += l_line_len;
+    }
+
+    Ok(())
+}
+
+/// Capture parameters and app id for the peek command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters for the command.
+pub fn peek_init(
+    p_app_id: u32,
+    p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_PEEK_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    *G_PEEK_PARAM_STORAGE.lock() = p_param;
+    Ok(())
+}
@@ -1,9 +1,12 @@
-use core::sync::atomic::{AtomicU8, AtomicU32, Ordering};
+use core::sync::atomic::{AtomicU32, Ordering};
 
 use heapless::{String, Vec, format};
 
+use crate::data::Kernel;
+use crate::reset_reason::{ResetReason, record_reset_reason};
 use crate::{
-    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, syscall_terminal,
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, Milliseconds,
+    syscall_terminal,
 };
 
 /// Stores the app ID associated with the current command context.
@@ -27,6 +30,10 @@ pub fn reboot_init(
 
 /// Perform the final reboot action by resetting the system.
 ///
+/// The reset reason is recorded first, then the shutdown hooks of the running apps are called
+/// within [`K_SHUTDOWN_BUDGET`] (see [`crate::apps::AppsManager::shutdown`]). The terminal
+/// output, including the hook failures, is flushed last.
+///
 /// # Returns
 /// This function does not return, as it triggers a system reset.
 ///
@@ -34,30 +41,54 @@ pub fn reboot_init(
 /// This function never returns an error because the system reset is invoked
 /// unconditionally.
 pub fn reboot_end() -> KernelResult<()> {
-    // Reset the system
+    // Recorded first so a watchdog reset during the hooks is still reported as a reboot
+    record_reset_reason(ResetReason::Reboot);
+    Kernel::apps().shutdown(K_SHUTDOWN_BUDGET);
+
+    // Send the pending output, then reset the system
+    Kernel::terminal().flush().unwrap_or(());
     cortex_m::peripheral::SCB::sys_reset();
 }
 
+/// Time given to the app shutdown hooks before the reset.
+pub const K_SHUTDOWN_BUDGET: Milliseconds = Milliseconds(500);
+
 /// Default number of seconds to wait before rebooting.
 pub const K_REBOOT_DELAY: u8 = 3;
 
-/// Countdown value used by [`reboot_periodic`] to report remaining time.
-static G_REBOOT_COUNTER: AtomicU8 = AtomicU8::new(K_REBOOT_DELAY);
+/// Period of the reboot countdown messages, in milliseconds.
+pub const K_REBOOT_PERIOD_MS: u32 = 1000;
+
+/// Lifetime of the reboot task: one countdown message per second of delay, plus the final one.
+///
+/// Computed with checked arithmetic so that an oversized delay fails the build instead of
+/// wrapping around.
+pub const K_REBOOT_DURATION: Milliseconds =
+    match Milliseconds(K_REBOOT_PERIOD_MS).checked_mul(K_REBOOT_DELAY as u32 + 1) {
+        Some(l_duration) => l_duration,
+        None => panic!("Reboot duration overflows"),
+    };
 
 /// Periodic reboot countdown handler.
 ///
-/// Decrements the internal reboot counter and prints a message indicating the
-/// remaining time until reboot.
+/// Prints a message indicating the remaining time until reboot, computed from the
+/// remaining lifetime of the task in the scheduler.
 ///
 /// # Errors
-/// Returns any error produced by the terminal syscall.
+/// Returns any error produced by the scheduler query or the terminal syscall.
 pub fn reboot_periodic() -> KernelResult<()> {
+    // The current execution is still counted in the remaining lifetime
+    let l_remaining = Kernel::scheduler()
+        .get_remaining("reboot")?
+        .map(|l_r| l_r.to_u32().saturating_sub(K_REBOOT_PERIOD_MS))
+        .unwrap_or(0);
+
     syscall_terminal(
         ConsoleFormatting::StrNewLineBefore(
             format!(
                 50;
                 "Rebooting in {} seconds...",
-                G_REBOOT_COUNTER.fetch_sub(1, Ordering::Relaxed)
+                l_remaining / 1000
             )
             .unwrap()
             .as_str(),
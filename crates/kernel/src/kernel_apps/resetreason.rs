@@ -0,0 +1,42 @@
+//! Reset reason application.
+//!
+//! Prints the cause of the last reset, as captured at boot: `resetreason`.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec, format};
+
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, last_reset_reason,
+    syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the resetreason app.
+static G_RESETREASON_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+
+/// Kernel app entry point for the resetreason command.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails.
+pub fn resetreason() -> KernelResult<()> {
+    syscall_terminal(
+        ConsoleFormatting::StrNewLineBefore(
+            format!(40; "Last reset: {}", last_reset_reason().name())
+                .unwrap()
+                .as_str(),
+        ),
+        G_RESETREASON_ID_STORAGE.load(Ordering::Relaxed),
+    )
+}
+
+/// Capture the app id for the resetreason command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters (unused).
+pub fn resetreason_init(
+    p_app_id: u32,
+    _p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_RESETREASON_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    Ok(())
+}
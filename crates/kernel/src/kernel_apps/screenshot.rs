@@ -0,0 +1,228 @@
+//! Screenshot application.
+//!
+//! Dumps the displayed frame over the terminal serial output, so the rendering can be checked
+//! without seeing the panel. The dump is binary and is not mirrored on the display.
+//!
+//! # Format
+//! All multi-byte values are little-endian.
+//! - Magic: the 4 bytes `SSHT`.
+//! - Width and height in pixels, `u16` each.
+//! - Pixel format, `u8`: `1` for run-length encoded RGB888.
+//! - Runs covering all pixels row by row, from the top-left corner: a `u8` count (1 to 255)
+//!   followed by the red, green and blue components of the repeated pixel. Runs may span
+//!   several rows.
+//!
+//! The frame is sent [`K_SCREENSHOT_ROWS_PER_CALL`] rows at a time, one batch per run of the
+//! app, so the other tasks keep running and the watchdog is fed between two batches. The app
+//! ends after the last row, or when stopped with Ctrl-C at the prompt, which truncates the
+//! dump.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec};
+use spin::Mutex;
+
+use crate::data::Kernel;
+use crate::{
+    DeviceType, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelError, KernelResult, Milliseconds,
+    SysCallDisplayArgs, syscall_display,
+};
+
+/// Name of the screenshot app, used to set its lifetime.
+const K_SCREENSHOT_APP_NAME: &str = "screenshot";
+/// Call period of the screenshot app.
+pub(super) const K_SCREENSHOT_PERIOD: Milliseconds = Milliseconds(50);
+/// Number of rows sent by one run of the app.
+///
+/// A row of the 800 pixels wide panel takes at worst 3200 bytes, about 280 ms at 115200 bauds,
+/// so a batch stays well below the watchdog timeout.
+const K_SCREENSHOT_ROWS_PER_CALL: u16 = 2;
+
+/// Magic bytes starting a screenshot.
+const K_SCREENSHOT_MAGIC: &[u8; 4] = b"SSHT";
+/// Pixel format identifier of run-length encoded RGB888.
+const K_SCREENSHOT_FORMAT_RLE_RGB888: u8 = 1;
+/// Number of pixels read from the frame buffer at once.
+const K_SCREENSHOT_CHUNK_PIXELS: usize = 80;
+/// Size of the output buffer, flushed when full.
+const K_SCREENSHOT_OUT_SIZE: usize = 256;
+
+/// Last assigned scheduler ID for the screenshot app.
+static G_SCREENSHOT_APP_ID: AtomicU32 = AtomicU32::new(0);
+
+/// Screenshot in progress, `None` when the app is not running.
+static G_SCREENSHOT_PROGRESS: Mutex<Option<Progress>> = Mutex::new(None);
+
+/// State of a screenshot kept between two runs of the app.
+struct Progress {
+    /// Encoder, the current run may span two batches.
+    writer: RleWriter,
+    /// Frame size in pixels.
+    size: (u16, u16),
+    /// Next row to send, `None` until the header is sent.
+    row: Option<u16>,
+}
+
+/// Run-length encoder buffering the encoded runs before sending them.
+struct RleWriter {
+    /// Pixel of the current run, in ARGB8888.
+    pixel: u32,
+    /// Length of the current run, `0` before the first pixel.
+    count: u8,
+    /// Encoded bytes not sent yet.
+    out: Vec<u8, K_SCREENSHOT_OUT_SIZE>,
+}
+
+impl RleWriter {
+    /// Adds a pixel, closing the current run if the pixel differs or the run is full.
+    ///
+    /// # Errors
+    /// Propagates any error from the terminal output.
+    fn push(&mut self, p_pixel: u32) -> KernelResult<()> {
+        if self.count > 0 && (self.pixel != p_pixel || self.count == u8::MAX) {
+            self.end_run()?;
+        }
+        self.pixel = p_pixel;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Appends the current run to the output buffer, flushing it if full.
+    ///
+    /// # Errors
+    /// Propagates any error from the terminal output.
+    fn end_run(&mut self) -> KernelResult<()> {
+        if self.out.len() + 4 > K_SCREENSHOT_OUT_SIZE {
+            self.flush()?;
+        }
+        let [_, l_r, l_g, l_b] = self.pixel.to_be_bytes();
+        self.out
+            .extend_from_slice(&[self.count, l_r, l_g, l_b])
+            .unwrap_or(());
+        self.count = 0;
+        Ok(())
+    }
+
+    /// Sends the buffered bytes to the terminal serial output.
+    ///
+    /// # Errors
+    /// Propagates any error from the terminal output.
+    fn flush(&mut self) -> KernelResult<()> {
+        Kernel::terminal().write_raw_output(&self.out)?;
+        self.out.clear();
+        Ok(())
+    }
+}
+
+/// Kernel app entry point for the screenshot command.
+///
+/// Sends the header on the first run, then the next [`K_SCREENSHOT_ROWS_PER_CALL`] rows on
+/// each run. The run sending the last row also closes the last pixel run.
+///
+/// # Errors
+/// Returns an error if the app is not authorized to use the terminal, or if reading the frame
+/// buffer or writing to the terminal fails.
+pub fn screenshot() -> KernelResult<()> {
+    let l_id = G_SCREENSHOT_APP_ID.load(Ordering::Relaxed);
+    let mut l_guard = G_SCREENSHOT_PROGRESS.lock();
+    let Some(l_progress) = l_guard.as_mut() else {
+        return Ok(());
+    };
+    let (l_width, l_height) = l_progress.size;
+    let l_writer = &mut l_progress.writer;
+
+    // Header, the terminal is locked to the app once it is started
+    let l_first_row = match l_progress.row {
+        Some(l_row) => l_row,
+        None => {
+            Kernel::devices().authorize(DeviceType::Terminal, l_id)?;
+            l_writer
+                .out
+                .extend_from_slice(K_SCREENSHOT_MAGIC)
+                .unwrap_or(());
+            l_writer
+                .out
+                .extend_from_slice(&l_width.to_le_bytes())
+                .unwrap_or(());
+            l_writer
+                .out
+                .extend_from_slice(&l_height.to_le_bytes())
+                .unwrap_or(());
+            l_writer
+                .out
+                .push(K_SCREENSHOT_FORMAT_RLE_RGB888)
+                .unwrap_or(());
+            0
+        }
+    };
+    let l_last_row = l_first_row
+        .saturating_add(K_SCREENSHOT_ROWS_PER_CALL)
+        .min(l_height);
+
+    // Pixels, read by chunks of a row
+    let mut l_chunk = [0u32; K_SCREENSHOT_CHUNK_PIXELS];
+    for l_y in l_first_row..l_last_row {
+        let mut l_x = 0;
+        while l_x < l_width {
+            let l_len = (l_width - l_x).min(K_SCREENSHOT_CHUNK_PIXELS as u16);
+            Kernel::display()
+                .save_region(l_x, l_y, l_len, 1, &mut l_chunk[..l_len as usize])
+                .map_err(KernelError::DisplayError)?;
+            for l_pixel in &l_chunk[..l_len as usize] {
+                l_writer.push(*l_pixel)?;
+            }
+            l_x += l_len;
+        }
+    }
+    l_progress.row = Some(l_last_row);
+
+    if l_last_row == l_height && l_writer.count > 0 {
+        l_writer.end_run()?;
+    }
+    l_writer.flush()
+}
+
+/// Initialize the screenshot app.
+///
+/// Stores the scheduler id and the frame size, and sets the lifetime of the app to the number
+/// of runs needed to send the frame.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters (unused).
+///
+/// # Errors
+/// Returns an error if no display is configured or if the lifetime cannot be set.
+pub fn screenshot_init(
+    p_app_id: u32,
+    _p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_SCREENSHOT_APP_ID.store(p_app_id, Ordering::Relaxed);
+
+    let mut l_size = (0, 0);
+    syscall_display(SysCallDisplayArgs::GetSize(&mut l_size), p_app_id)?;
+    *G_SCREENSHOT_PROGRESS.lock() = Some(Progress {
+        writer: RleWriter {
+            pixel: 0,
+            count: 0,
+            out: Vec::new(),
+        },
+        size: l_size,
+        row: None,
+    });
+
+    // The header is sent with the first batch of rows
+    let l_runs = l_size.1.div_ceil(K_SCREENSHOT_ROWS_PER_CALL).max(1) as u32;
+    Kernel::scheduler().set_new_task_duration(
+        K_SCREENSHOT_APP_NAME,
+        Milliseconds(l_runs * K_SCREENSHOT_PERIOD.to_u32()),
+    )
+}
+
+/// Drop the screenshot in progress.
+///
+/// # Errors
+/// This function does not fail.
+pub fn screenshot_end() -> KernelResult<()> {
+    *G_SCREENSHOT_PROGRESS.lock() = None;
+    Ok(())
+}
@@ -0,0 +1,83 @@
+//! Firmware integrity self-test application.
+//!
+//! Computes the CRC-32 of the firmware code region (`__stext`..`__etext`, defined by the
+//! `cortex-m-rt` linker script) with the hardware CRC unit and compares it with the value
+//! stored in the reserved `.fw_crc` flash slot.
+//!
+//! The build does not compute the expected CRC: the slot holds `0xFFFFFFFF` (erased flash)
+//! and must be patched post-build with the CRC of the code region (CRC-32/MPEG-2: polynomial
+//! `0x04C11DB7`, initial value `0xFFFFFFFF`, no reflection, no final XOR). The slot address is
+//! given by the `G_FIRMWARE_CRC` symbol. While the slot is not patched, the self-test fails.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec, format};
+
+use crate::data::Kernel;
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelError, KernelResult,
+    syscall_terminal,
+};
+
+unsafe extern "C" {
+    /// Start of the `.text` section, provided by the linker script.
+    static __stext: u8;
+    /// End of the `.text` section, provided by the linker script.
+    static __etext: u8;
+}
+
+/// Expected CRC of the firmware code region, patched post-build.
+#[used]
+#[unsafe(no_mangle)]
+#[unsafe(link_section = ".fw_crc")]
+static G_FIRMWARE_CRC: u32 = 0xFFFF_FFFF;
+
+/// Last assigned scheduler ID for the selftest app.
+static G_SELFTEST_APP_ID: AtomicU32 = AtomicU32::new(0);
+
+/// Kernel app entry point for the selftest command.
+///
+/// # Errors
+/// Returns [`KernelError::FirmwareCrcMismatch`] (critical) if the computed CRC does not match
+/// the expected value, or an error if writing to the terminal fails.
+pub fn selftest() -> KernelResult<()> {
+    // Compute CRC of the code region
+    let l_computed = unsafe {
+        let l_start = &raw const __stext;
+        let l_end = &raw const __etext;
+        Kernel::hal().crc32(core::slice::from_raw_parts(
+            l_start,
+            l_end as usize - l_start as usize,
+        ))
+    };
+
+    // Read the expected value from flash, the compiler must not fold the initial value
+    let l_expected = unsafe { core::ptr::read_volatile(&raw const G_FIRMWARE_CRC) };
+
+    if l_computed != l_expected {
+        return Err(KernelError::FirmwareCrcMismatch(l_expected, l_computed));
+    }
+
+    syscall_terminal(
+        ConsoleFormatting::StrNewLineBefore(
+            format!(50; "Firmware CRC OK ({:08X})", l_computed)
+                .unwrap()
+                .as_str(),
+        ),
+        G_SELFTEST_APP_ID.load(Ordering::Relaxed),
+    )?;
+
+    Ok(())
+}
+
+/// Initialize the selftest app by storing its scheduler id.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters (unused).
+pub fn selftest_init(
+    p_app_id: u32,
+    _p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_SELFTEST_APP_ID.store(p_app_id, Ordering::Relaxed);
+    Ok(())
+}
@@ -0,0 +1,51 @@
+//! Capacity report application.
+//!
+//! Prints the used and free slots of the scheduler and of the apps registry: `slots`.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec, format};
+
+use crate::data::Kernel;
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the slots app.
+static G_SLOTS_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+
+/// Kernel app entry point for the slots command.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails.
+pub fn slots() -> KernelResult<()> {
+    let l_id = G_SLOTS_ID_STORAGE.load(Ordering::Relaxed);
+
+    for (l_label, (l_used, l_max)) in [
+        ("Scheduler", Kernel::scheduler().slots()),
+        ("Apps", Kernel::apps().slots()),
+    ] {
+        syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore(
+                format!(60; "{}: {}/{} used, {} free", l_label, l_used, l_max, l_max - l_used)
+                    .unwrap()
+                    .as_str(),
+            ),
+            l_id,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Capture the app id for the slots command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters (unused).
+pub fn slots_init(
+    p_app_id: u32,
+    _p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_SLOTS_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    Ok(())
+}
@@ -0,0 +1,45 @@
+//! Stack usage application.
+//!
+//! Prints the maximum stack usage since startup: `stack`.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec, format};
+
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, stack_high_water,
+    stack_size, syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the stack app.
+static G_STACK_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+
+/// Kernel app entry point for the stack command.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails.
+pub fn stack() -> KernelResult<()> {
+    let l_used = stack_high_water();
+    let l_size = stack_size();
+
+    syscall_terminal(
+        ConsoleFormatting::StrNewLineBefore(
+            format!(60; "Stack: {}/{} bytes used ({}%)", l_used, l_size, l_used * 100 / l_size)
+                .unwrap()
+                .as_str(),
+        ),
+        G_STACK_ID_STORAGE.load(Ordering::Relaxed),
+    )
+}
+
+/// Capture the app id for the stack command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters (unused).
+pub fn stack_init(
+    p_app_id: u32,
+    _p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_STACK_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    Ok(())
+}
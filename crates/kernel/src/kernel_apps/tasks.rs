@@ -0,0 +1,57 @@
+//! Scheduler tasks listing application.
+//!
+//! Prints the tasks currently active in the scheduler with their app id and remaining
+//! lifetime, which helps understanding why a task did or did not run.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec, format};
+
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, K_MAX_TASKS, KernelResult,
+    SysCallSchedulerArgs, TaskInfo, syscall_scheduler, syscall_terminal, syscall_terminal_pager,
+};
+
+/// Last assigned scheduler ID for the tasks app.
+static G_TASKS_APP_ID: AtomicU32 = AtomicU32::new(0);
+
+/// Kernel app entry point for the tasks command.
+///
+/// # Errors
+/// Returns an error if the scheduler query or writing to the terminal fails.
+pub fn tasks() -> KernelResult<()> {
+    let l_id = G_TASKS_APP_ID.load(Ordering::Relaxed);
+    let mut l_tasks: Vec<TaskInfo, K_MAX_TASKS> = Vec::new();
+    syscall_scheduler(SysCallSchedulerArgs::ListTasks(&mut l_tasks))?;
+
+    syscall_terminal_pager(true, l_id)?;
+    syscall_terminal(
+        ConsoleFormatting::StrNewLineBefore("ID   TASK                 ENDS IN"),
+        l_id,
+    )?;
+    for (l_name, l_app_id, l_ends_in) in l_tasks {
+        let l_line = if l_ends_in == 0 {
+            format!(60; "{:<4} {:<20} -", l_app_id, l_name)
+        } else {
+            format!(60; "{:<4} {:<20} {} ms", l_app_id, l_name, l_ends_in)
+        };
+        syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore(l_line.unwrap().as_str()),
+            l_id,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Initialize the tasks app by storing its scheduler id.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters (unused).
+pub fn tasks_init(
+    p_app_id: u32,
+    _p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_TASKS_APP_ID.store(p_app_id, Ordering::Relaxed);
+    Ok(())
+}
@@ -0,0 +1,137 @@
+//! Display test pattern application.
+//!
+//! Draws full-height color bars (red, green, blue, white, black), a 1-pixel yellow
+//! border and an inverse-text label (black on white), then leaves the pattern on screen. Useful during panel bring-up: swapped red and
+//! blue channels show up in the bar order (and as a cyan border), stride errors as slanted
+//! bars, and an offset frame buffer as a missing border edge.
+//!
+//! `testpattern checker <cell>` fills the screen with a white and black checkerboard of
+//! `cell`-pixel squares instead, to look for dead pixels.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use display::{Colors, FontSize};
+use heapless::{String, Vec};
+use spin::Mutex;
+
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, SysCallDisplayArgs,
+    syscall_display, syscall_display_batch, syscall_terminal,
+};
+
+/// Color bars drawn from left to right.
+const K_TEST_PATTERN_BARS: [Colors; 5] = [
+    Colors::Red,
+    Colors::Green,
+    Colors::Blue,
+    Colors::White,
+    Colors::Black,
+];
+
+/// Color of the border drawn around the screen.
+const K_TEST_PATTERN_BORDER: Colors = Colors::Yellow;
+
+/// Label drawn in inverse colors in the top-left corner.
+const K_TEST_PATTERN_LABEL: &str = "INVERSE";
+
+/// Font used for the label, the system font set at boot.
+const K_TEST_PATTERN_FONT: FontSize = FontSize::Font24;
+
+/// Last assigned scheduler ID for the testpattern app.
+static G_TESTPATTERN_APP_ID: AtomicU32 = AtomicU32::new(0);
+/// Captured parameters for the testpattern app.
+static G_TESTPATTERN_PARAM_STORAGE: Mutex<Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>> =
+    Mutex::new(Vec::new());
+
+/// Kernel app entry point for the testpattern command.
+///
+/// # Errors
+/// Returns an error if the display is not available or a drawing operation fails.
+pub fn testpattern() -> KernelResult<()> {
+    let l_storage = G_TESTPATTERN_PARAM_STORAGE.lock();
+    let l_id = G_TESTPATTERN_APP_ID.load(Ordering::Relaxed);
+
+    match (
+        l_storage.len(),
+        l_storage.first().map(|l_p| l_p.as_str()),
+        l_storage.get(1).map(|l_p| l_p.parse::<u16>()),
+    ) {
+        (0, ..) => {}
+        (2, Some("checker"), Some(Ok(l_cell))) if l_cell > 0 => {
+            return syscall_display(
+                SysCallDisplayArgs::DrawCheckerboard(l_cell, Colors::White, Colors::Black),
+                l_id,
+            );
+        }
+        _ => {
+            return syscall_terminal(
+                ConsoleFormatting::StrNewLineBefore("Usage: testpattern [checker <cell>]"),
+                l_id,
+            );
+        }
+    }
+
+    let mut l_size = (0, 0);
+    syscall_display(SysCallDisplayArgs::GetSize(&mut l_size), l_id)?;
+    let (l_width, l_height) = l_size;
+
+    // Full-height bars, the last one takes the remaining columns
+    let l_bar_width = l_width / K_TEST_PATTERN_BARS.len() as u16;
+    for (l_index, l_color) in K_TEST_PATTERN_BARS.iter().enumerate() {
+        let l_x = l_index as u16 * l_bar_width;
+        let l_w = if l_index == K_TEST_PATTERN_BARS.len() - 1 {
+            l_width - l_x
+        } else {
+            l_bar_width
+        };
+        syscall_display(
+            SysCallDisplayArgs::DrawRect(l_x, 0, l_w, l_height, Some(*l_color)),
+            l_id,
+        )?;
+    }
+
+    // Inverse-text label, each glyph cell is fully painted with the background color
+    syscall_display(SysCallDisplayArgs::SetFont(K_TEST_PATTERN_FONT), l_id)?;
+    let l_char_width = K_TEST_PATTERN_FONT.get_char_size().0 as u16;
+    syscall_display_batch(
+        K_TEST_PATTERN_LABEL
+            .chars()
+            .enumerate()
+            .map(|(l_index, l_c)| {
+                SysCallDisplayArgs::WriteCharBg(
+                    l_c,
+                    8 + l_index as u16 * l_char_width,
+                    8,
+                    Colors::Black,
+                    Colors::White,
+                )
+            }),
+        l_id,
+    )
+    .map_err(|(_, l_err)| l_err)?;
+
+    // 1-pixel border
+    syscall_display_batch(
+        [
+            SysCallDisplayArgs::DrawRect(0, 0, l_width, 1, Some(K_TEST_PATTERN_BORDER)),
+            SysCallDisplayArgs::DrawRect(0, l_height - 1, l_width, 1, Some(K_TEST_PATTERN_BORDER)),
+            SysCallDisplayArgs::DrawRect(0, 0, 1, l_height, Some(K_TEST_PATTERN_BORDER)),
+            SysCallDisplayArgs::DrawRect(l_width - 1, 0, 1, l_height, Some(K_TEST_PATTERN_BORDER)),
+        ],
+        l_id,
+    )
+    .map_err(|(_, l_err)| l_err)
+}
+
+/// Capture parameters and app id for the testpattern command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters for the command.
+pub fn testpattern_init(
+    p_app_id: u32,
+    p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_TESTPATTERN_APP_ID.store(p_app_id, Ordering::Relaxed);
+    *G_TESTPATTERN_PARAM_STORAGE.lock() = p_param;
+    Ok(())
+}
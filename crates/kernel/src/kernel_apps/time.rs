@@ -0,0 +1,41 @@
+//! Uptime application.
+//!
+//! Prints the time elapsed since boot, in milliseconds: `time`.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec, format};
+
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, SysCallSchedulerArgs,
+    syscall_scheduler, syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the time app.
+static G_TIME_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+
+/// Kernel app entry point for the time command.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails.
+pub fn time() -> KernelResult<()> {
+    let mut l_now = 0;
+    syscall_scheduler(SysCallSchedulerArgs::Now(&mut l_now))?;
+
+    syscall_terminal(
+        ConsoleFormatting::StrNewLineBefore(format!(30; "Uptime: {} ms", l_now).unwrap().as_str()),
+        G_TIME_ID_STORAGE.load(Ordering::Relaxed),
+    )
+}
+
+/// Capture the app id for the time command.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters (unused).
+pub fn time_init(
+    p_app_id: u32,
+    _p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_TIME_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    Ok(())
+}
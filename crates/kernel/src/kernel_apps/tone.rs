@@ -0,0 +1,163 @@
+//! Tone generator application.
+//!
+//! Outputs a sine wave on a DAC channel: `tone <name>`. One sample of [`K_SINE_TABLE`] is
+//! written at each call of the app, so the output frequency is bound to the app period and
+//! the waveform is mostly useful to check the analog output with a scope or a multimeter.
+//! The tone plays until stopped with Ctrl-C at the prompt.
+
+use core::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use hal_interface::{
+    HalError, InterfaceWriteActions, K_DAC_MAX_VALUE, K_MAX_INTERFACES, interface_name,
+};
+use heapless::{String, Vec, format};
+use spin::Mutex;
+
+use crate::{
+    ConsoleFormatting, DeviceType, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelError,
+    KernelResult, SysCallDevicesArgs, SysCallHalActions, syscall_devices, syscall_hal,
+    syscall_terminal,
+};
+
+/// One period of a full scale sine wave, as 12-bit DAC values.
+const K_SINE_TABLE: [u16; 16] = [
+    2048, 2831, 3495, 3939, 4095, 3939, 3495, 2831, 2048, 1265, 601, 157, 1, 157, 601, 1265,
+];
+
+/// App/owner identifier used when locking and writing to the DAC interface.
+static G_TONE_ID_STORAGE: AtomicU32 = AtomicU32::new(0);
+
+/// Interface ID of the DAC, `None` when the app is not running.
+static G_TONE_DAC_ID: Mutex<Option<usize>> = Mutex::new(None);
+
+/// Current index in [`K_SINE_TABLE`].
+static G_TONE_STEP: AtomicUsize = AtomicUsize::new(0);
+
+/// Kernel app entry point for the tone command.
+///
+/// Writes the next sample of the sine table to the DAC.
+///
+/// # Errors
+/// Returns an error if the HAL write fails.
+pub fn tone() -> KernelResult<()> {
+    let Some(l_if_id) = *G_TONE_DAC_ID.lock() else {
+        return Ok(());
+    };
+
+    let l_step = G_TONE_STEP.load(Ordering::Relaxed);
+    G_TONE_STEP.store((l_step + 1) % K_SINE_TABLE.len(), Ordering::Relaxed);
+
+    syscall_hal(
+        l_if_id,
+        SysCallHalActions::Write(InterfaceWriteActions::DacWrite(K_SINE_TABLE[l_step])),
+        G_TONE_ID_STORAGE.load(Ordering::Relaxed),
+    )
+}
+
+/// Initialize the tone app by resolving the DAC interface and locking it.
+///
+/// The interface is set to mid-scale before being locked, which checks it is a DAC, so
+/// another kind of peripheral is never locked by the app.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters for the command, the DAC interface name.
+///
+/// # Errors
+/// - [`KernelError::AppParamMissing`] if the parameters are invalid.
+/// - [`KernelError::UnknownInterface`] if the interface does not exist.
+/// - [`KernelError::UnsupportedInterface`] if the interface is not a DAC output.
+/// - Any error from the HAL write or the device lock.
+///
+/// The reason is printed on the terminal before failing.
+pub fn tone_init(
+    p_app_id: u32,
+    p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_TONE_ID_STORAGE.store(p_app_id, Ordering::Relaxed);
+    G_TONE_STEP.store(0, Ordering::Relaxed);
+    *G_TONE_DAC_ID.lock() = None;
+
+    if p_param.len() != 1 {
+        syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore("Usage: tone <name>"),
+            p_app_id,
+        )?;
+        return Err(KernelError::AppParamMissing("tone"));
+    }
+
+    // Resolve the interface ID, IDs are contiguous
+    let l_name = p_param[0].as_str();
+    let Some(l_if_id) = (0..K_MAX_INTERFACES)
+        .map_while(|l_if_id| interface_name(l_if_id).ok().map(|l_n| (l_if_id, l_n)))
+        .find(|(_, l_n)| *l_n == l_name)
+        .map(|(l_if_id, _)| l_if_id)
+    else {
+        syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore(
+                format!(60; "Unknown interface {}", l_name)
+                    .unwrap()
+                    .as_str(),
+            ),
+            p_app_id,
+        )?;
+        return Err(KernelError::UnknownInterface("tone"));
+    };
+
+    // Only a DAC accepts the write, other interfaces are left untouched
+    match syscall_hal(
+        l_if_id,
+        SysCallHalActions::Write(InterfaceWriteActions::DacWrite(K_DAC_MAX_VALUE / 2 + 1)),
+        p_app_id,
+    ) {
+        Ok(()) => {}
+        Err(KernelError::HalError(
+            HalError::IncompatibleAction(..) | HalError::ReadOnlyInterface(_),
+        )) => {
+            syscall_terminal(
+                ConsoleFormatting::StrNewLineBefore(
+                    format!(60; "Interface {} is not a DAC", l_name)
+                        .unwrap()
+                        .as_str(),
+                ),
+                p_app_id,
+            )?;
+            return Err(KernelError::UnsupportedInterface(
+                "tone",
+                interface_name(l_if_id).unwrap_or("?"),
+            ));
+        }
+        Err(l_err) => return Err(l_err),
+    }
+
+    // Keep the DAC locked while the tone is playing
+    syscall_devices(
+        DeviceType::Peripheral(l_if_id),
+        SysCallDevicesArgs::Lock,
+        p_app_id,
+    )?;
+    *G_TONE_DAC_ID.lock() = Some(l_if_id);
+
+    Ok(())
+}
+
+/// Stop the tone by setting the DAC output to mid-scale and unlocking the peripheral.
+///
+/// # Errors
+/// Returns any error from the HAL write or the device unlock.
+pub fn tone_end() -> KernelResult<()> {
+    let Some(l_if_id) = G_TONE_DAC_ID.lock().take() else {
+        return Ok(());
+    };
+    let l_id = G_TONE_ID_STORAGE.load(Ordering::Relaxed);
+
+    syscall_hal(
+        l_if_id,
+        SysCallHalActions::Write(InterfaceWriteActions::DacWrite(K_DAC_MAX_VALUE / 2 + 1)),
+        l_id,
+    )?;
+    syscall_devices(
+        DeviceType::Peripheral(l_if_id),
+        SysCallDevicesArgs::Unlock,
+        l_id,
+    )
+}
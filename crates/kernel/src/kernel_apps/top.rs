@@ -0,0 +1,55 @@
+//! CPU usage monitoring application.
+//!
+//! Periodically prints the share of CPU used by each scheduled task since the previous
+//! report, then starts a new measurement window.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec, format};
+
+use crate::data::Kernel;
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the top app.
+static G_TOP_APP_ID: AtomicU32 = AtomicU32::new(0);
+
+/// Periodic top handler, prints the CPU usage table and resets the statistics.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails.
+pub fn top() -> KernelResult<()> {
+    let l_usage = Kernel::scheduler().cpu_usage();
+    Kernel::scheduler().reset_cpu_stats();
+
+    syscall_terminal(
+        ConsoleFormatting::StrNewLineBefore("TASK                 CPU"),
+        G_TOP_APP_ID.load(Ordering::Relaxed),
+    )?;
+    for (l_name, l_percent) in l_usage {
+        syscall_terminal(
+            ConsoleFormatting::StrNewLineBefore(
+                format!(50; "{:<20} {:>3}%", l_name, l_percent)
+                    .unwrap()
+                    .as_str(),
+            ),
+            G_TOP_APP_ID.load(Ordering::Relaxed),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Initialize the top app by storing its scheduler id and starting a new measurement window.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters (unused).
+pub fn top_init(
+    p_app_id: u32,
+    _p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_TOP_APP_ID.store(p_app_id, Ordering::Relaxed);
+    Kernel::scheduler().reset_cpu_stats();
+    Ok(())
+}
@@ -0,0 +1,54 @@
+//! Version application.
+//!
+//! Prints the firmware build information and the configured core frequency.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use heapless::{String, Vec, format};
+
+use crate::build_info::{K_BUILD_PROFILE, K_KERNEL_VERSION};
+use crate::data::Kernel;
+use crate::ident::K_KERNEL_NAME;
+use crate::{
+    ConsoleFormatting, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS, KernelResult, syscall_terminal,
+};
+
+/// Last assigned scheduler ID for the version app.
+static G_VERSION_APP_ID: AtomicU32 = AtomicU32::new(0);
+
+/// Kernel app entry point for the version command.
+///
+/// # Errors
+/// Returns an error if writing to the terminal fails.
+pub fn version() -> KernelResult<()> {
+    syscall_terminal(
+        ConsoleFormatting::StrNewLineBefore(
+            format!(50; "{} version {} ({})", K_KERNEL_NAME, K_KERNEL_VERSION, K_BUILD_PROFILE)
+                .unwrap()
+                .as_str(),
+        ),
+        G_VERSION_APP_ID.load(Ordering::Relaxed),
+    )?;
+    syscall_terminal(
+        ConsoleFormatting::StrNewLineBefore(
+            format!(50; "Core frequency is {} MHz", Kernel::time_data().core_frequency.to_u32() / 1_000_000)
+                .unwrap()
+                .as_str(),
+        ),
+        G_VERSION_APP_ID.load(Ordering::Relaxed),
+    )?;
+
+    Ok(())
+}
+
+/// Initialize the version app by storing its scheduler id.
+///
+/// # Parameters
+/// - `app_id`: Scheduler id assigned to this app.
+/// - `param`: Parsed parameters (unused).
+pub fn version_init(
+    p_app_id: u32,
+    _p_param: Vec<String<K_MAX_APP_PARAM_SIZE>, K_MAX_APP_PARAMS>,
+) -> KernelResult<()> {
+    G_VERSION_APP_ID.store(p_app_id, Ordering::Relaxed);
+    Ok(())
+}
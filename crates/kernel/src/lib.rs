@@ -1,13 +1,22 @@
 #![no_std]
 mod apps;
 mod boot;
+mod build_info;
+mod caret;
 mod console_output;
 mod data;
 mod devices;
 mod errors_mgt;
+mod events;
 mod ident;
 mod kernel_apps;
+mod logger;
+mod power;
+mod reset_reason;
 mod scheduler;
+mod screen_saver;
+mod stack;
+mod sync;
 mod syscall;
 mod systick;
 mod terminal;
@@ -17,13 +26,24 @@ use crate::apps::AppsManager;
 pub use crate::console_output::ConsoleOutput;
 use crate::data::Kernel;
 pub use crate::data::KernelTimeData;
-pub use apps::{AppConfig, AppStatus, CallPeriodicity, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS};
+pub use apps::{
+    AppConfig, AppStatus, CallMethod, CallPeriodicity, K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS,
+};
 pub use boot::{BootConfig, boot};
 pub use console_output::ConsoleFormatting;
-pub use data::cortex_init;
+pub use data::{K_MAX_DISPLAYS, K_PRIMARY_DISPLAY, cortex_init};
 pub use devices::{DeviceType, LockState};
+pub use errors_mgt::{assertion_failed, emergency_stop};
+pub use events::{EventHandler, K_MAX_SUBSCRIPTIONS, publish, subscribe, unsubscribe};
+pub use logger::{LogLevel, get_log_level, log, set_log_level};
+pub use power::idle;
+pub use reset_reason::{ResetReason, last_reset_reason};
+pub use scheduler::{K_MAX_TASKS, TaskInfo};
+pub use stack::{stack_high_water, stack_size};
+pub use sync::critical_section;
 pub use syscall::*;
-pub use systick::init_systick;
+pub use systick::{init_systick, max_isr_latency_cycles, now_ms};
+pub use terminal::{InputFilter, numeric_filter, printable_filter};
 pub use types::KernelResult;
 pub use types::Milliseconds;
 pub use types::*;
@@ -35,3 +55,11 @@ pub use types::*;
 pub fn apps() -> &'static mut AppsManager {
     Kernel::apps()
 }
+
+/// Returns `true` once the scheduler has been started at the end of the boot sequence.
+///
+/// Init code can use it to know whether it runs during boot or from a scheduled app.
+/// Returns `false` before [`boot`] has initialized the kernel data.
+pub fn scheduler_started() -> bool {
+    Kernel::try_scheduler().is_some_and(|l_scheduler| l_scheduler.started)
+}
@@ -0,0 +1,187 @@
+//! In-RAM kernel logging facility.
+//!
+//! Log entries are formatted with the current tick and level, stored in a fixed-size ring
+//! buffer and forwarded to the system terminal. When the buffer is full, the oldest entry is
+//! evicted. Entries below the runtime threshold (see [`set_log_level`]) are discarded.
+//!
+//! Keeping the entries in RAM decouples log production from the terminal device: recent logs
+//! are preserved even if the terminal write fails, and can be dumped later with the `log`
+//! kernel app.
+
+use core::sync::atomic::{AtomicU8, Ordering};
+
+use heapless::{Deque, String, format};
+use spin::Mutex;
+
+use crate::console_output::ConsoleFormatting;
+use crate::data::Kernel;
+use crate::systick::HAL_GetTick;
+
+/// Number of entries kept in the log ring buffer.
+pub const K_LOG_ENTRIES: usize = 16;
+/// Maximum length in bytes of a single log entry (prefix included).
+pub const K_LOG_ENTRY_SIZE: usize = 64;
+
+/// Type of a single stored log entry.
+pub type LogEntry = String<K_LOG_ENTRY_SIZE>;
+
+/// Severity of a log entry.
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq)]
+#[repr(u8)]
+pub enum LogLevel {
+    /// Detailed information for debugging.
+    Debug = 0,
+    /// General information about system operation.
+    Info = 1,
+    /// Unexpected situation that does not prevent operation.
+    Warning = 2,
+    /// Failure of an operation.
+    Error = 3,
+    /// Threshold only: discards all entries when set with [`set_log_level`].
+    Off = 4,
+}
+
+impl LogLevel {
+    /// Returns a short string representation of the log level.
+    ///
+    /// # Returns
+    /// A static string slice used as entry prefix.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Debug => "DBG",
+            LogLevel::Info => "INF",
+            LogLevel::Warning => "WRN",
+            LogLevel::Error => "ERR",
+            LogLevel::Off => "OFF",
+        }
+    }
+
+    /// Converts a raw value into a [`LogLevel`].
+    ///
+    /// # Parameters
+    /// - `value`: Raw level value. Values above [`LogLevel::Off`] are saturated.
+    ///
+    /// # Returns
+    /// The corresponding [`LogLevel`].
+    fn from_u8(p_value: u8) -> LogLevel {
+        match p_value {
+            0 => LogLevel::Debug,
+            1 => LogLevel::Info,
+            2 => LogLevel::Warning,
+            3 => LogLevel::Error,
+            _ => LogLevel::Off,
+        }
+    }
+}
+
+/// Ring buffer holding the most recent log entries.
+static G_LOG_BUFFER: Mutex<Deque<LogEntry, K_LOG_ENTRIES>> = Mutex::new(Deque::new());
+
+/// Minimum level of the entries accepted by [`log`].
+static G_LOG_THRESHOLD: AtomicU8 = AtomicU8::new(LogLevel::Info as u8);
+
+/// Records a log entry and forwards it to the system terminal.
+///
+/// The entry is formatted as `[<tick>][<level>] <message>` and truncated to
+/// [`K_LOG_ENTRY_SIZE`] bytes. If the ring buffer is full, the oldest entry is evicted.
+///
+/// # Parameters
+/// - `level`: Severity of the entry. Entries below the current threshold are discarded
+///   before formatting, as well as entries logged with [`LogLevel::Off`].
+/// - `msg`: Message to log (ASCII expected).
+///
+/// # Errors
+/// This function does not return errors. Terminal forwarding is best-effort: a failing
+/// terminal write does not prevent the entry from being stored.
+pub fn log(p_level: LogLevel, p_msg: &str) {
+    if !is_logged(p_level) {
+        return;
+    }
+
+    // Format entry, truncating the message if needed
+    let mut l_entry: LogEntry =
+        format!(K_LOG_ENTRY_SIZE; "[{}][{}] ", HAL_GetTick(), p_level.as_str()).unwrap_or_default();
+    for l_c in p_msg.chars() {
+        if l_entry.push(l_c).is_err() {
+            break;
+        }
+    }
+
+    // Store entry, evicting the oldest one if the buffer is full
+    {
+        let mut l_buffer = G_LOG_BUFFER.lock();
+        if l_buffer.is_full() {
+            l_buffer.pop_front();
+        }
+        l_buffer.push_back(l_entry.clone()).unwrap_or(());
+    }
+
+    // Forward to the terminal
+    Kernel::terminal()
+        .write(&ConsoleFormatting::StrNewLineBefore(l_entry.as_str()))
+        .unwrap_or(());
+}
+
+/// Tells whether entries of a level are recorded by [`log`] with the current threshold.
+///
+/// # Parameters
+/// - `level`: Severity of the entry.
+///
+/// # Returns
+/// `false` if the level is below the threshold or is [`LogLevel::Off`].
+fn is_logged(p_level: LogLevel) -> bool {
+    p_level >= get_log_level() && p_level != LogLevel::Off
+}
+
+/// Sets the minimum level of the entries recorded by [`log`].
+///
+/// # Parameters
+/// - `level`: New threshold.
+pub fn set_log_level(p_level: LogLevel) {
+    G_LOG_THRESHOLD.store(p_level as u8, Ordering::Relaxed);
+}
+
+/// Returns the minimum level of the entries recorded by [`log`].
+///
+/// # Returns
+/// The current threshold.
+pub fn get_log_level() -> LogLevel {
+    LogLevel::from_u8(G_LOG_THRESHOLD.load(Ordering::Relaxed))
+}
+
+/// Returns a copy of the stored log entries, oldest first.
+///
+/// # Returns
+/// A ring buffer copy containing up to [`K_LOG_ENTRIES`] entries.
+pub(crate) fn log_entries() -> Deque<LogEntry, K_LOG_ENTRIES> {
+    G_LOG_BUFFER.lock().clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_below_the_threshold_are_discarded() {
+        // Only test changing the threshold, the levels are checked in sequence
+        set_log_level(LogLevel::Info);
+        assert!(!is_logged(LogLevel::Debug));
+        assert!(is_logged(LogLevel::Info));
+        assert!(is_logged(LogLevel::Error));
+        assert!(!is_logged(LogLevel::Off));
+
+        set_log_level(LogLevel::Debug);
+        assert!(is_logged(LogLevel::Debug));
+
+        set_log_level(LogLevel::Off);
+        assert!(!is_logged(LogLevel::Error));
+
+        set_log_level(LogLevel::Info);
+    }
+
+    #[test]
+    fn raw_levels_above_off_saturate() {
+        assert_eq!(LogLevel::from_u8(LogLevel::Debug as u8), LogLevel::Debug);
+        assert_eq!(LogLevel::from_u8(200), LogLevel::Off);
+    }
+}
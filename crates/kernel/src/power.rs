@@ -0,0 +1,33 @@
+//! Power management helpers.
+//!
+//! The kernel does all its work from interrupts (SysTick, PendSV for the scheduler and
+//! peripheral callbacks), so the main loop has nothing to do but wait. When low-power idle
+//! is enabled, [`idle`] puts the core to sleep until the next interrupt instead of spinning.
+//!
+//! While the core sleeps, only interrupt handlers run: any app or driver that must react
+//! during sleep has to be interrupt-driven. Low-power idle is disabled by default because
+//! some debug probes lose the connection to a sleeping core.
+
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether [`idle`] puts the core to sleep.
+static G_LOW_POWER_IDLE: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable low-power idle.
+///
+/// # Parameters
+/// - `enable`: `true` to sleep in [`idle`], `false` to return immediately.
+pub(crate) fn set_low_power_idle(p_enable: bool) {
+    G_LOW_POWER_IDLE.store(p_enable, Ordering::Relaxed);
+}
+
+/// Idle hook, to be called repeatedly from the main loop.
+///
+/// If low-power idle is enabled (see [`crate::BootConfig::low_power_idle`]), the core
+/// executes `WFI` and sleeps until the next interrupt. SysTick and PendSV are interrupts,
+/// so the scheduler keeps running normally. Otherwise, this function returns immediately.
+pub fn idle() {
+    if G_LOW_POWER_IDLE.load(Ordering::Relaxed) {
+        cortex_m::asm::wfi();
+    }
+}
@@ -0,0 +1,115 @@
+//! Reset reason tracking.
+//!
+//! The cause of a kernel-initiated reset (panic, software watchdog, reboot command) is written
+//! to a record in the `.uninit` RAM section, which the startup code does not zero. At boot,
+//! [`capture_reset_reason`] reads the record, keeps the reason for [`last_reset_reason`] and
+//! clears the record so a stale reason is never reported twice.
+//!
+//! The record is guarded by a magic word: random RAM content after a power-on does not match
+//! it. The data cache is not enabled, so the record reaches RAM before the reset.
+
+use core::ptr::{addr_of, addr_of_mut};
+use spin::Mutex;
+
+/// Magic word marking a valid record ("RESR").
+const K_RESET_RECORD_MAGIC: u32 = 0x5245_5352;
+/// Reason code of a valid record without recorded cause.
+const K_NO_REASON: u32 = 0;
+
+/// Reset record as `[magic, reason code]`, kept across resets.
+#[unsafe(link_section = ".uninit.RESET_REASON")]
+static mut G_RESET_RECORD: [u32; 2] = [0; 2];
+
+/// Reason captured at boot.
+static G_LAST_RESET_REASON: Mutex<ResetReason> = Mutex::new(ResetReason::PowerOn);
+
+/// Cause of the last reset.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResetReason {
+    /// No record found in RAM: power-on or loss of the RAM content.
+    PowerOn,
+    /// Reset not initiated by the kernel (reset button, debugger, hardware fault).
+    External,
+    /// Reset by the panic handler.
+    Panic,
+    /// Reset after the software watchdog detected a stalled scheduler.
+    Watchdog,
+    /// Reset requested with the `reboot` command.
+    Reboot,
+}
+
+impl ResetReason {
+    /// Returns the human-readable name of the reason.
+    ///
+    /// # Returns
+    /// A static string slice naming the reason.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ResetReason::PowerOn => "power-on",
+            ResetReason::External => "external",
+            ResetReason::Panic => "panic",
+            ResetReason::Watchdog => "watchdog",
+            ResetReason::Reboot => "reboot",
+        }
+    }
+
+    /// Returns the code stored in the reset record.
+    fn code(&self) -> u32 {
+        match self {
+            ResetReason::PowerOn | ResetReason::External => K_NO_REASON,
+            ResetReason::Panic => 1,
+            ResetReason::Watchdog => 2,
+            ResetReason::Reboot => 3,
+        }
+    }
+
+    /// Builds a reason from a stored code, unknown codes are reported as external resets.
+    fn from_code(p_code: u32) -> ResetReason {
+        match p_code {
+            1 => ResetReason::Panic,
+            2 => ResetReason::Watchdog,
+            3 => ResetReason::Reboot,
+            _ => ResetReason::External,
+        }
+    }
+}
+
+/// Reads the reset record left by the previous run, then clears it.
+///
+/// Must be called once, early at boot, before anything can record a new reason.
+pub(crate) fn capture_reset_reason() {
+    // The record lives in `.uninit` and is only accessed through raw pointers
+    let l_record = unsafe { addr_of!(G_RESET_RECORD).read_volatile() };
+    *G_LAST_RESET_REASON.lock() = if l_record[0] == K_RESET_RECORD_MAGIC {
+        ResetReason::from_code(l_record[1])
+    } else {
+        ResetReason::PowerOn
+    };
+
+    unsafe {
+        addr_of_mut!(G_RESET_RECORD).write_volatile([K_RESET_RECORD_MAGIC, K_NO_REASON]);
+    }
+}
+
+/// Records the cause of an upcoming reset.
+///
+/// The first recorded reason wins, so the watchdog reason is kept when the watchdog then
+/// triggers the panic handler.
+///
+/// # Parameters
+/// - `reason`: Cause of the reset about to be performed.
+pub(crate) fn record_reset_reason(p_reason: ResetReason) {
+    let l_record = unsafe { addr_of!(G_RESET_RECORD).read_volatile() };
+    if l_record[0] == K_RESET_RECORD_MAGIC && l_record[1] != K_NO_REASON {
+        return;
+    }
+
+    unsafe {
+        addr_of_mut!(G_RESET_RECORD).write_volatile([K_RESET_RECORD_MAGIC, p_reason.code()]);
+    }
+}
+
+/// Returns the cause of the last reset, as captured at boot.
+pub fn last_reset_reason() -> ResetReason {
+    *G_LAST_RESET_REASON.lock()
+}
@@ -1,12 +1,48 @@
 use crate::KernelError::CannotAddNewPeriodicApp;
 use crate::console_output::ConsoleFormatting;
 use crate::data::Kernel;
-use crate::systick::set_ticks_target;
-use crate::{KernelError, KernelResult, Milliseconds};
-use cortex_m::peripheral::SCB;
+use crate::systick::{HAL_GetTick, set_ticks_target, watchdog_feed};
+use crate::{KernelError, KernelErrorLevel, KernelResult, Milliseconds, kernel_assert};
 use cortex_m::peripheral::scb::{Exception, SystemHandler, VectActive};
+use cortex_m::peripheral::{DWT, SCB};
 use heapless::Vec;
 
+/// Maximum number of tasks managed by the scheduler.
+///
+/// Defaults to 32 and can be changed at build time with one of the `max-tasks-8`,
+/// `max-tasks-16`, `max-tasks-64` or `max-tasks-128` features of the kernel crate. Cargo
+/// features are additive, so when several of them are enabled the largest size is used. Each
+/// slot costs one `AppWrapper` in RAM; when all slots are used, adding a task fails with
+/// [`KernelError::SchedulerFull`].
+#[cfg(feature = "max-tasks-128")]
+pub const K_MAX_TASKS: usize = 128;
+#[cfg(all(feature = "max-tasks-64", not(feature = "max-tasks-128")))]
+pub const K_MAX_TASKS: usize = 64;
+#[cfg(all(
+    not(any(feature = "max-tasks-64", feature = "max-tasks-128")),
+    not(any(feature = "max-tasks-8", feature = "max-tasks-16"))
+))]
+pub const K_MAX_TASKS: usize = 32;
+#[cfg(all(
+    feature = "max-tasks-16",
+    not(any(feature = "max-tasks-64", feature = "max-tasks-128"))
+))]
+pub const K_MAX_TASKS: usize = 16;
+#[cfg(all(
+    feature = "max-tasks-8",
+    not(any(
+        feature = "max-tasks-16",
+        feature = "max-tasks-64",
+        feature = "max-tasks-128"
+    ))
+))]
+pub const K_MAX_TASKS: usize = 8;
+
+/// Scheduler view of a task, as `(name, app_id, remaining lifetime in ms)`.
+///
+/// The remaining lifetime is `0` for tasks running indefinitely.
+pub type TaskInfo = (&'static str, u32, u32);
+
 /// Type alias `App` represents a function pointer type that returns a `KernelResult<()>`.
 ///
 /// This type alias is used as a shorthand for functions that are intended to serve
@@ -26,6 +62,34 @@ use heapless::Vec;
 ///
 pub type App = fn() -> KernelResult<()>;
 
+/// Type alias `AppParam` represents an application entry point taking a `u32` parameter.
+///
+/// It allows the same function to be scheduled several times against different resources
+/// (e.g. one blink task per LED), the parameter being stored with the task.
+pub type AppParam = fn(u32) -> KernelResult<()>;
+
+/// Describes how the scheduler invokes a task.
+#[derive(Copy, Clone)]
+pub enum AppCall {
+    /// The task is called without parameter.
+    App(App),
+    /// The task is called with the stored `u32` parameter.
+    AppParam(AppParam, u32),
+}
+
+impl AppCall {
+    /// Invokes the task function, passing the stored parameter if any.
+    ///
+    /// # Returns
+    /// The result of the task function.
+    fn call(&self) -> KernelResult<()> {
+        match self {
+            AppCall::App(l_app) => l_app(),
+            AppCall::AppParam(l_app, l_param) => l_app(*l_param),
+        }
+    }
+}
+
 /// `AppWrapper` is a structure that encapsulates metadata and state for an application
 /// or service within a system. It provides details such as the application name,
 /// its initialization state, runtime period, lifecycle, and active status.
@@ -36,9 +100,10 @@ pub type App = fn() -> KernelResult<()>;
 ///   The static name identifier for the application. This name remains constant
 ///   throughout the lifecycle of the application.
 ///
-/// * `app` (`App`) -
-///   Represents the core application logic or callable function associated with the application.
-///   This is the primary entry point for executing application-specific logic.
+/// * `app` (`AppCall`) -
+///   Represents the core application logic or callable function associated with the application,
+///   along with its parameter if any. This is the primary entry point for executing
+///   application-specific logic.
 ///
 /// * `app_closure` (`Option<App>`) -
 ///   Optional cleanup function called when the application's lifetime expires.
@@ -66,6 +131,9 @@ pub type App = fn() -> KernelResult<()>;
 ///   A flag indicating whether the application is managed by the `AppsManager`.
 ///   If true, cleanup is handled by the `AppsManager`; otherwise, it's handled internally.
 ///
+/// * `busy_cycles` (`u64`) -
+///   Core clock cycles spent executing the application since the last CPU statistics reset.
+///
 /// # Usage
 ///
 /// The `AppWrapper` structure is used to manage the state and metadata of applications
@@ -74,13 +142,14 @@ pub type App = fn() -> KernelResult<()>;
 ///
 struct AppWrapper {
     name: &'static str,
-    app: App,
+    app: AppCall,
     app_closure: Option<App>,
     app_period: u32,
     ends_in: Option<u32>,
     active: bool,
     app_id: u32,
     managed_by_apps: bool,
+    busy_cycles: u64,
 }
 /// Struct representing a Scheduler, which manages tasks and their execution
 /// in a cyclic time period.
@@ -91,7 +160,7 @@ struct AppWrapper {
 ///
 /// # Fields
 /// * `tasks` - A fixed-size vector containing the scheduled tasks (`AppWrapper`) managed by the scheduler.
-///   Limited to a size of 32.
+///   Limited to [`K_MAX_TASKS`] entries.
 /// * `cycle_counter` - A counter representing the number of completed execution cycles.
 /// * `sched_period` - The scheduling period, represented in milliseconds, specifying the frequency
 ///   at which the scheduler cycles through tasks.
@@ -100,15 +169,22 @@ struct AppWrapper {
 ///   If no task is currently active, it is `None`.
 /// * `current_task_has_error` - A boolean flag indicating whether the currently executing task has encountered an error.
 /// * `next_id` - A unique identifier (`u32`) for assigning to newly added tasks within the scheduler.
+/// * `cpu_window_start` - System tick at which the current CPU usage measurement window started.
+/// * `paused` - Master flag set by [`Scheduler::pause_all`]; while set, no task body is executed.
+/// * `cycle_reset_pending` - Set by [`Scheduler::reset_cycle_count`]; the cycle counter restarts
+///   from zero at the end of the current cycle.
 ///
 pub struct Scheduler {
-    tasks: Vec<AppWrapper, 32>,
+    tasks: Vec<AppWrapper, K_MAX_TASKS>,
     cycle_counter: u32,
     sched_period: Milliseconds,
     pub started: bool,
     current_task_id: Option<usize>,
     current_task_has_error: bool,
     next_id: u32,
+    cpu_window_start: u32,
+    paused: bool,
+    cycle_reset_pending: bool,
 }
 
 impl Scheduler {
@@ -137,6 +213,9 @@ impl Scheduler {
             current_task_id: None,
             current_task_has_error: false,
             next_id: 0,
+            cpu_window_start: 0,
+            paused: false,
+            cycle_reset_pending: false,
         }
     }
 
@@ -154,6 +233,10 @@ impl Scheduler {
     /// # Returns
     /// - `KernelResult<()>`: Returns an empty result on success, or an error if the operation fails.
     ///
+    /// # Errors
+    /// Returns [`KernelError::InvalidTimingConfig`] if the SysTick period is zero or if the
+    /// scheduler period is not a non-zero multiple of the SysTick period.
+    ///
     /// # Panics
     /// This method may panic if there is an issue interacting with the underlying terminal logging system.
     ///
@@ -163,6 +246,19 @@ impl Scheduler {
     /// The unsafe block must ensure safe interaction with shared hardware resources to avoid undefined behavior.
     ///
     pub fn start(&mut self, p_systick_period: Milliseconds) -> KernelResult<()> {
+        // Check the scheduler period is a whole number of SysTick periods
+        if p_systick_period.to_u32() == 0
+            || self.sched_period.to_u32() == 0
+            || !self
+                .sched_period
+                .to_u32()
+                .is_multiple_of(p_systick_period.to_u32())
+        {
+            return Err(KernelError::InvalidTimingConfig(
+                "scheduler period must be a non-zero multiple of the SysTick period",
+            ));
+        }
+
         let l_cortex_p = Kernel::cortex_peripherals();
 
         // Initialize scheduler periodic IT
@@ -171,6 +267,12 @@ impl Scheduler {
             set_ticks_target(self.sched_period.to_u32() / p_systick_period.to_u32())
         }
 
+        // Enable cycle counter used for CPU usage measurement
+        l_cortex_p.DCB.enable_trace();
+        DWT::unlock();
+        l_cortex_p.DWT.enable_cycle_counter();
+        self.cpu_window_start = HAL_GetTick();
+
         self.started = true;
         Kernel::terminal().write(&ConsoleFormatting::StrNewLineBoth("Scheduler started !"))
     }
@@ -186,7 +288,7 @@ impl Scheduler {
     /// * `name` - A static string identifier for the application. Must be unique within
     ///   the scheduler.
     ///
-    /// * `app` - The application entry point.
+    /// * `app` - The application entry point, see [`AppCall`].
     ///
     /// * `app_closure` - Optional cleanup function called when the application's lifetime
     ///   expires (i.e., when `ends_in` reaches zero). Useful for releasing resources.
@@ -206,12 +308,16 @@ impl Scheduler {
     /// * `Err(KernelError::AppAlreadyScheduled)` - If an application with the same name
     ///   is already registered.
     ///
-    /// * `Err(KernelError::CannotAddNewPeriodicApp)` - If the task list is full and
-    ///   cannot accommodate additional applications.
+    /// * `Err(KernelError::SchedulerFull)` - If the task list is full and cannot
+    ///   accommodate additional applications. The error carries the used and total slots.
+    ///
+    /// * `Err(KernelError::InvalidDuration)` - If `period` is shorter than the scheduler
+    ///   period, or if the lifetime cannot be converted back to milliseconds without
+    ///   overflowing.
     pub fn add_periodic_app(
         &mut self,
         p_name: &'static str,
-        p_app: App,
+        p_app: AppCall,
         p_app_closure: Option<App>,
         p_period: Milliseconds,
         p_ends_in: Option<Milliseconds>,
@@ -222,6 +328,30 @@ impl Scheduler {
             return Err(KernelError::AppAlreadyScheduled(p_name));
         }
 
+        // Check a slot is free before doing anything else
+        if self.tasks.is_full() {
+            return Err(KernelError::SchedulerFull(
+                p_name,
+                self.tasks.len(),
+                K_MAX_TASKS,
+            ));
+        }
+
+        // Check the period and lifetime can be expressed in scheduler cycles
+        let l_app_period = p_period.to_u32() / self.sched_period.to_u32();
+        if l_app_period == 0 {
+            return Err(KernelError::InvalidDuration(p_name));
+        }
+        let l_ends_in = p_ends_in.map(|l_e| l_e.to_u32() / p_period.to_u32());
+        if let Some(l_e) = l_ends_in
+            && Milliseconds(l_e)
+                .checked_mul(l_app_period)
+                .and_then(|l_ms| l_ms.checked_mul(self.sched_period.to_u32()))
+                .is_none()
+        {
+            return Err(KernelError::InvalidDuration(p_name));
+        }
+
         // Increment app ID
         self.next_id += 1;
 
@@ -231,11 +361,12 @@ impl Scheduler {
                 name: p_name,
                 app: p_app,
                 app_closure: p_app_closure,
-                app_period: p_period.to_u32() / self.sched_period.to_u32(),
+                app_period: l_app_period,
                 active: true,
-                ends_in: p_ends_in.map(|l_e| l_e.to_u32() / p_period.to_u32()),
+                ends_in: l_ends_in,
                 app_id: self.next_id,
                 managed_by_apps: p_managed_by_apps,
+                busy_cycles: 0,
             })
             .map_err(|_| CannotAddNewPeriodicApp(p_name))?;
 
@@ -316,9 +447,14 @@ impl Scheduler {
     ///    - The `app_closure` callback is invoked (if configured) for cleanup.
     ///    - The task is marked for removal.
     ///
-    /// 4. **Cleanup**: All tasks marked for removal are unregistered from the scheduler.
+    /// 4. **Cleanup**: All tasks marked for removal are unregistered from the scheduler. For
+    ///    managed apps, the successor app is started if one is set.
     ///
-    /// 5. **Cycle increment**: The global cycle counter is incremented.
+    /// 5. **Cycle increment**: The global cycle counter is incremented, or restarted from zero
+    ///    if a reset was requested (see [`Scheduler::reset_cycle_count`]).
+    ///
+    /// While the scheduler is paused (see [`Scheduler::pause_all`]), steps 1 to 4 are skipped
+    /// and only the cycle counter is incremented.
     ///
     /// # Error handling
     ///
@@ -326,21 +462,28 @@ impl Scheduler {
     /// The `current_task_has_error` flag prevents duplicate error handling if the error
     /// handler itself triggers additional errors for the same task.
     ///
-    /// # Panics
-    ///
-    /// May panic if the internal `tasks_to_remove` buffer overflows (more than 8 tasks
-    /// ending in a single cycle) or if `Kernel::apps().stop_app` fails unexpectedly.
+    /// More than 8 tasks ending in a single cycle is reported as an error, the extra tasks
+    /// are removed on the next cycle. An ended internal task missing from the scheduler is
+    /// reported as a critical error.
     pub fn periodic_task(&mut self) {
+        // Keep counting cycles while paused so task phases are preserved on resume
+        if self.paused {
+            self.next_cycle();
+            return;
+        }
+
         let mut l_tasks_to_remove: Vec<u32, 8> = Vec::new();
 
         // Run all tasks
-        for (l_id, l_task) in self.tasks.iter_mut().enumerate() {
-            if self.cycle_counter.is_multiple_of(l_task.app_period) && l_task.active {
+        for l_id in 0..self.tasks.len() {
+            if self.is_due(l_id) {
+                let l_task = &mut self.tasks[l_id];
                 self.current_task_id = Some(l_id);
                 self.current_task_has_error = false;
 
                 // Execute the task
-                match (l_task.app)() {
+                let l_start_cycles = DWT::cycle_count();
+                match l_task.app.call() {
                     Ok(..) => {}
                     Err(l_e) => {
                         if !self.current_task_has_error {
@@ -348,6 +491,7 @@ impl Scheduler {
                         }
                     }
                 }
+                l_task.busy_cycles += DWT::cycle_count().wrapping_sub(l_start_cycles) as u64;
                 self.current_task_has_error = false;
                 self.current_task_id = None;
 
@@ -355,7 +499,15 @@ impl Scheduler {
                 if l_task.ends_in.is_some() {
                     l_task.ends_in = l_task.ends_in.map(|l_e| l_e - 1);
                     if l_task.ends_in.unwrap() == 0 {
-                        l_tasks_to_remove.push(l_task.app_id).unwrap();
+                        // Retry on next cycle if the removal list is full
+                        if !kernel_assert!(
+                            l_tasks_to_remove.push(l_task.app_id).is_ok(),
+                            KernelErrorLevel::Error,
+                            "Too many tasks ending in one cycle"
+                        ) {
+                            l_task.ends_in = Some(1);
+                            continue;
+                        }
 
                         // Apply closure only for internal tasks
                         // (managed apps handle it in their stop() logic)
@@ -378,11 +530,15 @@ impl Scheduler {
 
         // Remove tasks that have ended
         for l_task_id in l_tasks_to_remove {
-            match Kernel::apps().stop_app(l_task_id) {
+            match Kernel::apps().end_app(l_task_id) {
                 Ok(()) => {}
                 Err(KernelError::AppNotFound) => {
                     // Internal task, remove it directly from scheduler
-                    self.remove_periodic_app_by_id(l_task_id).unwrap();
+                    kernel_assert!(
+                        self.remove_periodic_app_by_id(l_task_id).is_ok(),
+                        KernelErrorLevel::Critical,
+                        "Ended task missing from the scheduler"
+                    );
                 }
                 Err(l_e) => {
                     if !self.current_task_has_error {
@@ -392,8 +548,51 @@ impl Scheduler {
             }
         }
 
-        // Increment cycle counter
-        self.cycle_counter += 1;
+        self.next_cycle();
+    }
+
+    /// Tells whether a task is executed in the current cycle.
+    ///
+    /// # Parameters
+    /// - `index`: Index of the task in the task list.
+    ///
+    /// # Returns
+    /// `true` if the task is active and its period has elapsed, `false` while the scheduler
+    /// is paused.
+    fn is_due(&self, p_index: usize) -> bool {
+        let l_task = &self.tasks[p_index];
+        !self.paused && l_task.active && self.cycle_counter.is_multiple_of(l_task.app_period)
+    }
+
+    /// Advances the cycle counter to the next cycle, applying a pending reset, and feeds the
+    /// software watchdog.
+    fn next_cycle(&mut self) {
+        watchdog_feed();
+        if self.cycle_reset_pending {
+            self.cycle_reset_pending = false;
+            self.cycle_counter = 0;
+        } else {
+            self.cycle_counter += 1;
+        }
+    }
+
+    /// Suspends the execution of all scheduled tasks.
+    ///
+    /// The individual `active` flags of the tasks are left untouched, so [`Scheduler::resume_all`]
+    /// restores exactly the previous state. Tasks can still be added or removed while paused.
+    ///
+    /// The SysTick and the scheduler cycle counter keep advancing while paused, so periodic tasks
+    /// stay aligned with their original phase after resume. Finite lifetimes are not consumed
+    /// while paused: a task ending in N cycles still runs N more times after resume.
+    pub fn pause_all(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resumes the execution of the scheduled tasks after a call to [`Scheduler::pause_all`].
+    ///
+    /// Has no effect if the scheduler is not paused.
+    pub fn resume_all(&mut self) {
+        self.paused = false;
     }
 
     /// Aborts the current task when an error occurs during the PendSV exception.
@@ -492,6 +691,39 @@ impl Scheduler {
         }
     }
 
+    /// Returns the remaining lifetime of a task with a finite duration.
+    ///
+    /// The remaining lifetime is the number of remaining executions converted back to
+    /// milliseconds, as the exact inverse of the computation done when the task was added.
+    /// When called from the task itself, the current execution is still counted.
+    ///
+    /// # Parameters
+    /// - `name`: Name of the task to query.
+    ///
+    /// # Returns
+    /// - `Ok(Some(time))` with the remaining lifetime of a finite task.
+    /// - `Ok(None)` if the task runs indefinitely.
+    /// - `Err(KernelError::AppNotScheduled)` if no task matching `name` is scheduled.
+    pub fn get_remaining(&self, p_name: &'static str) -> KernelResult<Option<Milliseconds>> {
+        let l_index = self
+            .app_exists(p_name)
+            .ok_or(KernelError::AppNotScheduled(p_name))?;
+        Ok(self.remaining_ms(&self.tasks[l_index]).map(Milliseconds))
+    }
+
+    /// Converts the remaining executions of a task into milliseconds.
+    ///
+    /// # Parameters
+    /// - `task`: Task to query.
+    ///
+    /// # Returns
+    /// The remaining lifetime in milliseconds, or `None` for tasks running indefinitely.
+    fn remaining_ms(&self, p_task: &AppWrapper) -> Option<u32> {
+        p_task
+            .ends_in
+            .map(|l_e| l_e * p_task.app_period * self.sched_period.to_u32())
+    }
+
     /// Returns the scheduling period of the current object.
     ///
     /// This method retrieves the value of `sched_period`, which represents
@@ -505,4 +737,201 @@ impl Scheduler {
     pub fn get_period(&self) -> Milliseconds {
         self.sched_period
     }
+
+    /// Returns the number of scheduler cycles elapsed since start or since the last reset.
+    ///
+    /// When called from a task, this is the cycle the task is executed in.
+    pub fn get_cycle_count(&self) -> u32 {
+        self.cycle_counter
+    }
+
+    /// Restarts the cycle counter from zero.
+    ///
+    /// The reset takes effect at the end of the current cycle, so the next cycle is cycle `0`.
+    /// Tasks are due when the cycle counter is a multiple of their period, hence every periodic
+    /// task runs in cycle `0` and then every `period` cycles: all tasks restart their phase,
+    /// whatever their previous alignment. Remaining lifetimes are not affected.
+    pub fn reset_cycle_count(&mut self) {
+        self.cycle_reset_pending = true;
+    }
+
+    /// Returns the occupancy of the task list.
+    ///
+    /// # Returns
+    /// A `(used, total)` tuple of scheduler slots.
+    pub fn slots(&self) -> (usize, usize) {
+        (self.tasks.len(), K_MAX_TASKS)
+    }
+
+    /// Returns the tasks currently active in the scheduler.
+    ///
+    /// Unlike the apps registry, this reflects the actual scheduler state, including the
+    /// lifetime countdown of tasks with a finite duration.
+    ///
+    /// # Returns
+    /// A vector of [`TaskInfo`] tuples, in scheduler order.
+    pub fn list_tasks(&self) -> Vec<TaskInfo, K_MAX_TASKS> {
+        self.tasks
+            .iter()
+            .filter(|l_task| l_task.active)
+            .map(|l_task| {
+                (
+                    l_task.name,
+                    l_task.app_id,
+                    self.remaining_ms(l_task).unwrap_or(0),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the CPU usage of each task since the last statistics reset.
+    ///
+    /// The usage of a task is the share of core clock cycles spent executing it over the
+    /// wall-clock time elapsed since [`Scheduler::reset_cpu_stats`] (or scheduler start),
+    /// idle time included.
+    ///
+    /// # Returns
+    /// A vector of `(task name, usage in percent)` tuples, in scheduler order.
+    pub fn cpu_usage(&self) -> Vec<(&'static str, u8), K_MAX_TASKS> {
+        let l_elapsed_cycles = HAL_GetTick().wrapping_sub(self.cpu_window_start) as u64
+            * Kernel::time_data().core_frequency.to_u32() as u64
+            / 1000;
+
+        self.tasks
+            .iter()
+            .map(|l_task| {
+                let l_percent = (l_task.busy_cycles * 100)
+                    .checked_div(l_elapsed_cycles)
+                    .unwrap_or(0)
+                    .min(100) as u8;
+                (l_task.name, l_percent)
+            })
+            .collect()
+    }
+
+    /// Starts a new CPU usage measurement window.
+    ///
+    /// Clears the accumulated execution cycles of all tasks and sets the window start to the
+    /// current system tick.
+    pub fn reset_cpu_stats(&mut self) {
+        for l_task in self.tasks.iter_mut() {
+            l_task.busy_cycles = 0;
+        }
+        self.cpu_window_start = HAL_GetTick();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Task names are the prefixes of this string, one per slot.
+    static G_TASK_NAMES: [u8; K_MAX_TASKS + 1] = [b'a'; K_MAX_TASKS + 1];
+
+    fn task_name(p_index: usize) -> &'static str {
+        core::str::from_utf8(&G_TASK_NAMES[..=p_index]).unwrap()
+    }
+
+    fn task() -> KernelResult<()> {
+        Ok(())
+    }
+
+    fn add(p_scheduler: &mut Scheduler, p_name: &'static str) -> KernelResult<u32> {
+        p_scheduler.add_periodic_app(
+            p_name,
+            AppCall::App(task),
+            None,
+            Milliseconds(100),
+            None,
+            false,
+        )
+    }
+
+    #[test]
+    fn largest_enabled_task_count_is_used() {
+        let l_sizes = [
+            (8, cfg!(feature = "max-tasks-8")),
+            (16, cfg!(feature = "max-tasks-16")),
+            (64, cfg!(feature = "max-tasks-64")),
+            (128, cfg!(feature = "max-tasks-128")),
+        ];
+        let l_expected = l_sizes
+            .iter()
+            .filter(|(_, l_enabled)| *l_enabled)
+            .map(|(l_size, _)| *l_size)
+            .max()
+            .unwrap_or(32);
+        assert_eq!(K_MAX_TASKS, l_expected);
+    }
+
+    #[test]
+    fn full_scheduler_reports_its_capacity() {
+        let mut l_scheduler = Scheduler::new(Milliseconds(50));
+        for l_index in 0..K_MAX_TASKS {
+            add(&mut l_scheduler, task_name(l_index)).unwrap();
+        }
+        assert_eq!(l_scheduler.slots(), (K_MAX_TASKS, K_MAX_TASKS));
+
+        let l_name = task_name(K_MAX_TASKS);
+        assert!(matches!(
+            add(&mut l_scheduler, l_name),
+            Err(KernelError::SchedulerFull(l_n, K_MAX_TASKS, K_MAX_TASKS)) if l_n == l_name
+        ));
+    }
+
+    #[test]
+    fn removed_task_frees_its_slot() {
+        let mut l_scheduler = Scheduler::new(Milliseconds(50));
+        for l_index in 0..K_MAX_TASKS {
+            add(&mut l_scheduler, task_name(l_index)).unwrap();
+        }
+        l_scheduler.remove_periodic_app(task_name(0)).unwrap();
+        assert_eq!(l_scheduler.slots(), (K_MAX_TASKS - 1, K_MAX_TASKS));
+        add(&mut l_scheduler, task_name(K_MAX_TASKS)).unwrap();
+    }
+
+    #[test]
+    fn no_task_is_due_while_paused() {
+        let mut l_scheduler = Scheduler::new(Milliseconds(50));
+        add(&mut l_scheduler, task_name(0)).unwrap();
+        l_scheduler
+            .add_periodic_app(
+                task_name(1),
+                AppCall::App(task),
+                None,
+                Milliseconds(50),
+                None,
+                false,
+            )
+            .unwrap();
+        assert!(l_scheduler.is_due(0) && l_scheduler.is_due(1));
+
+        // Cycles keep counting while paused, as periodic_task does
+        l_scheduler.pause_all();
+        for _ in 0..2 {
+            assert!(!l_scheduler.is_due(0) && !l_scheduler.is_due(1));
+            l_scheduler.next_cycle();
+        }
+
+        // Tasks run again on their original phase after resume
+        l_scheduler.resume_all();
+        assert!(l_scheduler.is_due(0) && l_scheduler.is_due(1));
+        l_scheduler.next_cycle();
+        assert!(!l_scheduler.is_due(0) && l_scheduler.is_due(1));
+    }
+
+    #[test]
+    fn cycle_count_reset_applies_at_the_end_of_the_cycle() {
+        let mut l_scheduler = Scheduler::new(Milliseconds(50));
+        l_scheduler.next_cycle();
+        l_scheduler.next_cycle();
+        assert_eq!(l_scheduler.get_cycle_count(), 2);
+
+        l_scheduler.reset_cycle_count();
+        assert_eq!(l_scheduler.get_cycle_count(), 2);
+        l_scheduler.next_cycle();
+        assert_eq!(l_scheduler.get_cycle_count(), 0);
+        l_scheduler.next_cycle();
+        assert_eq!(l_scheduler.get_cycle_count(), 1);
+    }
 }
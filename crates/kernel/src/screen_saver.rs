@@ -0,0 +1,114 @@
+//! Screen saver for the primary display.
+//!
+//! When no activity happened for the configured timeout, a kernel task switches the display
+//! backlight off to prevent burn-in and save power. The brightness in use before is restored
+//! on the next activity. The backlight limits of the display still apply, so the screen is
+//! only dimmed down to the lowest allowed brightness.
+//!
+//! Activity is:
+//! - any byte received by the terminal prompt, including bytes ignored while an app runs;
+//! - any display syscall on the primary display, whatever the caller. Terminal output
+//!   mirrored on the display goes through display syscalls, so it counts as activity too.
+//!
+//! Kernel tasks driving the display directly (display refresh, caret blink) are not activity,
+//! so the screen saver starts on an idle prompt.
+//!
+//! The timeout is set at boot through [`crate::BootConfig::screen_saver_timeout`], `0`
+//! disables the screen saver.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use spin::Mutex;
+
+use crate::data::Kernel;
+use crate::scheduler::AppCall;
+use crate::{KernelError, KernelResult, Milliseconds, critical_section, now_ms};
+
+/// Name of the screen saver task in the scheduler.
+const K_SCREEN_SAVER_APP_NAME: &str = "SCREEN_SAVER";
+/// Period of the idle duration check.
+const K_SCREEN_SAVER_CHECK_PERIOD: Milliseconds = Milliseconds(1000);
+
+/// Idle timeout in milliseconds, `0` when the screen saver is disabled.
+static G_SCREEN_SAVER_TIMEOUT: AtomicU32 = AtomicU32::new(0);
+/// Uptime of the last activity in milliseconds.
+static G_LAST_ACTIVITY: AtomicU32 = AtomicU32::new(0);
+/// Brightness to restore, `None` while the screen saver is not active.
+static G_SAVED_BACKLIGHT: Mutex<Option<u8>> = Mutex::new(None);
+
+/// Starts or stops the screen saver.
+///
+/// The idle duration restarts from the call. Stopping the screen saver while it is active
+/// restores the backlight.
+///
+/// # Parameters
+/// - `timeout`: Idle duration before the backlight is switched off, `Milliseconds(0)` to
+///   disable the screen saver.
+///
+/// # Errors
+/// Returns an error if the check task cannot be scheduled or removed, or if the backlight
+/// cannot be restored.
+pub(crate) fn set_screen_saver_timeout(p_timeout: Milliseconds) -> KernelResult<()> {
+    let l_running = Kernel::scheduler()
+        .app_exists(K_SCREEN_SAVER_APP_NAME)
+        .is_some();
+    G_SCREEN_SAVER_TIMEOUT.store(p_timeout.to_u32(), Ordering::Relaxed);
+
+    if p_timeout.to_u32() == 0 {
+        if l_running {
+            Kernel::scheduler().remove_periodic_app(K_SCREEN_SAVER_APP_NAME)?;
+        }
+    } else if !l_running {
+        Kernel::scheduler().add_periodic_app(
+            K_SCREEN_SAVER_APP_NAME,
+            AppCall::App(screen_saver_task),
+            None,
+            K_SCREEN_SAVER_CHECK_PERIOD,
+            None,
+            false,
+        )?;
+    }
+
+    notify_activity()
+}
+
+/// Records an activity, restoring the backlight if the screen saver is active.
+///
+/// # Errors
+/// Returns [`KernelError::DisplayError`] if the backlight cannot be restored.
+pub(crate) fn notify_activity() -> KernelResult<()> {
+    G_LAST_ACTIVITY.store(now_ms(), Ordering::Relaxed);
+
+    critical_section(|| match G_SAVED_BACKLIGHT.lock().take() {
+        Some(l_percent) => Kernel::display()
+            .set_backlight(l_percent)
+            .map_err(KernelError::DisplayError),
+        None => Ok(()),
+    })
+}
+
+/// Scheduler task body: switch the backlight off once the idle timeout has elapsed.
+///
+/// # Errors
+/// Returns [`KernelError::DisplayError`] if the backlight cannot be changed.
+fn screen_saver_task() -> KernelResult<()> {
+    let l_timeout = G_SCREEN_SAVER_TIMEOUT.load(Ordering::Relaxed);
+    let l_idle = now_ms().wrapping_sub(G_LAST_ACTIVITY.load(Ordering::Relaxed));
+    if l_timeout == 0 || l_idle < l_timeout {
+        return Ok(());
+    }
+
+    critical_section(|| {
+        let mut l_saved = G_SAVED_BACKLIGHT.lock();
+        if l_saved.is_some() {
+            return Ok(());
+        }
+
+        let l_display = Kernel::display();
+        let l_percent = l_display.get_backlight();
+        l_display
+            .set_backlight(0)
+            .map_err(KernelError::DisplayError)?;
+        *l_saved = Some(l_percent);
+        Ok(())
+    })
+}
@@ -0,0 +1,78 @@
+//! Stack usage watermarking.
+//!
+//! Apps, kernel tasks and interrupt handlers all run on the main stack, which grows down from
+//! `_stack_start` to the end of the static data (`__sheap`, no heap is used). At startup,
+//! [`stack_fill`] writes [`K_STACK_FILL_PATTERN`] over the free part of the stack. The
+//! deepest stack use since then is found by [`stack_high_water`], by scanning up from the
+//! bottom of the stack for the first overwritten word.
+//!
+//! The fill must happen before any deep call, so it is done by `cortex_init`. Anything used
+//! before the fill is not accounted for.
+
+use core::ptr::addr_of;
+
+/// Pattern written over the free stack at startup.
+const K_STACK_FILL_PATTERN: u32 = 0xA5A5_A5A5;
+
+/// Bytes left untouched below the stack pointer when filling, covering the fill function frame.
+const K_STACK_FILL_MARGIN: usize = 256;
+
+unsafe extern "C" {
+    /// End of the static data, set by the `cortex-m-rt` linker script.
+    static __sheap: u32;
+    /// Initial stack pointer, set in `memory.x`.
+    static _stack_start: u32;
+}
+
+/// Returns the bottom and top addresses of the stack region, aligned on words.
+fn stack_bounds() -> (usize, usize) {
+    let l_bottom = (addr_of!(__sheap) as usize + 3) & !3;
+    let l_top = addr_of!(_stack_start) as usize & !3;
+    (l_bottom, l_top)
+}
+
+/// Fills the unused part of the stack with [`K_STACK_FILL_PATTERN`].
+///
+/// Everything from the bottom of the stack up to [`K_STACK_FILL_MARGIN`] bytes below the
+/// current stack pointer is overwritten.
+pub(crate) fn stack_fill() {
+    let (l_bottom, _) = stack_bounds();
+    let l_end = cortex_m::register::msp::read() as usize - K_STACK_FILL_MARGIN;
+
+    let mut l_address = l_bottom;
+    while l_address < l_end {
+        // The region is below the stack pointer and not used by anything else
+        unsafe {
+            (l_address as *mut u32).write_volatile(K_STACK_FILL_PATTERN);
+        }
+        l_address += 4;
+    }
+}
+
+/// Returns the size of the stack region in bytes.
+pub fn stack_size() -> usize {
+    let (l_bottom, l_top) = stack_bounds();
+    l_top - l_bottom
+}
+
+/// Returns the maximum stack usage since startup, in bytes.
+///
+/// The stack is scanned from its bottom for the first word which no longer holds the fill
+/// pattern. A word legitimately written with the pattern value lowers the result by a few
+/// bytes, which is acceptable for a diagnostic.
+///
+/// # Returns
+/// The distance between the top of the stack and the deepest overwritten word. It equals
+/// [`stack_size`] when the whole stack was used, meaning it may have overflowed.
+pub fn stack_high_water() -> usize {
+    let (l_bottom, l_top) = stack_bounds();
+
+    let mut l_address = l_bottom;
+    while l_address < l_top
+        && unsafe { (l_address as *const u32).read_volatile() } == K_STACK_FILL_PATTERN
+    {
+        l_address += 4;
+    }
+
+    l_top - l_address
+}
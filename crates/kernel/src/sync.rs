@@ -0,0 +1,23 @@
+//! Synchronization helpers for data shared between apps and interrupt callbacks.
+
+/// Executes a closure with interrupts masked.
+///
+/// Kernel apps run from the scheduler interrupt while HAL callbacks (e.g. terminal reception)
+/// run from peripheral interrupts. Data shared between both must be accessed inside a
+/// critical section so that an interrupt cannot fire in the middle of a mutation.
+///
+/// Interrupts are masked before `f` runs and restored to their previous state afterwards, so
+/// critical sections can be nested. Keep the closure short: blocking I/O inside it delays all
+/// interrupts, including the system tick.
+///
+/// # Parameters
+/// - `f`: Closure to execute with interrupts masked.
+///
+/// # Returns
+/// The value returned by `f`.
+pub fn critical_section<F, R>(p_f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    cortex_m::interrupt::free(|_| p_f())
+}
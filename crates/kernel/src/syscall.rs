@@ -1,10 +1,14 @@
 use crate::console_output::ConsoleFormatting;
 use crate::data::Kernel;
-use crate::{DeviceType, KernelError, KernelResult};
-use display::Colors;
+use crate::scheduler::{K_MAX_TASKS, TaskInfo};
+use crate::screen_saver::notify_activity;
+use crate::{DeviceType, K_PRIMARY_DISPLAY, KernelError, KernelResult, Microseconds, now_ms};
+use display::{Colors, Display};
 use hal_interface::{
-    InterfaceCallback, InterfaceReadAction, InterfaceReadResult, InterfaceWriteActions,
+    InterfaceCallback, InterfaceReadAction, InterfaceReadResult, InterfaceWriteActions, LcdLayer,
+    TimerMode,
 };
+use heapless::Vec;
 
 /// Represents the actions that can be performed via a HAL syscall.
 pub enum SysCallHalActions<'a> {
@@ -12,10 +16,19 @@ pub enum SysCallHalActions<'a> {
     Write(InterfaceWriteActions<'a>),
     /// Read data from a HAL interface into the provided result buffer.
     Read(InterfaceReadAction, &'a mut InterfaceReadResult),
+    /// Read the receive buffer of a HAL interface into a caller buffer, the number of bytes
+    /// read is written into the provided `usize`.
+    ReadInto(&'a mut [u8], &'a mut usize),
     /// Retrieve the ID of a HAL interface by its name.
     GetID(&'static str, &'a mut usize),
     /// Configure a callback for a HAL interface.
     ConfigureCallback(InterfaceCallback),
+    /// Start a hardware timer calling the callback once per period, or once after the period
+    /// in one-shot mode, 0 stops it. The callback runs in interrupt context, see
+    /// [`hal_interface::Hal::configure_timer`].
+    ConfigureTimer(Microseconds, TimerMode, InterfaceCallback),
+    /// Reinitialize a HAL interface, the caller must hold the lock on it.
+    Reset,
 }
 
 /// Dispatches a HAL-related syscall to the currently configured HAL implementation.
@@ -26,7 +39,7 @@ pub enum SysCallHalActions<'a> {
 ///
 /// # Parameters
 /// - `interface_id`: The numeric identifier of the HAL interface to operate on.
-/// - `action`: The action to perform against the interface (read/write/lookup/configure).
+/// - `action`: The action to perform against the interface (read/write/lookup/configure/reset).
 /// - `caller_id`: The ID of the calling process/app, used for access control/auditing by the HAL.
 ///
 /// # Returns
@@ -36,15 +49,19 @@ pub enum SysCallHalActions<'a> {
 /// # Errors
 /// - Returns `Err(KernelError::HalError(_))` when:
 ///   - `interface_write` fails
-///   - `interface_read` fails
+///   - `interface_read` or `interface_read_into` fails
 ///   - `get_interface_id` fails
 ///   - `configure_callback` fails
+///   - `reset_interface` fails
 ///
 /// In all error cases, `Kernel::errors().error_handler(&err)` is called before returning the error.
 ///
 /// # Side effects
 /// - For [`SysCallHalActions::Read`], writes the read result into the provided
 ///   [`InterfaceReadResult`] via the mutable reference parameter.
+/// - For [`SysCallHalActions::ReadInto`], fills the provided slice and writes the number of
+///   bytes read into the provided `usize`. It is lower than the slice length when fewer bytes
+///   were available.
 /// - For [`SysCallHalActions::GetID`], writes the resolved interface id into the provided `usize`.
 pub fn syscall_hal(
     p_interface_id: usize,
@@ -61,6 +78,12 @@ pub fn syscall_hal(
                 .map_err(KernelError::HalError)?;
             Ok(())
         }
+        SysCallHalActions::ReadInto(l_buffer, l_count) => {
+            *l_count = Kernel::hal()
+                .interface_read_into(p_interface_id, p_caller_id, l_buffer)
+                .map_err(KernelError::HalError)?;
+            Ok(())
+        }
         SysCallHalActions::GetID(l_name, l_id) => match Kernel::hal().get_interface_id(l_name) {
             Ok(l_hal_id) => {
                 *l_id = l_hal_id;
@@ -71,6 +94,12 @@ pub fn syscall_hal(
         SysCallHalActions::ConfigureCallback(l_callback) => Kernel::hal()
             .configure_callback(p_interface_id, p_caller_id, l_callback)
             .map_err(KernelError::HalError),
+        SysCallHalActions::ConfigureTimer(l_period, l_mode, l_callback) => Kernel::hal()
+            .configure_timer(p_interface_id, p_caller_id, l_period, l_mode, l_callback)
+            .map_err(KernelError::HalError),
+        SysCallHalActions::Reset => Kernel::hal()
+            .reset_interface(p_interface_id, p_caller_id)
+            .map_err(KernelError::HalError),
     };
 
     match l_result {
@@ -86,30 +115,93 @@ pub fn syscall_hal(
 pub enum SysCallDisplayArgs<'a> {
     /// Clear the display with a specific color.
     Clear(Colors),
+    /// Clear the display with the default background color.
+    ClearDefault,
+    /// Set the default background color used by `ClearDefault`.
+    SetBackground(Colors),
+    /// Select the layer used by the clear and drawing requests. Apps drawing on the
+    /// background layer must select the foreground layer again before exiting.
+    SetLayer(LcdLayer),
     /// Set the default drawing color.
     SetColor(Colors),
     /// Set the active font size.
     SetFont(display::FontSize),
+    /// Set the horizontal and vertical text scale factors.
+    SetTextScale(u8, u8),
     /// Set the cursor position in pixels (x, y).
     SetCursorPos(u16, u16),
     /// Write a character at the current cursor position.
     WriteCharAtCursor(char, Option<Colors>),
     /// Write a character at a specific position (char, x, y, color).
     WriteChar(char, u16, u16, Option<Colors>),
+    /// Write a character at a specific position with a background color (char, x, y, fg, bg).
+    WriteCharBg(char, u16, u16, Colors, Colors),
     /// Write a string at the current cursor position.
     WriteStrAtCursor(&'a str, Option<Colors>),
     /// Write a string at a specific position (string, x, y, color).
     WriteStr(&'a str, u16, u16, Option<Colors>),
+    /// Write a string at a specific position over a background color, in a readable text color
+    /// (string, x, y, bg).
+    WriteStrAutoContrast(&'a str, u16, u16, Colors),
+    /// Set the backlight brightness in percent (clamped to the display limits).
+    SetBacklight(u8),
+    /// Draw a filled rectangle (x, y, width, height, color).
+    DrawRect(u16, u16, u16, u16, Option<Colors>),
+    /// Set a single pixel (x, y, color). Slow for large areas, prefer `DrawRect` there.
+    SetPixel(u16, u16, Colors),
+    /// Fill the screen with a checkerboard (cell size, top-left color, other color).
+    DrawCheckerboard(u16, Colors, Colors),
+    /// Get the screen dimensions (width, height) in pixels.
+    GetSize(&'a mut (u16, u16)),
+    /// Get the font used for text rendering, e.g. to restore it after a temporary change.
+    GetFont(&'a mut display::FontSize),
+    /// Get the default drawing color, e.g. to restore it after a temporary change.
+    GetColor(&'a mut Colors),
+    /// Get the base address of the frame buffer currently used for drawing.
+    ///
+    /// The caller must own the display lock. Writing through this address bypasses the display
+    /// driver entirely: the caller is responsible for staying within the frame buffer and for
+    /// querying the address again after each frame buffer switch.
+    GetFbAddress(&'a mut u32),
 }
 
-/// Dispatches a display-related syscall to the kernel display driver.
+/// Dispatches a display-related syscall to the primary display driver.
 ///
 /// This function enforces that the caller is authorized to use the display device before
 /// performing the requested operation. Errors are mapped into [`KernelError::DisplayError`]
 /// and routed through the kernel error handler.
 ///
+/// This is [`syscall_display_on`] with the [`K_PRIMARY_DISPLAY`] selector.
+///
+/// # Parameters
+/// - `args`: The display operation to perform (clear, set color/background/font, set cursor,
+///   draw text, set backlight, draw rectangle, get screen size, font or color, get frame buffer
+///   address).
+/// - `caller_id`: The ID of the calling process/app. Used to authorize access to the display.
+///
+/// # Returns
+/// - `Ok(())` if authorization and the display operation succeed.
+/// - `Err(KernelError)` if authorization fails or the display operation fails.
+///
+/// # Errors
+/// See [`syscall_display_on`].
+///
+/// # Side effects
+/// - Writes to the display framebuffer/hardware through `Kernel::display()`.
+pub fn syscall_display(p_args: SysCallDisplayArgs, p_caller_id: u32) -> KernelResult<()> {
+    syscall_display_on(K_PRIMARY_DISPLAY, p_args, p_caller_id)
+}
+
+/// Dispatches a display-related syscall to the display matching a selector.
+///
+/// Each display is locked and authorized on its own, as `DeviceType::Display(display)`: owning
+/// one display gives no access to a display locked by another app. The primary display is
+/// [`K_PRIMARY_DISPLAY`], the additional displays follow in the order of
+/// [`crate::BootConfig::extra_displays`].
+///
 /// # Parameters
-/// - `args`: The display operation to perform (clear, set color/font, set cursor, draw text).
+/// - `display`: Selector of the target display.
+/// - `args`: The display operation to perform.
 /// - `caller_id`: The ID of the calling process/app. Used to authorize access to the display.
 ///
 /// # Returns
@@ -117,46 +209,175 @@ pub enum SysCallDisplayArgs<'a> {
 /// - `Err(KernelError)` if authorization fails or the display operation fails.
 ///
 /// # Errors
-/// - Returns any error produced by `Kernel::devices().authorize(DeviceType::Display, caller_id)`.
+/// - Returns any error produced by `Kernel::devices().authorize(DeviceType::Display(display),
+///   caller_id)`.
+/// - Returns `Err(KernelError::DisplayNotConfigured)` if no display is registered with this
+///   selector, or if it was not initialized at boot.
+/// - For [`SysCallDisplayArgs::GetFbAddress`], returns any error produced by
+///   `Kernel::devices().check_owner(DeviceType::Display(display), caller_id)`: the caller must
+///   hold the display lock, authorization alone is not enough.
 /// - Returns `Err(KernelError::DisplayError(_))` if the underlying display operation fails.
 ///
 /// In all error cases occurring after the match is evaluated, `Kernel::errors().error_handler(&err)`
 /// is called before returning the error.
 ///
 /// # Side effects
+/// - Writes to the display framebuffer/hardware through `Kernel::display_at(display)`.
+pub fn syscall_display_on(
+    p_display: usize,
+    p_args: SysCallDisplayArgs,
+    p_caller_id: u32,
+) -> KernelResult<()> {
+    // Check for device authorization
+    Kernel::devices().authorize(DeviceType::Display(p_display), p_caller_id)?;
+
+    // Display may be absent on boards without LCD
+    let l_display = Kernel::display_at(p_display)?;
+    if !l_display.is_initialized() {
+        return Err(KernelError::DisplayNotConfigured);
+    }
+
+    // Raw frame buffer access requires lock ownership
+    if let SysCallDisplayArgs::GetFbAddress(_) = p_args {
+        Kernel::devices().check_owner(DeviceType::Display(p_display), p_caller_id)?;
+    }
+
+    // Wake the primary display up before using it
+    if p_display == K_PRIMARY_DISPLAY {
+        notify_activity()?;
+    }
+
+    let l_result = display_dispatch(l_display, p_args);
+
+    match l_result {
+        Ok(..) => Ok(()),
+        Err(l_err) => {
+            Kernel::errors().error_handler(&l_err);
+            Err(l_err)
+        }
+    }
+}
+
+/// Dispatches a batch of display-related syscalls, authorizing the caller only once.
+///
+/// This is equivalent to calling [`syscall_display`] for each operation, without repeating
+/// the authorization for every operation. Batches always target the primary display. Operations are applied in order and the batch stops
+/// at the first error.
+///
+/// Operations are taken by value from any iterable, typically an array literal:
+/// `syscall_display_batch([SysCallDisplayArgs::Clear(..), SysCallDisplayArgs::WriteStr(..)], id)`.
+/// The borrows held by the operations (`&str`, `&mut u32`) only need to outlive the call.
+///
+/// # Parameters
+/// - `ops`: The display operations to perform.
+/// - `caller_id`: The ID of the calling process/app. Used to authorize access to the display.
+///
+/// # Returns
+/// - `Ok(())` if authorization and all display operations succeed.
+/// - `Err((index, KernelError))` with the index of the failing operation otherwise.
+///
+/// # Errors
+/// - Authorization errors and [`KernelError::DisplayNotConfigured`] are reported with index `0`,
+///   no operation is applied in this case.
+/// - Other errors are the same as for [`syscall_display`], display driver errors being routed
+///   through `Kernel::errors().error_handler(&err)`.
+///
+/// # Side effects
 /// - Writes to the display framebuffer/hardware through `Kernel::display()`.
-pub fn syscall_display(p_args: SysCallDisplayArgs, p_caller_id: u32) -> KernelResult<()> {
+pub fn syscall_display_batch<'a, I>(p_ops: I, p_caller_id: u32) -> Result<(), (usize, KernelError)>
+where
+    I: IntoIterator<Item = SysCallDisplayArgs<'a>>,
+{
     // Check for device authorization
-    Kernel::devices().authorize(DeviceType::Display, p_caller_id)?;
+    Kernel::devices()
+        .authorize(DeviceType::Display(K_PRIMARY_DISPLAY), p_caller_id)
+        .map_err(|l_err| (0, l_err))?;
 
-    let l_result = match p_args {
-        SysCallDisplayArgs::Clear(l_color) => Kernel::display().clear(l_color),
-        SysCallDisplayArgs::SetColor(l_color) => Kernel::display().set_color(l_color),
-        SysCallDisplayArgs::SetFont(l_font) => Kernel::display().set_font(l_font),
-        SysCallDisplayArgs::SetCursorPos(l_x, l_y) => Kernel::display().set_cursor_pos(l_x, l_y),
+    // Display may be absent on boards without LCD
+    let l_display = Kernel::display();
+    if !l_display.is_initialized() {
+        return Err((0, KernelError::DisplayNotConfigured));
+    }
+
+    // Wake the display up before using it
+    notify_activity().map_err(|l_err| (0, l_err))?;
+
+    for (l_index, l_op) in p_ops.into_iter().enumerate() {
+        // Raw frame buffer access requires lock ownership
+        if let SysCallDisplayArgs::GetFbAddress(_) = l_op {
+            Kernel::devices()
+                .check_owner(DeviceType::Display(K_PRIMARY_DISPLAY), p_caller_id)
+                .map_err(|l_err| (l_index, l_err))?;
+        }
+
+        if let Err(l_err) = display_dispatch(l_display, l_op) {
+            Kernel::errors().error_handler(&l_err);
+            return Err((l_index, l_err));
+        }
+    }
+
+    Ok(())
+}
+
+/// Applies a single display operation, without authorization checks.
+///
+/// # Parameters
+/// - `display`: The display driver to apply the operation to.
+/// - `args`: The display operation to perform.
+///
+/// # Errors
+/// - Returns `Err(KernelError::DisplayError(_))` if the underlying display operation fails.
+fn display_dispatch(p_display: &mut Display, p_args: SysCallDisplayArgs) -> KernelResult<()> {
+    match p_args {
+        SysCallDisplayArgs::Clear(l_color) => p_display.clear(l_color),
+        SysCallDisplayArgs::ClearDefault => p_display.clear_default(),
+        SysCallDisplayArgs::SetBackground(l_color) => p_display.set_background(l_color),
+        SysCallDisplayArgs::SetLayer(l_layer) => p_display.set_active_layer(l_layer),
+        SysCallDisplayArgs::SetColor(l_color) => p_display.set_color(l_color),
+        SysCallDisplayArgs::SetFont(l_font) => p_display.set_font(l_font),
+        SysCallDisplayArgs::SetTextScale(l_sx, l_sy) => p_display.set_text_scale(l_sx, l_sy),
+        SysCallDisplayArgs::SetCursorPos(l_x, l_y) => p_display.set_cursor_pos(l_x, l_y),
         SysCallDisplayArgs::WriteCharAtCursor(l_c, l_color) => {
-            Kernel::display().draw_char_at_cursor(l_c as u8, l_color)
+            p_display.draw_char_at_cursor(l_c as u8, l_color)
         }
 
         SysCallDisplayArgs::WriteChar(l_c, l_x, l_y, l_color) => {
-            Kernel::display().draw_char(l_c as u8, l_x, l_y, l_color)
+            p_display.draw_char(l_c as u8, l_x, l_y, l_color)
+        }
+        SysCallDisplayArgs::WriteCharBg(l_c, l_x, l_y, l_fg, l_bg) => {
+            p_display.draw_char_bg(l_c as u8, l_x, l_y, l_fg, l_bg)
         }
         SysCallDisplayArgs::WriteStrAtCursor(l_str, l_color) => {
-            Kernel::display().draw_string_at_cursor(l_str, l_color)
+            p_display.draw_string_at_cursor(l_str, l_color)
         }
         SysCallDisplayArgs::WriteStr(l_str, l_x, l_y, l_color) => {
-            Kernel::display().draw_string(l_str, l_x, l_y, l_color)
+            p_display.draw_string(l_str, l_x, l_y, l_color)
         }
-    }
-    .map_err(KernelError::DisplayError);
-
-    match l_result {
-        Ok(..) => Ok(()),
-        Err(l_err) => {
-            Kernel::errors().error_handler(&l_err);
-            Err(l_err)
+        SysCallDisplayArgs::WriteStrAutoContrast(l_str, l_x, l_y, l_bg) => {
+            p_display.draw_string_auto_contrast(l_str, l_x, l_y, l_bg)
+        }
+        SysCallDisplayArgs::SetBacklight(l_percent) => p_display.set_backlight(l_percent),
+        SysCallDisplayArgs::DrawRect(l_x, l_y, l_width, l_height, l_color) => {
+            p_display.draw_rect(l_x, l_y, l_width, l_height, l_color)
+        }
+        SysCallDisplayArgs::SetPixel(l_x, l_y, l_color) => p_display.set_pixel(l_x, l_y, l_color),
+        SysCallDisplayArgs::DrawCheckerboard(l_cell, l_color_a, l_color_b) => {
+            p_display.draw_checkerboard(l_cell, l_color_a, l_color_b)
+        }
+        SysCallDisplayArgs::GetSize(l_size) => p_display.get_size().map(|l_s| *l_size = l_s),
+        SysCallDisplayArgs::GetFont(l_font) => {
+            *l_font = p_display.get_font();
+            Ok(())
+        }
+        SysCallDisplayArgs::GetColor(l_color) => {
+            *l_color = p_display.get_color();
+            Ok(())
         }
+        SysCallDisplayArgs::GetFbAddress(l_address) => p_display
+            .displayed_fb_address()
+            .map(|l_addr| *l_address = l_addr),
     }
+    .map_err(KernelError::DisplayError)
 }
 
 /// Writes formatted output to the terminal device.
@@ -193,6 +414,59 @@ pub fn syscall_terminal(p_formatting: ConsoleFormatting, p_caller_id: u32) -> Ke
     }
 }
 
+/// Writes raw bytes to the terminal device, bypassing the formatting layer.
+///
+/// Used to send terminal control sequences (cursor positioning, colors...). The caller must be
+/// authorized to use the terminal device, as for [`syscall_terminal`]. Any write error is
+/// routed through the kernel error handler.
+///
+/// # Parameters
+/// - `bytes`: The bytes to write, forwarded unchanged to the serial output.
+/// - `caller_id`: The ID of the calling process/app. Used to authorize access to the terminal.
+///
+/// # Returns
+/// - `Ok(())` if authorization and the terminal write succeed.
+/// - `Err(KernelError)` if authorization fails or the terminal write fails.
+pub fn syscall_terminal_raw(p_bytes: &[u8], p_caller_id: u32) -> KernelResult<()> {
+    // Check for device authorization
+    Kernel::devices().authorize(DeviceType::Terminal, p_caller_id)?;
+
+    match Kernel::terminal().write_raw(p_bytes) {
+        Ok(..) => Ok(()),
+        Err(l_err) => {
+            Kernel::errors().error_handler(&l_err);
+            Err(l_err)
+        }
+    }
+}
+
+/// Enables or disables the display mirror pager for the calling command.
+///
+/// Commands printing more lines than the display can show enable the pager before printing;
+/// it is disabled automatically when the foreground command exits. The caller must be
+/// authorized to use the terminal device, as for [`syscall_terminal`]. Any error is routed
+/// through the kernel error handler.
+///
+/// # Parameters
+/// - `enabled`: `true` to page the display mirror output.
+/// - `caller_id`: The ID of the calling process/app. Used to authorize access to the terminal.
+///
+/// # Returns
+/// - `Ok(())` if authorization succeeds and the pager is updated.
+/// - `Err(KernelError)` if authorization fails or the display size cannot be read.
+pub fn syscall_terminal_pager(p_enabled: bool, p_caller_id: u32) -> KernelResult<()> {
+    // Check for device authorization
+    Kernel::devices().authorize(DeviceType::Terminal, p_caller_id)?;
+
+    match Kernel::terminal().set_pager(p_enabled) {
+        Ok(..) => Ok(()),
+        Err(l_err) => {
+            Kernel::errors().error_handler(&l_err);
+            Err(l_err)
+        }
+    }
+}
+
 /// Represents the operations for a device-management syscall.
 pub enum SysCallDevicesArgs<'a> {
     /// Request an exclusive lock on the device.
@@ -252,3 +526,45 @@ pub fn syscall_devices(
         }
     }
 }
+
+/// Represents the operations for a scheduler introspection syscall.
+pub enum SysCallSchedulerArgs<'a> {
+    /// List the tasks currently active in the scheduler.
+    ListTasks(&'a mut Vec<TaskInfo, K_MAX_TASKS>),
+    /// Suspend the execution of all scheduled tasks.
+    PauseAll,
+    /// Resume the execution of all scheduled tasks.
+    ResumeAll,
+    /// Read the uptime in milliseconds.
+    Now(&'a mut u32),
+}
+
+/// Dispatches scheduler introspection syscalls.
+///
+/// # Parameters
+/// - `args`: The scheduler operation to perform:
+///   - `ListTasks(tasks_out)`: Writes the name, app id and remaining lifetime (in ms, `0` for
+///     infinite) of each active task into `tasks_out`.
+///   - `PauseAll`: Stops executing task bodies until `ResumeAll` is requested. The scheduler
+///     cycle counter keeps advancing while paused.
+///   - `ResumeAll`: Resumes the execution of the tasks.
+///   - `Now(now_out)`: Writes the uptime in milliseconds into `now_out`, see [`now_ms`] for
+///     the wraparound.
+///
+/// # Returns
+/// - `Ok(())` if the requested operation succeeds.
+///
+/// # Side effects
+/// - For `ListTasks`, overwrites the provided vector.
+/// - For `PauseAll`, no task (including the caller) runs again until another context resumes
+///   the scheduler.
+pub fn syscall_scheduler(p_args: SysCallSchedulerArgs) -> KernelResult<()> {
+    match p_args {
+        SysCallSchedulerArgs::ListTasks(l_tasks) => *l_tasks = Kernel::scheduler().list_tasks(),
+        SysCallSchedulerArgs::PauseAll => Kernel::scheduler().pause_all(),
+        SysCallSchedulerArgs::ResumeAll => Kernel::scheduler().resume_all(),
+        SysCallSchedulerArgs::Now(l_now) => *l_now = now_ms(),
+    }
+
+    Ok(())
+}
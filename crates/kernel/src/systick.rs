@@ -1,13 +1,37 @@
-use crate::Milliseconds;
 use crate::data::Kernel;
-use core::sync::atomic::{AtomicU32, Ordering};
-use cortex_m::peripheral::SCB;
+use crate::reset_reason::{ResetReason, record_reset_reason};
+use crate::{KernelError, KernelResult, Milliseconds};
+use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use cortex_m::peripheral::syst::SystClkSource;
+use cortex_m::peripheral::{DWT, SCB};
+#[cfg_attr(test, allow(unused_imports))]
 use cortex_m_rt::exception;
 
+/// Maximum reload value of the 24-bit SysTick counter.
+const K_SYSTICK_MAX_RELOAD: u32 = 0x00FF_FFFF;
+
 static G_SCHED_TICKS_COUNTER: AtomicU32 = AtomicU32::new(0);
 static G_SCHED_TICKS_TARGET: AtomicU32 = AtomicU32::new(0);
 
+/// SysTick period in milliseconds, set by [`init_systick`].
+static G_SYSTICK_PERIOD_MS: AtomicU32 = AtomicU32::new(1);
+/// SysTick reload value, the SysTick period is `reload + 1` core cycles.
+static G_SYSTICK_RELOAD: AtomicU32 = AtomicU32::new(0);
+
+/// Set once a SysTick entry time is available as reference for the latency measurement.
+static G_LATENCY_ARMED: AtomicBool = AtomicBool::new(false);
+/// DWT cycle count at which the next SysTick interrupt is expected to be entered.
+static G_LATENCY_NEXT_TICK: AtomicU32 = AtomicU32::new(0);
+/// Worst SysTick entry delay measured, in core cycles.
+static G_LATENCY_MAX: AtomicU32 = AtomicU32::new(0);
+
+/// Software watchdog timeout in SysTick ticks, `0` when the watchdog is disabled.
+static G_WATCHDOG_TIMEOUT: AtomicU32 = AtomicU32::new(0);
+/// Tick of the last scheduler cycle seen by the watchdog.
+static G_WATCHDOG_LAST_FEED: AtomicU32 = AtomicU32::new(0);
+/// Set by the first scheduler cycle, the watchdog does not check anything before.
+static G_WATCHDOG_ARMED: AtomicBool = AtomicBool::new(false);
+
 /// Initializes the system timer (Systick) with a specified or default period.
 ///
 /// This function configures the SysTick timer to generate periodic interrupts
@@ -28,6 +52,12 @@ static G_SCHED_TICKS_TARGET: AtomicU32 = AtomicU32::new(0);
 ///   a default reload value corresponding to 1 millisecond is used.
 /// - Enables the SysTick interrupt and starts the SysTick counter.
 ///
+/// # Errors
+///
+/// Returns [`KernelError::InvalidTimingConfig`] without touching the timer if the
+/// period is zero, or if the resulting reload value does not fit in the 24-bit
+/// SysTick reload register at the configured core frequency.
+///
 /// # Assumptions
 ///
 /// - The default core frequency is assumed to be 16 MHz unless a specific period
@@ -39,23 +69,51 @@ static G_SCHED_TICKS_TARGET: AtomicU32 = AtomicU32::new(0);
 ///   matches the actual system clock frequency for correct timer behavior.
 /// - Ensure that `Kernel::cortex_peripherals()` is properly set up before invoking this function.
 ///
-pub fn init_systick(p_period: Option<Milliseconds>) {
-    // Initialize Systick at 1ms
+pub fn init_systick(p_period: Option<Milliseconds>) -> KernelResult<()> {
+    let l_reload = match p_period {
+        Some(l_period) => {
+            if l_period.to_u32() == 0 {
+                return Err(KernelError::InvalidTimingConfig(
+                    "SysTick period cannot be 0",
+                ));
+            }
+            (Kernel::time_data().core_frequency.to_u32() as u64 / 1000) * l_period.to_u32() as u64
+        }
+        // The default core frequency is 16 MHz, so 1 ms is 16,000 ticks
+        None => 16_000,
+    };
+
+    if l_reload == 0 || l_reload > K_SYSTICK_MAX_RELOAD as u64 {
+        return Err(KernelError::InvalidTimingConfig(
+            "SysTick reload value does not fit in 24 bits",
+        ));
+    }
+
     let l_cortex_p = Kernel::cortex_peripherals();
     l_cortex_p.SYST.set_clock_source(SystClkSource::Core);
     l_cortex_p.SYST.clear_current();
-
-    if let Some(l_period) = p_period {
-        l_cortex_p
-            .SYST
-            .set_reload(Kernel::time_data().core_frequency.to_u32() * l_period.0 / 1000);
-    } else {
-        // The default core frequency is 16 MHz, so 1 ms is 16,000 ticks
-        l_cortex_p.SYST.set_reload(16_000);
-    }
+    l_cortex_p.SYST.set_reload(l_reload as u32);
 
     l_cortex_p.SYST.enable_interrupt();
     l_cortex_p.SYST.enable_counter();
+
+    G_SYSTICK_PERIOD_MS.store(p_period.map_or(1, |l_p| l_p.to_u32()), Ordering::Relaxed);
+    G_SYSTICK_RELOAD.store(l_reload as u32, Ordering::Relaxed);
+
+    Ok(())
+}
+
+/// Returns the time elapsed since the SysTick was started, in milliseconds.
+///
+/// The tick counter is read with a single atomic load, so the value is consistent even if a
+/// SysTick interrupt fires during the call.
+///
+/// # Returns
+/// The uptime in milliseconds. The counter wraps around to `0` after `2^32` ms (about 49.7
+/// days): durations must be computed with `wrapping_sub`, which stays correct across the
+/// wraparound for durations shorter than that.
+pub fn now_ms() -> u32 {
+    HAL_GetTick().wrapping_mul(G_SYSTICK_PERIOD_MS.load(Ordering::Relaxed))
 }
 
 /// Sets the target value for scheduling ticks.
@@ -73,16 +131,89 @@ pub fn set_ticks_target(p_target: u32) {
     G_SCHED_TICKS_TARGET.store(p_target, Ordering::Relaxed);
 }
 
+/// Configures the software watchdog checking that the scheduler keeps running.
+///
+/// The scheduler reports each completed cycle with [`watchdog_feed`]. If no cycle completes
+/// within the timeout, the SysTick handler panics, which prints the stall and resets the
+/// system. The check only starts after the first scheduler cycle, so boot time does not count.
+///
+/// A task legitimately running longer than the timeout (long busy-wait, blocking transfer)
+/// also trips the watchdog, as the scheduler cannot complete its cycle meanwhile. Long jobs
+/// are split over several cycles (e.g. the screenshot app sends a few rows per run), and
+/// blocking operations which cannot be split run through [`watchdog_suspended`].
+///
+/// # Parameters
+/// - `timeout`: Maximum delay between two scheduler cycles, or `None` to disable the check.
+///
+/// # Errors
+/// Returns [`KernelError::InvalidTimingConfig`] if the timeout is not longer than the
+/// scheduler period, as the watchdog would trip on every cycle.
+pub(crate) fn set_watchdog_timeout(
+    p_timeout: Option<Milliseconds>,
+    p_sched_period: Milliseconds,
+) -> KernelResult<()> {
+    let l_ticks = match p_timeout {
+        Some(l_timeout) => {
+            if l_timeout.to_u32() <= p_sched_period.to_u32() {
+                return Err(KernelError::InvalidTimingConfig(
+                    "Watchdog timeout must be longer than the scheduler period",
+                ));
+            }
+            (l_timeout.to_u32() / Kernel::time_data().systick_period.to_u32()).max(1)
+        }
+        None => 0,
+    };
+
+    G_WATCHDOG_TIMEOUT.store(l_ticks, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Disables the software watchdog, for states where the scheduler is stopped on purpose.
+pub(crate) fn watchdog_disable() {
+    G_WATCHDOG_TIMEOUT.store(0, Ordering::Relaxed);
+}
+
+/// Runs a blocking operation with the software watchdog suspended.
+///
+/// Meant for operations which cannot be split and may outlast the watchdog timeout, such as
+/// a flash sector erase (up to 2.6 s for the 256 KB configuration sector). The delay since the
+/// last scheduler cycle restarts when the operation returns, so the time it took does not
+/// count.
+///
+/// # Parameters
+/// - `operation`: The blocking operation.
+///
+/// # Returns
+/// The value returned by `operation`.
+pub(crate) fn watchdog_suspended<T>(p_operation: impl FnOnce() -> T) -> T {
+    let l_timeout = G_WATCHDOG_TIMEOUT.swap(0, Ordering::Relaxed);
+    let l_result = p_operation();
+    G_WATCHDOG_LAST_FEED.store(HAL_GetTick(), Ordering::Relaxed);
+    G_WATCHDOG_TIMEOUT.store(l_timeout, Ordering::Relaxed);
+    l_result
+}
+
+/// Records the completion of a scheduler cycle for the software watchdog.
+pub(crate) fn watchdog_feed() {
+    G_WATCHDOG_LAST_FEED.store(HAL_GetTick(), Ordering::Relaxed);
+    G_WATCHDOG_ARMED.store(true, Ordering::Relaxed);
+}
+
 /// Handles the SysTick exception (system timer interrupt).
 ///
 /// This function is executed whenever the SysTick interrupt occurs, typically at regular
-/// intervals to manage system timing and scheduling. Within the handler:
+/// intervals to manage system timing and scheduling. The entry delay is measured first (see
+/// [`max_isr_latency_cycles`]), then within the handler:
 ///
 /// 1. It checks whether a rescheduling event should be triggered based on the system's
 ///    scheduling tick configuration (`SCHED_TICKS_TARGET`).
 /// 2. If the current system tick matches the target interval, a PendSV interrupt is triggered
 ///    by calling `SCB::set_pendsv()` to handle any context switch for task scheduling.
 /// 3. Independently of scheduling, the system tick counter is incremented by calling `HAL_IncTick()`.
+/// 4. If the software watchdog is enabled (see [`set_watchdog_timeout`]) and the scheduler has
+///    not completed a cycle within the timeout, the handler panics. SysTick preempts the
+///    PendSV handler running the tasks, so a hung task cannot block the check; the panic
+///    handler then prints the stall and resets the system.
 ///
 /// # Globals:
 /// - `SCHED_TICKS_TARGET`: A globally stored value used to define the target tick interval at
@@ -106,8 +237,11 @@ pub fn set_ticks_target(p_target: u32) {
 /// # Notes:
 /// - This function is part of the exception handling mechanism and should always remain
 ///   minimal in execution to avoid delaying other system-critical interrupts.
-#[exception]
+#[cfg_attr(not(test), exception)]
+#[cfg_attr(test, allow(dead_code, non_snake_case))]
 fn SysTick() {
+    measure_latency();
+
     if G_SCHED_TICKS_TARGET.load(Ordering::Relaxed) != 0
         && HAL_GetTick() % G_SCHED_TICKS_TARGET.load(Ordering::Relaxed) == 0
     {
@@ -115,6 +249,78 @@ fn SysTick() {
     }
 
     HAL_IncTick();
+
+    // Software watchdog, disarmed before panicking so it trips only once
+    let l_timeout = G_WATCHDOG_TIMEOUT.load(Ordering::Relaxed);
+    if l_timeout != 0
+        && G_WATCHDOG_ARMED.load(Ordering::Relaxed)
+        && HAL_GetTick().wrapping_sub(G_WATCHDOG_LAST_FEED.load(Ordering::Relaxed)) > l_timeout
+    {
+        G_WATCHDOG_ARMED.store(false, Ordering::Relaxed);
+        record_reset_reason(ResetReason::Watchdog);
+        panic!(
+            "Watchdog: scheduler stalled for more than {} ticks",
+            l_timeout
+        );
+    }
+}
+
+/// Measures the delay between the expected and the actual SysTick handler entry.
+///
+/// The SysTick fires every `reload + 1` core cycles, so each entry is expected one period
+/// after the previous expected entry. The DWT cycle counter gives the actual entry time, the
+/// difference is the time the interrupt waited for masked sections or higher priority
+/// handlers. The first entry is taken as reference, and an entry earlier than expected
+/// becomes the new reference: the result is the jitter relative to the fastest entry seen,
+/// including the constant exception entry time.
+///
+/// Nothing is measured until the DWT cycle counter is enabled by the scheduler start. When a
+/// tick is missed (delay of a full period or more), the delay is recorded and the reference
+/// restarts from this entry.
+#[inline(always)]
+fn measure_latency() {
+    if !DWT::cycle_counter_enabled() {
+        return;
+    }
+
+    let l_now = DWT::cycle_count();
+    let l_period = G_SYSTICK_RELOAD.load(Ordering::Relaxed).wrapping_add(1);
+    let l_next = if G_LATENCY_ARMED.load(Ordering::Relaxed) {
+        let l_late = l_now.wrapping_sub(G_LATENCY_NEXT_TICK.load(Ordering::Relaxed)) as i32;
+        if l_late < 0 {
+            l_now
+        } else {
+            G_LATENCY_MAX.fetch_max(l_late as u32, Ordering::Relaxed);
+            if l_late as u32 >= l_period {
+                l_now
+            } else {
+                G_LATENCY_NEXT_TICK.load(Ordering::Relaxed)
+            }
+        }
+    } else {
+        G_LATENCY_ARMED.store(true, Ordering::Relaxed);
+        l_now
+    };
+    G_LATENCY_NEXT_TICK.store(l_next.wrapping_add(l_period), Ordering::Relaxed);
+}
+
+/// Returns the worst SysTick interrupt latency measured since startup or the last reset.
+///
+/// This measures the SysTick handler jitter specifically, as described in
+/// [`measure_latency`]: the delay of other interrupts is not observed, although masked
+/// sections and handlers delaying the SysTick are. The measurement relies on the DWT cycle
+/// counter, which is enabled when the scheduler starts.
+///
+/// # Returns
+/// The latency in core clock cycles, `0` while nothing has been measured.
+pub fn max_isr_latency_cycles() -> u32 {
+    G_LATENCY_MAX.load(Ordering::Relaxed)
+}
+
+/// Restarts the latency measurement, see [`max_isr_latency_cycles`].
+pub(crate) fn reset_isr_latency() {
+    G_LATENCY_ARMED.store(false, Ordering::Relaxed);
+    G_LATENCY_MAX.store(0, Ordering::Relaxed);
 }
 
 /// Increments the system tick counter.
@@ -229,7 +435,8 @@ pub extern "C" fn HAL_Delay(mut p_ms: u32) {
 /// - This function is designed to work in tandem with an embedded operating system kernel.
 /// - Proper setup of the PendSV exception and system configuration is required for this
 ///   function to be effective.
-#[exception]
+#[cfg_attr(not(test), exception)]
+#[cfg_attr(test, allow(dead_code, non_snake_case))]
 fn PendSV() {
     Kernel::scheduler().periodic_task();
 }
@@ -9,20 +9,266 @@
 //!
 //! A HAL callback (`terminal_prompt_callback`) is registered in prompt mode so
 //! that incoming bytes are read from the interface and forwarded to
-//! [`Terminal::process_input`].
+//! [`Terminal::process_input`]. Additional input interfaces (e.g. a keypad) can be
+//! registered with [`Terminal::register_input_source`]: their bytes feed the same
+//! line buffer as the USART ones.
+//!
+//! Input sources may report key releases: a byte with [`K_KEY_RELEASE_FLAG`] set releases
+//! the key given by its lower bits, any other byte is a key press. A key held down for
+//! [`K_KEY_REPEAT_DELAY`] is repeated every [`K_KEY_REPEAT_PERIOD`] by a kernel task until
+//! it is released or another key is pressed. Carriage return is never repeated. The USART
+//! sends one byte per key and never triggers auto-repeat.
+//!
+//! Printable characters typed at the prompt go through an input filter (see
+//! [`Terminal::set_input_filter`]) before being echoed and stored: rejected characters are
+//! dropped. Control characters are never filtered, so line submission keeps working.
+//!
+//! The prompt line can be edited anywhere: characters are inserted at the cursor, which is
+//! moved with the left/right arrows (`ESC [ D`, `ESC [ C`) and Home/End (`ESC [ H`,
+//! `ESC [ F`, and the `ESC O` and `ESC [ n ~` variants). Backspace and Delete (`ESC [ 3 ~`)
+//! remove the character before or under the cursor. Escape sequences may be split over
+//! several input callbacks. The echo only uses backspaces to move the cursor back, so it works
+//! on any serial terminal.
+//!
+//! Once the scheduler runs, the USART output is buffered (see [`Terminal::set_tx_buffered`]):
+//! writes are queued and a kernel task sends them, so printing does not wait for the serial
+//! line.
+//!
+//! A command can page its output on the display mirror (see [`Terminal::set_pager`]): after
+//! a screenful of lines, `-- more --` is shown and the following mirror output is held until
+//! a key is pressed. The serial output is never paged, as the host terminal can scroll.
+//!
+//! Commands can be given short aliases (see [`Terminal::add_alias`]). When the first
+//! token of a command line matches an alias, it is replaced by the alias target before
+//! the app is started. Registered app names always take precedence over aliases.
+//!
+//! When the command does not exist, the registered app sharing the longest prefix with it
+//! is suggested, provided they share at least [`K_SUGGESTION_MIN_PREFIX`] characters.
 
 use crate::KernelError::TerminalError;
 use crate::KernelErrorLevel::Error;
 
-use crate::console_output::{ConsoleFormatting, ConsoleOutput};
+use crate::caret::{K_CARET_APP_NAME, K_CARET_PERIOD, caret_blink, hide_caret};
+use crate::console_output::{ConsoleFormatting, ConsoleOutput, ConsoleOutputType};
 use crate::data::Kernel;
 use crate::ident::K_KERNEL_MASTER_ID;
+use crate::scheduler::AppCall;
+use crate::screen_saver::notify_activity;
 use crate::terminal::TerminalState::{Display, Prompt};
-use crate::{KernelResult, SysCallHalActions, syscall_hal};
+use crate::{
+    KernelError, KernelResult, Milliseconds, SysCallHalActions, critical_section, now_ms,
+    syscall_hal,
+};
 
 use display::Colors;
 use hal_interface::{InterfaceReadAction, InterfaceReadResult, K_BUFFER_SIZE};
 use heapless::{String, Vec, format};
+use spin::Mutex;
+
+/// Name of the task sending the queued terminal output.
+const K_CONSOLE_TX_APP_NAME: &str = "CONSOLE_TX";
+
+/// Default prompt string displayed when waiting for user input.
+const K_DEFAULT_PROMPT: &str = ">";
+
+/// Maximum number of command aliases.
+pub const K_MAX_ALIASES: usize = 8;
+/// Maximum length of an alias name or target.
+pub const K_ALIAS_SIZE: usize = 32;
+
+/// Maximum number of additional input sources.
+pub const K_MAX_INPUT_SOURCES: usize = 4;
+
+/// Flag set in an input source byte to report the release of a key.
+pub const K_KEY_RELEASE_FLAG: u8 = 0x80;
+/// Time a key must be held before it starts repeating.
+pub const K_KEY_REPEAT_DELAY: Milliseconds = Milliseconds(500);
+/// Period of the repeats of a held key.
+pub const K_KEY_REPEAT_PERIOD: Milliseconds = Milliseconds(100);
+/// Name of the key repeat task in the scheduler.
+const K_KEY_REPEAT_APP_NAME: &str = "KEY_REPEAT";
+
+/// Size of the buffer holding the display mirror output while the pager is paused.
+pub const K_PAGER_BUFFER_SIZE: usize = 2048;
+/// Line shown on the display mirror when the pager is paused.
+const K_PAGER_PROMPT: &str = "-- more --";
+/// Spaces erasing [`K_PAGER_PROMPT`].
+const K_PAGER_PROMPT_CLEAR: &str = "          ";
+
+/// Prompt input filter, returns `true` if the printable character is accepted.
+pub type InputFilter = fn(u8) -> bool;
+
+/// Default input filter, accepting all printable ASCII characters.
+///
+/// # Parameters
+/// - `c`: Received byte.
+pub fn printable_filter(p_c: u8) -> bool {
+    p_c.is_ascii_graphic() || p_c == b' '
+}
+
+/// Numeric input filter, accepting only digits, the minus sign and the decimal point.
+///
+/// # Parameters
+/// - `c`: Received byte.
+pub fn numeric_filter(p_c: u8) -> bool {
+    p_c.is_ascii_digit() || p_c == b'-' || p_c == b'.'
+}
+
+/// Minimum number of leading characters shared with an unknown command to suggest an app.
+const K_SUGGESTION_MIN_PREFIX: usize = 2;
+
+/// Returns the candidate sharing the longest common prefix with a command name.
+///
+/// On ties, the first candidate is returned.
+///
+/// # Parameters
+/// - `name`: Command name which matched no app.
+/// - `candidates`: Names to choose from, the registered apps in registration order.
+///
+/// # Returns
+/// The closest candidate, or `None` if none shares at least [`K_SUGGESTION_MIN_PREFIX`]
+/// leading characters with `name`.
+fn closest_name<'a>(
+    p_name: &str,
+    p_candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let mut l_best = None;
+    let mut l_best_len = K_SUGGESTION_MIN_PREFIX - 1;
+
+    for l_candidate in p_candidates {
+        let l_len = l_candidate
+            .bytes()
+            .zip(p_name.bytes())
+            .take_while(|(l_a, l_b)| l_a == l_b)
+            .count();
+        if l_len > l_best_len {
+            l_best = Some(l_candidate);
+            l_best_len = l_len;
+        }
+    }
+
+    l_best
+}
+
+/// Inserts a character in a command line.
+///
+/// # Parameters
+/// - `line`: Command line, only holding ASCII characters.
+/// - `pos`: Insertion index, at most the line length.
+/// - `byte`: Character to insert.
+///
+/// # Returns
+/// The end of the line following the inserted character, or `None` if the character was
+/// dropped because the line is full or the character is not ASCII.
+fn insert_at(p_line: &mut String<256>, p_pos: usize, p_byte: u8) -> Option<String<256>> {
+    if p_line.len() == p_line.capacity() || !p_byte.is_ascii() {
+        return None;
+    }
+
+    // Room is available, the line only holds ASCII characters
+    let l_tail: String<256> = String::try_from(&p_line[p_pos..]).unwrap_or_default();
+    p_line.truncate(p_pos);
+    p_line.push(p_byte as char).unwrap_or(());
+    p_line.push_str(&l_tail).unwrap_or(());
+    Some(l_tail)
+}
+
+/// Substitutes the command name of a line with its alias target, if any.
+///
+/// # Parameters
+/// - `line`: Command line as typed by the user.
+/// - `aliases`: Defined `(alias, target)` entries.
+/// - `is_app`: Tells whether a name is a registered app, which takes precedence over aliases.
+///
+/// # Returns
+/// The command line to execute.
+///
+/// # Errors
+/// Returns [`TerminalError`] if the expanded line does not fit in the line buffer.
+fn expand_alias(
+    p_line: &str,
+    p_aliases: &[Alias],
+    p_is_app: impl Fn(&str) -> bool,
+) -> KernelResult<String<256>> {
+    let l_line = p_line.trim_start();
+    let l_name = l_line.split_ascii_whitespace().next().unwrap_or_default();
+    let l_target = match p_aliases.iter().find(|l_a| l_a.0 == l_name) {
+        Some(l_entry) if !p_is_app(l_name) => &l_entry.1,
+        _ => return Ok(String::try_from(p_line).unwrap_or_default()),
+    };
+
+    let mut l_command: String<256> = String::try_from(l_target.as_str()).unwrap_or_default();
+    l_command
+        .push_str(&l_line[l_name.len()..])
+        .map_err(|_| TerminalError(Error, "Line buffer overflow"))?;
+    Ok(l_command)
+}
+
+/// Escape character starting the control sequences sent by the arrow and editing keys.
+const K_ESC: u8 = 0x1B;
+/// Break character (Ctrl-C), stops the foreground app.
+const K_BREAK: u8 = 0x03;
+
+/// Handling of the input received while a foreground app is running.
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum BusyInput {
+    /// Nothing to do, the input is dropped.
+    Ignore,
+    /// A command was submitted, the user is told the terminal is busy.
+    Busy,
+    /// The break key was pressed, the foreground app is stopped.
+    Break,
+}
+
+/// Decides how the input received while a foreground app is running is handled.
+///
+/// The break key wins over a carriage return received in the same buffer. No input can start
+/// a command.
+///
+/// # Parameters
+/// - `buffer`: Bytes received from the input interface.
+///
+/// # Returns
+/// The [`BusyInput`] action for the buffer.
+fn busy_input(p_buffer: &[u8]) -> BusyInput {
+    if p_buffer.contains(&K_BREAK) {
+        BusyInput::Break
+    } else if p_buffer.contains(&b'\r') {
+        BusyInput::Busy
+    } else {
+        BusyInput::Ignore
+    }
+}
+
+/// Command alias entry, stored as `(alias, target)`.
+pub type Alias = (String<K_ALIAS_SIZE>, String<K_ALIAS_SIZE>);
+
+/// Pager state of the display mirror.
+struct Pager {
+    /// Paging requested by the foreground command.
+    enabled: bool,
+    /// Number of text rows of the display.
+    rows: u16,
+    /// Lines written since the start of the page.
+    lines: u16,
+    /// Waiting for a key press, the mirror output is held in `pending`.
+    paused: bool,
+    /// Mirror output held while paused, line breaks are stored as `'\n'`.
+    pending: String<K_PAGER_BUFFER_SIZE>,
+}
+
+/// Progress of an escape sequence received at the prompt.
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum EscapeState {
+    /// No sequence in progress.
+    Idle,
+    /// `ESC` received.
+    Escape,
+    /// `ESC [` received, with the numeric parameter received so far.
+    Csi(u8),
+    /// `ESC O` received.
+    Ss3,
+}
 
 #[derive(PartialEq, Clone, Copy, Debug)]
 enum TerminalState {
@@ -39,8 +285,21 @@ pub struct Terminal {
     line_buffer: String<256>,
     mode: TerminalState,
     cursor_pos: usize,
+    /// Insertion index in the line buffer, the cursor column is `cursor_pos`.
+    edit_pos: usize,
+    /// Escape sequence being received at the prompt.
+    escape: EscapeState,
     display_mirror: Option<ConsoleOutput>,
     app_exe_in_progress: Option<u32>,
+    prompt: &'static str,
+    aliases: Vec<Alias, K_MAX_ALIASES>,
+    input_sources: Vec<usize, K_MAX_INPUT_SOURCES>,
+    /// Key held down on an input source and the uptime in ms at which it was pressed.
+    held_key: Option<(u8, u32)>,
+    /// Filter applied to printable prompt characters.
+    input_filter: InputFilter,
+    /// Display mirror pager, shared with the input callback.
+    pager: Mutex<Pager>,
 }
 
 impl Terminal {
@@ -49,7 +308,9 @@ impl Terminal {
     /// This initializes the primary [`ConsoleOutput`] as a USART backend using
     /// the provided `name` and a default color of [`Colors::White`]. The terminal
     /// starts in the [`TerminalState::Stopped`] state with an empty line buffer,
-    /// cursor position at `0`, and no display mirror configured.
+    /// cursor and insertion positions at `0`, no display mirror configured, the default
+    /// prompt (`>`), no command alias, no additional input source and the
+    /// [`printable_filter`] input filter.
     ///
     /// # Parameters
     /// - `name`: Static name/identifier used by the HAL to select the USART interface.
@@ -59,15 +320,26 @@ impl Terminal {
     /// - `Err(_)` if creating the underlying [`ConsoleOutput`] fails.
     pub fn new(p_name: &'static str) -> KernelResult<Terminal> {
         Ok(Terminal {
-            output: ConsoleOutput::new(
-                crate::console_output::ConsoleOutputType::Usart(p_name),
-                Colors::White,
-            ),
+            output: ConsoleOutput::new(ConsoleOutputType::Usart(p_name), Colors::White),
             line_buffer: String::new(),
             mode: TerminalState::Stopped,
             cursor_pos: 0,
+            edit_pos: 0,
+            escape: EscapeState::Idle,
             display_mirror: None,
             app_exe_in_progress: None,
+            prompt: K_DEFAULT_PROMPT,
+            aliases: Vec::new(),
+            input_sources: Vec::new(),
+            held_key: None,
+            input_filter: printable_filter,
+            pager: Mutex::new(Pager {
+                enabled: false,
+                rows: 0,
+                lines: 0,
+                paused: false,
+                pending: String::new(),
+            }),
         })
     }
 
@@ -75,13 +347,16 @@ impl Terminal {
     ///
     /// When enabled (`display_mirror == true`) and no mirror exists yet, this
     /// function will create a secondary [`ConsoleOutput`] targeting the display
-    /// backend (`ConsoleOutputType::Display`) and store it in
-    /// [`Terminal::display_mirror`].
+    /// backend (`ConsoleOutputType::Display`), using the current terminal color,
+    /// and store it in [`Terminal::display_mirror`].
     ///
     /// When disabled (`display_mirror == false`) and a mirror is currently
     /// active, this function will release the mirror output and clear the stored
     /// handle.
     ///
+    /// The display caret is started or stopped accordingly, see
+    /// [`Terminal::update_caret_task`].
+    ///
     /// # Parameters
     /// - `display_mirror`: `true` to enable mirroring, `false` to disable it.
     ///
@@ -89,22 +364,46 @@ impl Terminal {
     /// - `Ok(())` on success.
     ///
     /// # Errors
+    /// - [`KernelError::DisplayNotConfigured`] when enabling while no display was
+    ///   initialized at boot.
     /// - Propagates any error produced by [`ConsoleOutput::new`] when enabling.
     /// - Propagates any error produced by [`ConsoleOutput::release`] when disabling.
     pub fn set_display_mirror(&mut self, p_display_mirror: bool) -> KernelResult<()> {
+        if p_display_mirror && !Kernel::display().is_initialized() {
+            return Err(KernelError::DisplayNotConfigured);
+        }
+
         if p_display_mirror && self.display_mirror.is_none() {
-            self.display_mirror = Some(ConsoleOutput::new(
-                crate::console_output::ConsoleOutputType::Display,
-                Colors::White,
-            ));
+            let mut l_mirror =
+                ConsoleOutput::new(ConsoleOutputType::Display, self.output.current_color);
+            l_mirror.tab_width = self.output.tab_width;
+            self.display_mirror = Some(l_mirror);
             self.display_mirror.as_mut().unwrap().initialize()?;
         } else if let Some(l_mirror) = self.display_mirror.as_mut()
             && !p_display_mirror
         {
             l_mirror.release()?;
             self.display_mirror = None;
+
+            // Held output has nowhere to go anymore
+            critical_section(|| {
+                let mut l_pager = self.pager.lock();
+                l_pager.enabled = false;
+                l_pager.paused = false;
+                l_pager.pending.clear();
+            });
         }
-        Ok(())
+        self.update_caret_task()
+    }
+
+    /// Returns `true` if the terminal is in prompt mode.
+    pub(crate) fn is_prompt_mode(&self) -> bool {
+        self.mode == Prompt
+    }
+
+    /// Returns `true` if the terminal output is mirrored on the display.
+    pub fn is_display_mirror(&self) -> bool {
+        self.display_mirror.is_some()
     }
 
     /// Switch the terminal into prompt mode.
@@ -114,7 +413,7 @@ impl Terminal {
     /// - Registers the HAL callback [`terminal_prompt_callback`] so incoming bytes
     ///   are forwarded to [`Terminal::process_input`].
     /// - If transitioning from another mode, resets the cursor state and prints a
-    ///   new prompt (`>`).
+    ///   new prompt (see [`Terminal::set_prompt`]).
     ///
     /// # Returns
     /// - `Ok(())` on success.
@@ -138,12 +437,11 @@ impl Terminal {
         // Set mode to prompt
         if self.mode != Prompt {
             self.mode = Prompt;
-            self.cursor_pos = 0;
             self.output.new_line()?;
-            self.output.write_char('>')?;
+            self.write_prompt()?;
         }
 
-        Ok(())
+        self.update_caret_task()
     }
 
     /// Switch the terminal into display mode.
@@ -172,6 +470,39 @@ impl Terminal {
             self.mode = Display;
         }
 
+        self.update_caret_task()
+    }
+
+    /// Start or stop the blinking caret of the display mirror.
+    ///
+    /// The caret task runs only while the terminal is in prompt mode with a display mirror
+    /// enabled. It is called on every mode or mirror change; when stopping the task, the
+    /// caret is hidden first so the display content is left unchanged.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    ///
+    /// # Errors
+    /// Propagates errors from the scheduler when adding or removing the task, or from the
+    /// display when hiding the caret.
+    fn update_caret_task(&self) -> KernelResult<()> {
+        let l_required = self.mode == Prompt && self.display_mirror.is_some();
+        let l_running = Kernel::scheduler().app_exists(K_CARET_APP_NAME).is_some();
+
+        if l_required && !l_running {
+            Kernel::scheduler().add_periodic_app(
+                K_CARET_APP_NAME,
+                AppCall::App(caret_blink),
+                None,
+                K_CARET_PERIOD,
+                None,
+                false,
+            )?;
+        } else if !l_required && l_running {
+            hide_caret()?;
+            Kernel::scheduler().remove_periodic_app(K_CARET_APP_NAME)?;
+        }
+
         Ok(())
     }
 
@@ -214,6 +545,44 @@ impl Terminal {
         }
 
         if let Some(l_mirror) = self.display_mirror.as_ref() {
+            // The cursor may move away from the caret cell
+            hide_caret()?;
+
+            let l_paged = critical_section(|| {
+                let l_pager = self.pager.lock();
+                l_pager.enabled || l_pager.paused
+            });
+            if l_paged {
+                let mut l_char = [0; 4];
+                return match p_format {
+                    ConsoleFormatting::StrNoFormatting(l_text) => {
+                        self.write_paged(l_mirror, &[l_text])
+                    }
+                    ConsoleFormatting::StrNewLineAfter(l_text) => {
+                        self.write_paged(l_mirror, &[l_text, "\n"])
+                    }
+                    ConsoleFormatting::StrNewLineBefore(l_text) => {
+                        self.write_paged(l_mirror, &["\n", l_text])
+                    }
+                    ConsoleFormatting::StrNewLineBoth(l_text) => {
+                        self.write_paged(l_mirror, &["\n", l_text, "\n"])
+                    }
+                    ConsoleFormatting::Newline => self.write_paged(l_mirror, &["\n"]),
+                    ConsoleFormatting::Char(l_c) => {
+                        self.write_paged(l_mirror, &[l_c.encode_utf8(&mut l_char)])
+                    }
+                    ConsoleFormatting::Clear => {
+                        critical_section(|| {
+                            let mut l_pager = self.pager.lock();
+                            l_pager.lines = 0;
+                            l_pager.paused = false;
+                            l_pager.pending.clear();
+                        });
+                        l_mirror.clear_terminal()
+                    }
+                };
+            }
+
             match p_format {
                 ConsoleFormatting::StrNoFormatting(l_text) => l_mirror.write_str(l_text)?,
                 ConsoleFormatting::StrNewLineAfter(l_text) => {
@@ -238,6 +607,182 @@ impl Terminal {
         Ok(())
     }
 
+    /// Write text to the display mirror through the pager.
+    ///
+    /// Lines are counted as they are written. When the page is full, [`K_PAGER_PROMPT`] is
+    /// shown and the remaining text is held until a key is pressed (see
+    /// [`Terminal::resume_pager`]). Text which does not fit in [`K_PAGER_BUFFER_SIZE`] while
+    /// paused is dropped.
+    ///
+    /// # Parameters
+    /// - `mirror`: The display mirror output.
+    /// - `segments`: Text to write, `'\n'` marks a line break.
+    ///
+    /// # Errors
+    /// Propagates any error returned by the mirror output.
+    fn write_paged(&self, p_mirror: &ConsoleOutput, p_segments: &[&str]) -> KernelResult<()> {
+        critical_section(|| {
+            let mut l_pager = self.pager.lock();
+            for l_piece in p_segments
+                .iter()
+                .flat_map(|l_segment| l_segment.split_inclusive('\n'))
+            {
+                if l_pager.paused {
+                    l_pager.pending.push_str(l_piece).unwrap_or(());
+                    continue;
+                }
+
+                let l_line = l_piece.strip_suffix('\n');
+                p_mirror.write_str(l_line.unwrap_or(l_piece))?;
+                if l_line.is_some() {
+                    p_mirror.new_line()?;
+                    l_pager.lines += 1;
+
+                    // Keep the last row for the pager prompt
+                    if l_pager.enabled && l_pager.lines + 1 >= l_pager.rows {
+                        p_mirror.write_str(K_PAGER_PROMPT)?;
+                        l_pager.paused = true;
+                    }
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Show the next page of the held display mirror output.
+    ///
+    /// The pager prompt is erased and the held output is written again through the pager,
+    /// so it pauses again if it exceeds a page.
+    ///
+    /// # Errors
+    /// Propagates any error returned by the mirror output.
+    fn resume_pager(&self) -> KernelResult<()> {
+        let l_pending = critical_section(|| {
+            let mut l_pager = self.pager.lock();
+            l_pager.paused = false;
+            l_pager.lines = 0;
+            core::mem::take(&mut l_pager.pending)
+        });
+
+        let Some(l_mirror) = self.display_mirror.as_ref() else {
+            return Ok(());
+        };
+        hide_caret()?;
+        l_mirror.write_char('\r')?;
+        l_mirror.write_str(K_PAGER_PROMPT_CLEAR)?;
+        l_mirror.write_char('\r')?;
+        self.write_paged(l_mirror, &[l_pending.as_str()])
+    }
+
+    /// Enable or disable the pager of the display mirror.
+    ///
+    /// Meant for commands printing more lines than the display can show: the command enables
+    /// the pager before printing, and it is disabled when the foreground command exits (see
+    /// [`Terminal::app_exit_notifier`]). While enabled, the mirror pauses after a screenful of
+    /// lines, counted from this call, until a key is pressed. Output held when the pager is
+    /// disabled is still shown page by page. Has no effect without display mirror.
+    ///
+    /// # Parameters
+    /// - `enabled`: `true` to page the display mirror output.
+    ///
+    /// # Errors
+    /// Returns [`KernelError::DisplayError`] if the display size cannot be read.
+    pub(crate) fn set_pager(&self, p_enabled: bool) -> KernelResult<()> {
+        let l_rows = if p_enabled && self.display_mirror.is_some() {
+            let (_, l_height) = Kernel::display()
+                .get_size()
+                .map_err(KernelError::DisplayError)?;
+            let (_, l_char_height) = Kernel::display().get_char_cell();
+            Some(l_height / l_char_height)
+        } else {
+            None
+        };
+
+        critical_section(|| {
+            let mut l_pager = self.pager.lock();
+            match l_rows {
+                // A single row leaves no room for the text
+                Some(l_rows) if l_rows > 1 => {
+                    l_pager.enabled = true;
+                    l_pager.rows = l_rows;
+                    l_pager.lines = 0;
+                }
+                _ => l_pager.enabled = false,
+            }
+        });
+        Ok(())
+    }
+
+    /// Write raw bytes to the terminal, bypassing [`ConsoleFormatting`].
+    ///
+    /// The bytes are forwarded unchanged to the primary output, which allows apps to emit
+    /// terminal control sequences. If the display mirror is enabled, it receives the printable
+    /// part of the bytes only (see [`ConsoleOutput::write_raw`]).
+    ///
+    /// # Parameters
+    /// - `bytes`: The bytes to write.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the bytes were written to all outputs.
+    ///
+    /// # Errors
+    /// Propagates any error returned by the underlying console outputs.
+    pub fn write_raw(&self, p_bytes: &[u8]) -> KernelResult<()> {
+        self.output.write_raw(p_bytes)?;
+        if let Some(l_mirror) = self.display_mirror.as_ref() {
+            hide_caret()?;
+            l_mirror.write_raw(p_bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Write raw bytes to the primary output only, leaving the display mirror untouched.
+    ///
+    /// Used to stream binary data, which must neither be rendered on the display nor depend
+    /// on the display content.
+    ///
+    /// # Parameters
+    /// - `bytes`: The bytes to write.
+    ///
+    /// # Errors
+    /// Propagates any error returned by the primary console output.
+    pub(crate) fn write_raw_output(&self, p_bytes: &[u8]) -> KernelResult<()> {
+        self.output.write_raw(p_bytes)
+    }
+
+    /// Best-effort write of a panic message, for use from the panic handler only.
+    ///
+    /// Nothing is written if the terminal was never started. The message is written in red to
+    /// the primary output and to the display mirror, if enabled. The caret is left as is, as
+    /// its state may be locked by the panicking code. All errors are ignored: the write may
+    /// fail or be truncated if the HAL or the display is in an inconsistent state.
+    ///
+    /// # Parameters
+    /// - `lines`: Lines of the message, each one written on a new line.
+    pub(crate) fn write_panic(&mut self, p_lines: &[&str]) {
+        if self.mode == TerminalState::Stopped {
+            return;
+        }
+
+        // Write directly, the queue may never be drained again
+        self.output.set_tx_buffered(false).unwrap_or(());
+        self.set_color(Colors::Red).unwrap_or(());
+        for l_line in p_lines {
+            self.output.new_line().unwrap_or(());
+            self.output.write_str(l_line).unwrap_or(());
+        }
+        self.output.new_line().unwrap_or(());
+
+        if let Some(l_mirror) = self.display_mirror.as_ref() {
+            for l_line in p_lines {
+                l_mirror.new_line().unwrap_or(());
+                l_mirror.write_str(l_line).unwrap_or(());
+            }
+            l_mirror.new_line().unwrap_or(());
+            Kernel::display().flush().unwrap_or(());
+        }
+    }
+
     /// Set the current output color for the terminal.
     ///
     /// This updates the `current_color` of the primary [`ConsoleOutput`] used by
@@ -254,23 +799,96 @@ impl Terminal {
     /// Propagates any error returned by the underlying console output when
     /// applying the color change.
     pub fn set_color(&mut self, p_color: Colors) -> KernelResult<()> {
+        self.output.current_color = p_color;
         if let Some(l_mirror) = self.display_mirror.as_mut() {
             l_mirror.current_color = p_color;
         }
         Ok(())
     }
 
+    /// Enable or disable the transmit queue of the terminal output.
+    ///
+    /// When enabled, writes to the USART are queued and a kernel task running every scheduler
+    /// cycle sends them, see [`ConsoleOutput::set_tx_buffered`]. When disabled, the queue is
+    /// flushed and the task is stopped. The scheduler must be started before enabling.
+    ///
+    /// # Parameters
+    /// - `buffered`: `true` to queue the terminal output.
+    ///
+    /// # Errors
+    /// Propagates errors from the scheduler when adding or removing the task, or from the
+    /// output when flushing the queue.
+    pub(crate) fn set_tx_buffered(&self, p_buffered: bool) -> KernelResult<()> {
+        let l_running = Kernel::scheduler()
+            .app_exists(K_CONSOLE_TX_APP_NAME)
+            .is_some();
+
+        if p_buffered && !l_running {
+            Kernel::scheduler().add_periodic_app(
+                K_CONSOLE_TX_APP_NAME,
+                AppCall::App(console_tx),
+                None,
+                Kernel::scheduler().get_period(),
+                None,
+                false,
+            )?;
+            self.output.set_tx_buffered(true)
+        } else if !p_buffered && l_running {
+            self.output.set_tx_buffered(false)?;
+            Kernel::scheduler().remove_periodic_app(K_CONSOLE_TX_APP_NAME)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Send all queued terminal output, blocking until it is out.
+    ///
+    /// To be called before critical actions such as a reset, which would lose the queue.
+    ///
+    /// # Errors
+    /// Propagates errors from [`ConsoleOutput::flush`].
+    pub(crate) fn flush(&self) -> KernelResult<()> {
+        self.output.flush()
+    }
+
+    /// Set the number of columns between two tab stops.
+    ///
+    /// Tab characters written to the terminal are expanded to spaces up to the next multiple
+    /// of `width`, counted from the last `'\r'` or `'\n'`. The display mirror, if enabled, uses
+    /// the same width. A width of `0` disables expansion and sends tabs unchanged.
+    ///
+    /// # Parameters
+    /// - `width`: Tab width in columns (default [`crate::console_output::K_DEFAULT_TAB_WIDTH`]).
+    pub fn set_tab_width(&mut self, p_width: u8) {
+        self.output.tab_width = p_width;
+        if let Some(l_mirror) = self.display_mirror.as_mut() {
+            l_mirror.tab_width = p_width;
+        }
+    }
+
     /// Process a buffer of input bytes received from the terminal interface.
     ///
-    /// In [`TerminalState::Prompt`] mode, this function implements a simple line
-    /// editor:
-    /// - Non-`'\r'` bytes are echoed to the terminal and appended to the internal
-    ///   line buffer.
+    /// In [`TerminalState::Prompt`] mode, the bytes feed a line editor:
+    /// - Printable bytes are inserted at the cursor and echoed, the end of the line is
+    ///   redrawn after them. Bytes rejected by the input filter (see
+    ///   [`Terminal::set_input_filter`]) are dropped and the terminal bell is sent instead on
+    ///   serial outputs. Once the line buffer is full, further bytes are dropped and the
+    ///   terminal bell (`'\x07'`) is sent instead on serial outputs, until the line is
+    ///   submitted.
+    /// - Backspace (`'\x08'` or `'\x7F'`) and Delete remove the character before or under
+    ///   the cursor. The arrow and Home/End keys move the cursor within the line. Other
+    ///   escape sequences and control characters are ignored.
     /// - On carriage return (`'\r'`), the accumulated line is treated as an
     ///   application command and is started via [`Kernel::apps().start_app`]. If
     ///   the application starts successfully, the terminal device is locked to
     ///   that application.
     ///
+    /// Only one foreground application runs at a time: while a command started from the
+    /// prompt is running, input bytes are dropped without echo, and a carriage return prints
+    /// a busy message instead of starting another application. Ctrl-C (`'\x03'`) stops the
+    /// application, which is the only way out of a periodic application. The prompt is
+    /// available again once the application exits (see [`Terminal::app_exit_notifier`]).
+    ///
     /// In other terminal modes, the input is ignored.
     ///
     /// # Parameters
@@ -281,66 +899,517 @@ impl Terminal {
     /// - `Ok(())` on success.
     ///
     /// # Errors
-    /// - Returns a terminal error if the internal line buffer overflows.
     /// - Propagates any I/O error from writing to the underlying console output.
     /// - Propagates any error from locking the terminal device after starting an app.
     pub fn process_input(&mut self, p_buffer: Vec<u8, K_BUFFER_SIZE>) -> KernelResult<()> {
         // If the terminal is in prompt mode
         if self.mode == Prompt {
-            // If the received character is a return character, process the line
-            if p_buffer[0] == '\r' as u8 {
-                // If the line buffer is not empty
-                if self.line_buffer.len() > 1 {
-                    // Start the requested command
-                    match Kernel::apps().start_app(&self.line_buffer) {
-                        Ok(l_app_id) => {
-                            self.app_exe_in_progress = Some(l_app_id);
-                            // Lock terminal for this app
-                            Kernel::devices().lock(crate::DeviceType::Terminal, l_app_id)?;
+            notify_activity()?;
+
+            // A key press shows the next page of the display mirror
+            if critical_section(|| self.pager.lock().paused) {
+                return self.resume_pager();
+            }
+
+            // A foreground app is running, no command can be started until it exits or is
+            // stopped with the break key
+            if let Some(l_app_id) = self.app_exe_in_progress {
+                match busy_input(&p_buffer) {
+                    BusyInput::Break => {
+                        self.output.write_str("^C")?;
+                        // The exit notifier prints the prompt back
+                        critical_section(|| Kernel::apps().stop_app(l_app_id))?;
+                    }
+                    BusyInput::Busy => {
+                        let l_name = Kernel::apps()
+                            .get_app_name_by_id(l_app_id)
+                            .unwrap_or_default();
+                        self.output.write_str(
+                            format!(64; "\r\nBusy: {} is running, Ctrl-C to stop", l_name)
+                                .unwrap()
+                                .as_str(),
+                        )?;
+                        self.output.new_line()?;
+                    }
+                    BusyInput::Ignore => {}
+                }
+                return Ok(());
+            }
+
+            for l_byte in p_buffer {
+                self.process_byte(l_byte)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Process one input byte at the prompt.
+    ///
+    /// # Parameters
+    /// - `byte`: Received byte.
+    ///
+    /// # Errors
+    /// Propagates errors from the line editing functions.
+    fn process_byte(&mut self, p_byte: u8) -> KernelResult<()> {
+        match self.escape {
+            EscapeState::Idle => {}
+            EscapeState::Escape => {
+                self.escape = match p_byte {
+                    b'[' => EscapeState::Csi(0),
+                    b'O' => EscapeState::Ss3,
+                    _ => EscapeState::Idle,
+                };
+                // A lone escape does not start a sequence, the byte is a regular key
+                if self.escape != EscapeState::Idle {
+                    return Ok(());
+                }
+            }
+            EscapeState::Csi(l_param) => {
+                return match p_byte {
+                    b'0'..=b'9' => {
+                        self.escape = EscapeState::Csi(
+                            l_param.saturating_mul(10).saturating_add(p_byte - b'0'),
+                        );
+                        Ok(())
+                    }
+                    // Final byte of the sequence
+                    0x40..=0x7E => {
+                        self.escape = EscapeState::Idle;
+                        match (p_byte, l_param) {
+                            (b'C', _) => self.move_cursor_to(self.edit_pos + 1),
+                            (b'D', _) => self.move_cursor_to(self.edit_pos.saturating_sub(1)),
+                            (b'H', _) | (b'~', 1 | 7) => self.move_cursor_to(0),
+                            (b'F', _) | (b'~', 4 | 8) => {
+                                self.move_cursor_to(self.line_buffer.len())
+                            }
+                            (b'~', 3) => self.delete_char(),
+                            _ => Ok(()),
                         }
-                        Err(l_err) => {
+                    }
+                    // Parameter separators and intermediate bytes are not used
+                    _ => Ok(()),
+                };
+            }
+            EscapeState::Ss3 => {
+                self.escape = EscapeState::Idle;
+                return match p_byte {
+                    b'H' => self.move_cursor_to(0),
+                    b'F' => self.move_cursor_to(self.line_buffer.len()),
+                    _ => Ok(()),
+                };
+            }
+        }
+
+        match p_byte {
+            K_ESC => {
+                self.escape = EscapeState::Escape;
+                Ok(())
+            }
+            b'\r' => self.submit_line(),
+            b'\x08' | b'\x7F' => {
+                if self.edit_pos == 0 {
+                    return Ok(());
+                }
+                self.move_cursor_to(self.edit_pos - 1)?;
+                self.delete_char()
+            }
+            _ if p_byte.is_ascii_control() => Ok(()),
+            _ => self.insert_char(p_byte),
+        }
+    }
+
+    /// Start the command typed at the prompt, then clear the line.
+    ///
+    /// # Errors
+    /// Propagates errors from writing to the output and from locking the terminal device for
+    /// the started app.
+    fn submit_line(&mut self) -> KernelResult<()> {
+        // If the line buffer is not blank
+        if !self.line_buffer.trim().is_empty() {
+            // Start the requested command, the apps table and terminal lock are
+            // shared with the scheduler so they are updated with interrupts masked
+            match self.resolve_alias(&self.line_buffer).and_then(|l_command| {
+                critical_section(|| {
+                    Kernel::apps().start_app(&l_command).map(|l_app_id| {
+                        self.app_exe_in_progress = Some(l_app_id);
+                        // Lock terminal for this app
+                        Kernel::devices().lock(crate::DeviceType::Terminal, l_app_id)
+                    })
+                })
+            }) {
+                Ok(l_lock_result) => l_lock_result?,
+                Err(l_err) => {
+                    self.output
+                        .write_str(format!(256;"\r\n{}",l_err.to_string()).unwrap().as_str())?;
+                    if let KernelError::AppNotFound = l_err {
+                        let l_command = self.resolve_alias(&self.line_buffer).unwrap_or_default();
+                        let l_name = l_command
+                            .split_ascii_whitespace()
+                            .next()
+                            .unwrap_or_default();
+                        if let Some(l_suggestion) = closest_name(l_name, Kernel::apps().list_apps())
+                        {
                             self.output.write_str(
-                                format!(256;"\r\n{}",l_err.to_string()).unwrap().as_str(),
+                                format!(64; "\r\nDid you mean {}?", l_suggestion)
+                                    .unwrap()
+                                    .as_str(),
                             )?;
-                            self.cursor_pos = 0;
-                            self.output.new_line()?;
-                            self.output.new_line()?;
-                            self.output.write_char('>')?;
                         }
-                    };
-                } else {
-                    self.cursor_pos = 0;
+                    }
                     self.output.new_line()?;
-                    self.output.write_char('>')?;
+                    self.output.new_line()?;
+                    self.write_prompt()?;
                 }
-                self.line_buffer.clear();
-            } else {
-                // Echo the received character
-                self.output.write_char(p_buffer[0] as char)?;
-
-                // Store it into the line buffer
-                self.line_buffer
-                    .push(p_buffer[0] as char)
-                    .map_err(|_| TerminalError(Error, "Line buffer overflow"))?;
-                self.cursor_pos += 1;
+            };
+        } else {
+            self.output.new_line()?;
+            self.write_prompt()?;
+        }
+        self.clear_line();
+        Ok(())
+    }
+
+    /// Insert a printable character at the cursor and redraw the end of the line.
+    ///
+    /// Characters rejected by the input filter, non-ASCII bytes and characters typed while
+    /// the line buffer is full are dropped, the bell is rung instead on serial outputs.
+    ///
+    /// # Parameters
+    /// - `byte`: Character to insert.
+    ///
+    /// # Errors
+    /// Propagates errors from writing to the output.
+    fn insert_char(&mut self, p_byte: u8) -> KernelResult<()> {
+        let l_tail = if (self.input_filter)(p_byte) {
+            insert_at(&mut self.line_buffer, self.edit_pos, p_byte)
+        } else {
+            None
+        };
+        let Some(l_tail) = l_tail else {
+            // Drop the character and ring the bell (serial only, the display has no glyph for it)
+            if let ConsoleOutputType::Usart(_) = self.output.output {
+                self.output.write_char('\x07')?;
             }
+            return Ok(());
+        };
+
+        // Echo the character and the shifted end of the line, then go back after the character
+        self.output.write_char(p_byte as char)?;
+        self.output.write_str(&l_tail)?;
+        self.edit_pos += 1;
+        self.cursor_pos = self.prompt.len() + self.line_buffer.len();
+        self.move_cursor_to(self.edit_pos)
+    }
+
+    /// Remove the character under the cursor and redraw the end of the line.
+    ///
+    /// Nothing is done at the end of the line.
+    ///
+    /// # Errors
+    /// Propagates errors from writing to the output.
+    fn delete_char(&mut self) -> KernelResult<()> {
+        if self.edit_pos >= self.line_buffer.len() {
+            return Ok(());
         }
 
+        let l_tail: String<256> =
+            String::try_from(&self.line_buffer[self.edit_pos + 1..]).unwrap_or_default();
+        self.line_buffer.truncate(self.edit_pos);
+        self.line_buffer.push_str(&l_tail).unwrap_or(());
+
+        // Shift the end of the line left and blank its last column
+        self.output.write_str(&l_tail)?;
+        self.output.write_char(' ')?;
+        self.cursor_pos = self.prompt.len() + self.line_buffer.len() + 1;
+        self.move_cursor_to(self.edit_pos)
+    }
+
+    /// Move the cursor to an insertion index of the line.
+    ///
+    /// The cursor goes back with backspaces and forward by writing the line characters
+    /// again. The index is clamped to the line length.
+    ///
+    /// # Parameters
+    /// - `pos`: Insertion index, from `0` (start of the line) to the line length.
+    ///
+    /// # Errors
+    /// Propagates errors from writing to the output.
+    fn move_cursor_to(&mut self, p_pos: usize) -> KernelResult<()> {
+        let l_target = self.prompt.len() + p_pos.min(self.line_buffer.len());
+        if l_target > self.cursor_pos {
+            let l_from = self.cursor_pos - self.prompt.len();
+            self.output
+                .write_str(&self.line_buffer[l_from..l_target - self.prompt.len()])?;
+        } else {
+            for _ in l_target..self.cursor_pos {
+                self.output.write_char('\x08')?;
+            }
+        }
+        self.cursor_pos = l_target;
+        self.edit_pos = l_target - self.prompt.len();
         Ok(())
     }
 
+    /// Clear the line being edited.
+    fn clear_line(&mut self) {
+        self.line_buffer.clear();
+        self.edit_pos = 0;
+        self.escape = EscapeState::Idle;
+    }
+
     pub fn app_exit_notifier(&mut self, p_app_exit_id: u32) -> KernelResult<()> {
         if let Some(l_id) = self.app_exe_in_progress {
             if l_id == p_app_exit_id {
                 self.app_exe_in_progress = None;
+                self.set_pager(false)?;
                 Kernel::devices().unlock(crate::DeviceType::Terminal, l_id)?;
-                self.cursor_pos = 0;
                 self.output.new_line()?;
                 self.output.new_line()?;
-                self.output.write_char('>')?;
+                self.write_prompt()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Set the filter applied to printable characters typed at the prompt.
+    ///
+    /// Characters for which the filter returns `false` are neither echoed nor stored, and the
+    /// terminal bell is sent on serial outputs. Control characters (carriage return, backspace,
+    /// ...) bypass the filter. The filter also applies to command names, so a restrictive
+    /// filter prevents typing commands.
+    ///
+    /// # Parameters
+    /// - `filter`: Filter function, e.g. [`printable_filter`] (default) or [`numeric_filter`].
+    pub fn set_input_filter(&mut self, p_filter: InputFilter) {
+        self.input_filter = p_filter;
+    }
+
+    /// Set the prompt string displayed when the terminal waits for user input.
+    ///
+    /// The new prompt is used the next time a prompt is printed; the current line
+    /// is not redrawn.
+    ///
+    /// # Parameters
+    /// - `prompt`: Prompt string (e.g. `"smolos$ "`).
+    pub fn set_prompt(&mut self, p_prompt: &'static str) {
+        self.prompt = p_prompt;
+    }
+
+    /// Register an additional interface feeding the prompt.
+    ///
+    /// The interface is locked for the kernel and configured with the same HAL callback
+    /// as the terminal USART, so its bytes are processed by [`Terminal::process_input`]
+    /// and interleave with the USART ones in the same line buffer. Echo and prompt output
+    /// still go to the terminal output only. Registering an interface twice has no effect.
+    ///
+    /// Bytes from concurrent sources are serialized: each callback processes its input with
+    /// interrupts masked, so a line is never modified by two sources at the same time.
+    ///
+    /// Held keys are repeated if the source reports key releases with [`K_KEY_RELEASE_FLAG`],
+    /// see the module documentation.
+    ///
+    /// # Parameters
+    /// - `interface_id`: HAL interface ID of the input source.
+    ///
+    /// # Errors
+    /// - [`TerminalError`] if the maximum number of input sources is reached.
+    /// - Propagates any HAL error returned when locking the interface or configuring the
+    ///   callback.
+    pub fn register_input_source(&mut self, p_interface_id: usize) -> KernelResult<()> {
+        if self.input_sources.contains(&p_interface_id) {
+            return Ok(());
+        }
+        if self.input_sources.is_full() {
+            return Err(TerminalError(Error, "Too many input sources"));
+        }
+
+        Kernel::hal()
+            .lock_interface(p_interface_id, K_KERNEL_MASTER_ID)
+            .map_err(KernelError::HalError)?;
+        syscall_hal(
+            p_interface_id,
+            SysCallHalActions::ConfigureCallback(terminal_prompt_callback),
+            K_KERNEL_MASTER_ID,
+        )?;
+        self.input_sources.push(p_interface_id).unwrap_or(());
+
+        Ok(())
+    }
+
+    /// Move the terminal to another USART interface.
+    ///
+    /// The new interface is resolved and locked first, so the terminal keeps its current
+    /// interface if this fails. In prompt mode, the prompt callback is configured on the new
+    /// interface. The previous interface is then released; bytes it still receives are
+    /// dropped. Color and tab width carry over.
+    ///
+    /// The line being typed is discarded, as it was echoed on the previous interface only.
+    /// In prompt mode, a new prompt is printed on the new interface, unless a foreground app
+    /// is running: the prompt is then printed when it exits. Switching to the current
+    /// interface has no effect.
+    ///
+    /// # Parameters
+    /// - `name`: Name of the USART interface to use.
+    ///
+    /// # Errors
+    /// - Propagates any error from resolving, locking or configuring the callback of the new
+    ///   interface. The terminal is unchanged in this case.
+    /// - Propagates any error from releasing the previous interface or printing the prompt.
+    pub fn switch_interface(&mut self, p_name: &'static str) -> KernelResult<()> {
+        let l_id = Kernel::hal()
+            .get_interface_id(p_name)
+            .map_err(KernelError::HalError)?;
+        if self.output.interface_id == Some(l_id) {
+            return Ok(());
+        }
+
+        let mut l_output =
+            ConsoleOutput::new(ConsoleOutputType::Usart(p_name), self.output.current_color);
+        l_output.tab_width = self.output.tab_width;
+        l_output.set_tx_buffered(self.output.is_tx_buffered())?;
+        l_output.initialize()?;
+        if self.mode == Prompt
+            && let Err(l_err) = syscall_hal(
+                l_id,
+                SysCallHalActions::ConfigureCallback(terminal_prompt_callback),
+                K_KERNEL_MASTER_ID,
+            )
+        {
+            l_output.release()?;
+            return Err(l_err);
+        }
+
+        // Swap outputs, the previous one is not written anymore
+        if self.output.interface_id.is_some() {
+            self.output.release()?;
+        }
+        self.output = l_output;
+        self.clear_line();
+
+        if self.mode == Prompt && self.app_exe_in_progress.is_none() {
+            self.output.new_line()?;
+            self.write_prompt()?;
+        }
+
+        Ok(())
+    }
+
+    /// Define a command alias, or update the target of an existing one.
+    ///
+    /// The alias is only used if no registered app has the same name: real apps
+    /// always take precedence over aliases.
+    ///
+    /// # Parameters
+    /// - `alias`: Alias name, matched against the first token of the command line.
+    /// - `target`: Name of the app started when the alias is used.
+    ///
+    /// # Errors
+    /// Returns [`TerminalError`] if a name exceeds [`K_ALIAS_SIZE`] bytes or if the
+    /// alias table is full.
+    pub fn add_alias(&mut self, p_alias: &str, p_target: &str) -> KernelResult<()> {
+        let l_target: String<K_ALIAS_SIZE> = String::try_from(p_target)
+            .map_err(|_| TerminalError(Error, "Alias target too long"))?;
+
+        if let Some(l_entry) = self.aliases.iter_mut().find(|l_a| l_a.0 == p_alias) {
+            l_entry.1 = l_target;
+        } else {
+            let l_alias: String<K_ALIAS_SIZE> = String::try_from(p_alias)
+                .map_err(|_| TerminalError(Error, "Alias name too long"))?;
+            self.aliases
+                .push((l_alias, l_target))
+                .map_err(|_| TerminalError(Error, "Alias table full"))?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the defined command aliases.
+    ///
+    /// # Returns
+    /// A slice of `(alias, target)` entries in definition order.
+    pub fn aliases(&self) -> &[Alias] {
+        &self.aliases
+    }
+
+    /// Track the keys pressed and released on an input source.
+    ///
+    /// Release events are removed from the buffer. The last pressed key, unless it is a
+    /// carriage return, becomes the held key until released, and the key repeat task is
+    /// started or stopped accordingly.
+    ///
+    /// # Parameters
+    /// - `buffer`: Bytes read from the input source.
+    ///
+    /// # Returns
+    /// The pressed keys, to be processed by [`Terminal::process_input`].
+    ///
+    /// # Errors
+    /// Propagates errors from the scheduler when adding or removing the key repeat task.
+    fn track_held_key(
+        &mut self,
+        p_buffer: Vec<u8, K_BUFFER_SIZE>,
+    ) -> KernelResult<Vec<u8, K_BUFFER_SIZE>> {
+        let mut l_keys = Vec::new();
+        for l_byte in p_buffer {
+            if l_byte & K_KEY_RELEASE_FLAG != 0 {
+                if self.held_key.map(|(l_key, _)| l_key) == Some(l_byte & !K_KEY_RELEASE_FLAG) {
+                    self.held_key = None;
+                }
+            } else {
+                l_keys.push(l_byte).unwrap_or(());
+                self.held_key = (l_byte != b'\r').then(|| (l_byte, now_ms()));
             }
         }
 
+        let l_running = Kernel::scheduler()
+            .app_exists(K_KEY_REPEAT_APP_NAME)
+            .is_some();
+        if self.held_key.is_some() && !l_running {
+            Kernel::scheduler().add_periodic_app(
+                K_KEY_REPEAT_APP_NAME,
+                AppCall::App(key_repeat),
+                None,
+                K_KEY_REPEAT_PERIOD,
+                None,
+                false,
+            )?;
+        } else if self.held_key.is_none() && l_running {
+            Kernel::scheduler().remove_periodic_app(K_KEY_REPEAT_APP_NAME)?;
+        }
+
+        Ok(l_keys)
+    }
+
+    /// Substitute the command name of a line with its alias target, if any.
+    ///
+    /// The substitution is skipped when the command name matches a registered app.
+    ///
+    /// # Parameters
+    /// - `line`: Command line as typed by the user.
+    ///
+    /// # Returns
+    /// The command line to execute.
+    ///
+    /// # Errors
+    /// Returns [`TerminalError`] if the expanded line does not fit in the line buffer.
+    fn resolve_alias(&self, p_line: &str) -> KernelResult<String<256>> {
+        expand_alias(p_line, &self.aliases, |l_name| {
+            Kernel::apps().get_app_status(l_name).is_ok()
+        })
+    }
+
+    /// Print the prompt string and reset the cursor position after it.
+    ///
+    /// The cursor position accounts for the whole prompt length so that line
+    /// editing stays consistent with multi-character prompts.
+    ///
+    /// # Errors
+    /// Propagates any error from writing to the underlying console output.
+    fn write_prompt(&mut self) -> KernelResult<()> {
+        self.output.write_str(self.prompt)?;
+        self.cursor_pos = self.prompt.len();
         Ok(())
     }
 }
@@ -348,7 +1417,12 @@ impl Terminal {
 /// HAL callback invoked when prompt input is available for the terminal interface.
 ///
 /// This callback reads a buffer from the HAL interface identified by `id` and
-/// forwards it to the kernel terminal's [`Terminal::process_input`] handler.
+/// forwards it to the kernel terminal's [`Terminal::process_input`] handler. It is
+/// shared by the terminal USART and all sources registered with
+/// [`Terminal::register_input_source`]. The source lookup and the held key tracking run
+/// inside a critical section, while [`Terminal::process_input`] runs with interrupts enabled
+/// and only masks them around its own shared state updates. Bytes from an interface released
+/// by [`Terminal::switch_interface`] are read and dropped.
 ///
 /// # Parameters
 /// - `id`: Interface identifier (as provided by the HAL) that should be read.
@@ -368,7 +1442,28 @@ pub extern "C" fn terminal_prompt_callback(p_id: u8) {
     ) {
         Ok(()) => {
             if let InterfaceReadResult::BufferRead(l_buffer) = l_result {
-                match Kernel::terminal().process_input(l_buffer) {
+                let l_keys = critical_section(|| {
+                    let l_terminal = Kernel::terminal();
+                    // Interface released by a terminal switch
+                    if l_terminal.output.interface_id != Some(p_id as usize)
+                        && !l_terminal.input_sources.contains(&(p_id as usize))
+                    {
+                        return Ok(None);
+                    }
+                    if l_terminal.input_sources.contains(&(p_id as usize)) {
+                        l_terminal.track_held_key(l_buffer).map(Some)
+                    } else {
+                        Ok(Some(l_buffer))
+                    }
+                });
+
+                match l_keys {
+                    // A buffer may only contain key releases
+                    Ok(Some(l_keys)) if !l_keys.is_empty() => {
+                        if let Err(l_e) = Kernel::terminal().process_input(l_keys) {
+                            Kernel::errors().error_handler(&l_e);
+                        }
+                    }
                     Ok(_) => {}
                     Err(l_e) => Kernel::errors().error_handler(&l_e),
                 }
@@ -377,3 +1472,130 @@ pub extern "C" fn terminal_prompt_callback(p_id: u8) {
         Err(l_e) => Kernel::errors().error_handler(&l_e),
     }
 }
+
+/// Periodic task sending the queued terminal output.
+///
+/// # Errors
+/// Propagates any error from [`ConsoleOutput::drain`].
+fn console_tx() -> KernelResult<()> {
+    Kernel::terminal().output.drain().map(|_| ())
+}
+
+/// Periodic task repeating the key held down on an input source.
+///
+/// The key is processed again once it has been held for [`K_KEY_REPEAT_DELAY`].
+///
+/// # Errors
+/// Propagates any error from [`Terminal::process_input`].
+fn key_repeat() -> KernelResult<()> {
+    critical_section(|| {
+        let l_terminal = Kernel::terminal();
+        match l_terminal.held_key {
+            Some((l_key, l_since))
+                if now_ms().wrapping_sub(l_since) >= K_KEY_REPEAT_DELAY.to_u32() =>
+            {
+                let mut l_buffer = Vec::new();
+                l_buffer.push(l_key).unwrap_or(());
+                l_terminal.process_input(l_buffer)
+            }
+            _ => Ok(()),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn carriage_return_while_busy_does_not_start_an_app() {
+        assert_eq!(busy_input(b"\r"), BusyInput::Busy);
+        assert_eq!(busy_input(b"help\r"), BusyInput::Busy);
+        assert_eq!(busy_input(b"\r\r"), BusyInput::Busy);
+    }
+
+    #[test]
+    fn other_input_while_busy_is_ignored() {
+        assert_eq!(busy_input(b""), BusyInput::Ignore);
+        assert_eq!(busy_input(b"help"), BusyInput::Ignore);
+        assert_eq!(busy_input(&[K_ESC, b'[', b'A']), BusyInput::Ignore);
+    }
+
+    #[test]
+    fn break_key_wins_over_carriage_return() {
+        assert_eq!(busy_input(&[K_BREAK]), BusyInput::Break);
+        assert_eq!(busy_input(&[b'\r', K_BREAK]), BusyInput::Break);
+    }
+
+    #[test]
+    fn closest_name_has_the_longest_common_prefix() {
+        let l_apps = ["led_blink", "led_off", "monitor"];
+        assert_eq!(closest_name("led_of", l_apps), Some("led_off"));
+        assert_eq!(closest_name("monitr", l_apps), Some("monitor"));
+    }
+
+    #[test]
+    fn closest_name_tie_gives_the_first_candidate() {
+        assert_eq!(
+            closest_name("led", ["led_blink", "led_off"]),
+            Some("led_blink")
+        );
+    }
+
+    #[test]
+    fn closest_name_needs_a_minimum_prefix() {
+        let l_apps = ["led_blink", "monitor"];
+        assert_eq!(closest_name("lx", l_apps), None);
+        assert_eq!(closest_name("le", l_apps), Some("led_blink"));
+        assert_eq!(closest_name("", l_apps), None);
+        assert_eq!(closest_name("led", []), None);
+    }
+
+    #[test]
+    fn characters_past_the_line_capacity_are_dropped() {
+        let mut l_line: String<256> = String::new();
+        for l_index in 0..300 {
+            let l_end = l_line.len();
+            let l_tail = insert_at(&mut l_line, l_end, b'a' + (l_index % 26) as u8);
+            assert_eq!(l_tail.is_some(), l_index < 256);
+        }
+        assert_eq!(l_line.len(), 256);
+        assert!(l_line.starts_with("abcdefghijklmnopqrstuvwxyzabc"));
+
+        // A full line is left untouched wherever the character is inserted
+        assert!(insert_at(&mut l_line, 0, b'z').is_none());
+        assert!(l_line.starts_with("abc"));
+    }
+
+    #[test]
+    fn character_is_inserted_at_the_position() {
+        let mut l_line: String<256> = String::try_from("hllo").unwrap();
+        assert_eq!(insert_at(&mut l_line, 1, b'e').unwrap().as_str(), "llo");
+        assert_eq!(l_line.as_str(), "hello");
+        assert!(insert_at(&mut l_line, 5, 0xC3).is_none());
+        assert_eq!(l_line.as_str(), "hello");
+    }
+
+    fn alias(p_alias: &str, p_target: &str) -> Alias {
+        (
+            String::try_from(p_alias).unwrap(),
+            String::try_from(p_target).unwrap(),
+        )
+    }
+
+    #[test]
+    fn one_letter_alias_resolves() {
+        let l_aliases = [alias("l", "led_blink")];
+        let l_resolve = |p_line| expand_alias(p_line, &l_aliases, |_| false).unwrap();
+        assert_eq!(l_resolve("l").as_str(), "led_blink");
+        assert_eq!(l_resolve(" l 500").as_str(), "led_blink 500");
+        assert_eq!(l_resolve("ls").as_str(), "ls");
+    }
+
+    #[test]
+    fn app_name_takes_precedence_over_alias() {
+        let l_aliases = [alias("monitor", "led_blink")];
+        let l_command = expand_alias("monitor", &l_aliases, |l_name| l_name == "monitor");
+        assert_eq!(l_command.unwrap().as_str(), "monitor");
+    }
+}
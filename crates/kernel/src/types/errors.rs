@@ -1,8 +1,10 @@
 use crate::KernelError::{
-    AppAlreadyScheduled, AppInitError, AppNeedsNoParam, AppNotFound, AppNotScheduled,
-    AppParamTooLong, CannotAddNewPeriodicApp, DeviceLocked, DeviceNotOwned, DisplayError, HalError,
-    TerminalError, TestCriticalError, TestError, TestFatalError, TooManyAppParams,
-    WrongSyscallArgs,
+    AppAlreadyScheduled, AppChainCycle, AppInitError, AppNeedsNoParam, AppNotFound,
+    AppNotScheduled, AppParamMissing, AppParamTooLong, AssertionFailed, AutostartConfigTooLarge,
+    CannotAddNewPeriodicApp, DeviceLocked, DeviceNotOwned, DisplayError, DisplayNotConfigured,
+    FirmwareCrcMismatch, HalError, InvalidDuration, InvalidTimingConfig, SchedulerFull,
+    SubscriptionTableFull, TerminalError, TestCriticalError, TestError, TestFatalError,
+    TooManyAppParams, TooManyDisplays, UnknownInterface, UnsupportedInterface, WrongSyscallArgs,
 };
 use crate::KernelErrorLevel::{Critical, Error, Fatal};
 use crate::{K_MAX_APP_PARAM_SIZE, K_MAX_APP_PARAMS};
@@ -40,10 +42,18 @@ pub enum KernelError {
     HalError(HalErrorDef),
     /// Errors originating from the display driver.
     DisplayError(DisplayErrorDef),
+    /// A display operation was requested but no display was configured at boot.
+    DisplayNotConfigured,
+    /// A display could not be registered because the display table is full (interface name).
+    TooManyDisplays(&'static str),
     /// Errors related to terminal I/O operations.
     TerminalError(KernelErrorLevel, &'static str),
+    /// A kernel invariant checked with `kernel_assert!` does not hold (severity, message).
+    AssertionFailed(KernelErrorLevel, &'static str),
     /// Failed to add a new periodic application to the scheduler.
     CannotAddNewPeriodicApp(&'static str),
+    /// The scheduler has no free slot for a new task (app name, used slots, total slots).
+    SchedulerFull(&'static str, usize, usize),
     /// Initialization failure with a captured error message and app name.
     AppInitError(&'static str),
     /// Invalid arguments passed to a system call.
@@ -64,6 +74,24 @@ pub enum KernelError {
     AppParamTooLong,
     /// App should not receive any parameters.
     AppNeedsNoParam(&'static str),
+    /// App main function expects a parameter but none is configured.
+    AppParamMissing(&'static str),
+    /// Chaining the app to its successor would create a loop.
+    AppChainCycle(&'static str),
+    /// Firmware integrity check failed (expected CRC, computed CRC).
+    FirmwareCrcMismatch(u32, u32),
+    /// The requested SysTick or scheduler period cannot be achieved with the current clock.
+    InvalidTimingConfig(&'static str),
+    /// A task period or lifetime cannot be represented in scheduler cycles.
+    InvalidDuration(&'static str),
+    /// The event bus subscription table is full (topic of the rejected subscription).
+    SubscriptionTableFull(u16),
+    /// The autostart app names do not fit in the persistent configuration area.
+    AutostartConfigTooLarge,
+    /// The interface given to an app does not exist (app name).
+    UnknownInterface(&'static str),
+    /// The interface given to an app cannot be used by it (app name, interface name).
+    UnsupportedInterface(&'static str, &'static str),
     /// Error generated for testing purposes (Error level).
     TestError,
     /// Error generated for testing purposes (Critical level).
@@ -92,6 +120,16 @@ impl KernelError {
                     )
                     .unwrap();
             }
+            AssertionFailed(_, l_text) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(200; "Assertion failed : {}", l_text)
+                            .unwrap()
+                            .as_str(),
+                    )
+                    .unwrap();
+            }
             CannotAddNewPeriodicApp(l_name) => {
                 l_msg.push_str(self.severity().as_str()).unwrap();
                 l_msg
@@ -102,6 +140,22 @@ impl KernelError {
                     )
                     .unwrap();
             }
+            SchedulerFull(l_name, l_used, l_max) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(
+                            200;
+                            "Cannot add periodic app {} : scheduler is full ({}/{} slots used)",
+                            l_name,
+                            l_used,
+                            l_max
+                        )
+                        .unwrap()
+                        .as_str(),
+                    )
+                    .unwrap();
+            }
             AppInitError(l_app_name) => {
                 l_msg.push_str(self.severity().as_str()).unwrap();
                 l_msg
@@ -148,6 +202,22 @@ impl KernelError {
                     .push_str(format!(200; "App does not exist").unwrap().as_str())
                     .unwrap();
             }
+            DisplayNotConfigured => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(format!(200; "No display configured").unwrap().as_str())
+                    .unwrap();
+            }
+            TooManyDisplays(l_name) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(200; "Cannot add display {} : display table is full", l_name)
+                            .unwrap()
+                            .as_str(),
+                    )
+                    .unwrap();
+            }
             DeviceLocked(l_device_name) => {
                 l_msg.push_str(self.severity().as_str()).unwrap();
                 l_msg
@@ -206,6 +276,101 @@ impl KernelError {
                     )
                     .unwrap();
             }
+            AppParamMissing(l_app_name) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(200; "App {} requires a parameter", l_app_name)
+                            .unwrap()
+                            .as_str(),
+                    )
+                    .unwrap();
+            }
+            AppChainCycle(l_app_name) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(200; "App {} chain would loop", l_app_name)
+                            .unwrap()
+                            .as_str(),
+                    )
+                    .unwrap();
+            }
+            InvalidDuration(l_app_name) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(200; "Invalid period or duration for app {}", l_app_name)
+                            .unwrap()
+                            .as_str(),
+                    )
+                    .unwrap();
+            }
+            SubscriptionTableFull(l_topic) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(
+                            200;
+                            "Cannot subscribe to topic {} : subscription table is full",
+                            l_topic
+                        )
+                        .unwrap()
+                        .as_str(),
+                    )
+                    .unwrap();
+            }
+            AutostartConfigTooLarge => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str("Autostart configuration does not fit in storage")
+                    .unwrap();
+            }
+            UnknownInterface(l_app_name) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(200; "App {} : unknown interface", l_app_name)
+                            .unwrap()
+                            .as_str(),
+                    )
+                    .unwrap();
+            }
+            UnsupportedInterface(l_app_name, l_interface) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(200; "App {} cannot use interface {}", l_app_name, l_interface)
+                            .unwrap()
+                            .as_str(),
+                    )
+                    .unwrap();
+            }
+            InvalidTimingConfig(l_err) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(200; "Invalid timing configuration : {}", l_err)
+                            .unwrap()
+                            .as_str(),
+                    )
+                    .unwrap();
+            }
+            FirmwareCrcMismatch(l_expected, l_computed) => {
+                l_msg.push_str(self.severity().as_str()).unwrap();
+                l_msg
+                    .push_str(
+                        format!(
+                            200;
+                            "Firmware CRC mismatch : expected {:08X}, computed {:08X}",
+                            l_expected,
+                            l_computed
+                        )
+                        .unwrap()
+                        .as_str(),
+                    )
+                    .unwrap();
+            }
             TestError => {
                 l_msg.push_str(self.severity().as_str()).unwrap();
                 l_msg.push_str("Test error").unwrap();
@@ -242,17 +407,30 @@ impl KernelError {
                 DisplayErrorLevel::Error => Error,
             },
             TerminalError(l_lvl, _) => *l_lvl,
+            AssertionFailed(l_lvl, _) => *l_lvl,
             CannotAddNewPeriodicApp(_) => Critical,
+            SchedulerFull(..) => Critical,
             AppInitError(_) => Critical,
             WrongSyscallArgs(_) => Error,
             AppNotScheduled(_) => Error,
             AppAlreadyScheduled(_) => Error,
             AppNotFound => Error,
+            DisplayNotConfigured => Error,
+            TooManyDisplays(_) => Critical,
             DeviceLocked(_) => Error,
             DeviceNotOwned(_) => Error,
             TooManyAppParams => Error,
             AppParamTooLong => Error,
             AppNeedsNoParam(_) => Error,
+            AppParamMissing(_) => Error,
+            AppChainCycle(_) => Error,
+            FirmwareCrcMismatch(_, _) => Critical,
+            InvalidTimingConfig(_) => Fatal,
+            InvalidDuration(_) => Error,
+            SubscriptionTableFull(_) => Error,
+            AutostartConfigTooLarge => Error,
+            UnknownInterface(_) => Error,
+            UnsupportedInterface(..) => Error,
             TestError => Error,
             TestCriticalError => Critical,
             TestFatalError => Fatal,
@@ -1,4 +1,5 @@
 use core::fmt::Display;
+pub use hal_interface::Microseconds;
 
 /// A wrapper struct representing a duration in milliseconds.
 ///
@@ -55,6 +56,34 @@ impl Milliseconds {
     pub fn to_u32(&self) -> u32 {
         self.0
     }
+
+    /// Multiplies the duration by a factor, checking for overflow.
+    ///
+    /// # Parameters
+    /// - `factor`: The multiplier.
+    ///
+    /// # Returns
+    /// - `Some(Milliseconds)` with the product, or `None` if it exceeds `u32::MAX` ms.
+    pub const fn checked_mul(self, p_factor: u32) -> Option<Milliseconds> {
+        match self.0.checked_mul(p_factor) {
+            Some(l_ms) => Some(Milliseconds(l_ms)),
+            None => None,
+        }
+    }
+
+    /// Adds two durations, checking for overflow.
+    ///
+    /// # Parameters
+    /// - `other`: The duration to add.
+    ///
+    /// # Returns
+    /// - `Some(Milliseconds)` with the sum, or `None` if it exceeds `u32::MAX` ms.
+    pub const fn checked_add(self, p_other: Milliseconds) -> Option<Milliseconds> {
+        match self.0.checked_add(p_other.0) {
+            Some(l_ms) => Some(Milliseconds(l_ms)),
+            None => None,
+        }
+    }
 }
 
 /// A wrapper struct representing time in seconds.
@@ -174,3 +203,49 @@ impl Mhz {
         self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_mul_is_exact_up_to_u32_max() {
+        assert_eq!(
+            Milliseconds(u32::MAX / 3)
+                .checked_mul(3)
+                .map(|l_ms| l_ms.to_u32()),
+            Some(u32::MAX)
+        );
+        assert_eq!(
+            Milliseconds(u32::MAX)
+                .checked_mul(0)
+                .map(|l_ms| l_ms.to_u32()),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn checked_mul_overflow_is_none() {
+        assert!(Milliseconds(u32::MAX / 2 + 1).checked_mul(2).is_none());
+        assert!(Milliseconds(u32::MAX).checked_mul(2).is_none());
+    }
+
+    #[test]
+    fn checked_add_is_exact_up_to_u32_max() {
+        assert_eq!(
+            Milliseconds(u32::MAX - 1)
+                .checked_add(Milliseconds(1))
+                .map(|l_ms| l_ms.to_u32()),
+            Some(u32::MAX)
+        );
+    }
+
+    #[test]
+    fn checked_add_overflow_is_none() {
+        assert!(
+            Milliseconds(u32::MAX)
+                .checked_add(Milliseconds(1))
+                .is_none()
+        );
+    }
+}
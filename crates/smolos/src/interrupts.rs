@@ -2,6 +2,7 @@ use stm32f7::stm32f769::interrupt;
 
 unsafe extern "C" {
     pub fn USART1_it_handler();
+    pub fn TIM7_it_handler();
 }
 
 #[allow(non_snake_case)]
@@ -11,3 +12,10 @@ fn USART1() {
         USART1_it_handler();
     }
 }
+#[allow(non_snake_case)]
+#[interrupt]
+fn TIM7() {
+    unsafe {
+        TIM7_it_handler();
+    }
+}
@@ -19,7 +19,7 @@ use kernel::{BootConfig, KernelTimeData, Mhz, Milliseconds};
 /// 2. Initializing the system tick timer with a default value.
 /// 3. Initializing the Hardware Abstraction Layer (HAL).
 /// 4. Booting the kernel with a specific configuration.
-/// 5. Entering an infinite loop as the kernel takes over execution.
+/// 5. Entering an infinite loop calling the kernel idle hook as the kernel takes over execution.
 ///
 /// # Returns
 /// This function never returns.
@@ -32,7 +32,7 @@ fn main() -> ! {
     kernel::cortex_init();
 
     // Start systick
-    kernel::init_systick(None);
+    kernel::init_systick(None).unwrap();
 
     // Initialize HAL
     let l_hal = Hal::new().unwrap();
@@ -47,9 +47,24 @@ fn main() -> ! {
         hal: l_hal,
         system_terminal: "SERIAL_MAIN",
         err_led_name: Some("ERR_LED"),
+        error_repeat_window: None,
+        panic_to_terminal: true,
         display_name: Some("LCD"),
+        extra_displays: &[],
+        heartbeat_led_name: None,
+        terminal_prompt: None,
+        terminal_tab_width: None,
+        terminal_input_filter: None,
+        terminal_input_sources: &[],
+        low_power_idle: !cfg!(debug_assertions),
+        display_refresh_period: None,
+        safe_mode_pin: None,
+        watchdog_timeout: Some(Milliseconds(2000)),
+        console_heartbeat_period: None,
+        screen_saver_timeout: Milliseconds(0),
     });
 
-    #[allow(clippy::empty_loop)]
-    loop {}
+    loop {
+        kernel::idle();
+    }
 }
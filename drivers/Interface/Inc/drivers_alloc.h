@@ -19,10 +19,11 @@
 /********************/
 /* Exported defines */
 /********************/
-#define K_DRIVERS_ALLOC_SIZE 4
+#define K_DRIVERS_ALLOC_SIZE 5
 #define K_DRIVER_ACTIVATE_USART
 #define K_DRIVER_ACTIVATE_GPIO
 #define K_DRIVER_ACTIVATE_LCD
+#define K_DRIVER_ACTIVATE_TIMER
 #define K_USART_BUFFER_SIZE 32
 
 /******************/
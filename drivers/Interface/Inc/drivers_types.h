@@ -32,7 +32,9 @@ typedef enum
 {
     GPIO,   /**< General Purpose Input/Output */
     USART,  /**< Universal Synchronous/Asynchronous Receiver/Transmitter */
-    LCD     /**< Liquid Crystal Display */
+    LCD,    /**< Liquid Crystal Display */
+    DAC_IF, /**< Digital to Analog Converter, not named DAC to avoid the CMSIS peripheral macro */
+    TIMER   /**< Hardware timer generating periodic callbacks */
 } INTERFACE_TYPE;
 
 /**
@@ -67,6 +69,24 @@ typedef struct
     uint16_t pin;                       /**< Pin number */
 } GPIO_ALLOC;
 
+/**
+ * @brief Allocation structure for DAC-specific data.
+ */
+typedef struct
+{
+    DAC_TypeDef *dac;                   /**< Pointer to the DAC peripheral base address */
+    uint8_t channel;                    /**< DAC channel (1 or 2) */
+} DAC_ALLOC;
+
+/**
+ * @brief Allocation structure for timer-specific data.
+ */
+typedef struct
+{
+    TIM_TypeDef *tim;                   /**< Pointer to the timer peripheral base address */
+    IRQn_Type irq;                      /**< Update interrupt of the timer */
+} TIMER_ALLOC;
+
 /**
  * @brief Type definition for HAL interface callbacks.
  *
@@ -15,12 +15,22 @@
 /************/
 /* Includes */
 /************/
+#include <stdbool.h>
 #include <stdint.h>
 
 /********************/
 /* Exported defines */
 /********************/
 
+/** Full scale value of the DAC, which is used with 12-bit right-aligned data */
+#define K_DAC_MAX_VALUE 0x0FFF
+
+/** Size of the persistent configuration area usable by config_read and config_write */
+#define K_CONFIG_STORAGE_SIZE 512
+
+/** Shortest accepted timer period in microseconds */
+#define K_TIMER_MIN_PERIOD_US 10
+
 /******************/
 /* Exported types */
 /******************/
@@ -38,6 +48,8 @@ typedef enum
     ERR_INCOMPATIBLE_ACTION = 5,    /**< Requested action not compatible with interface type */
     ERR_WRITE_ERROR = 6,            /**< Error during write operation */
     ERR_NO_BUFFER = 7,              /**< No buffer associated with the interface for reading */
+    ERR_RESET_ERROR = 8,            /**< Error while reinitializing the interface */
+    ERR_INVALID_PERIOD = 9,         /**< Requested timer period cannot be generated */
 } HAL_INTERFACE_RESULT;
 
 /**
@@ -50,6 +62,18 @@ typedef enum
     TOGGLE_PIN = 2  /**< Toggle pin state */
 } GPIO_WRITE_ACTION;
 
+/**
+ * @brief Optional features of the board, combined as a bit mask by get_capabilities.
+ */
+typedef enum
+{
+    CAP_HW_CRC = 0x01,          /**< Hardware CRC unit */
+    CAP_LCD_BACKLIGHT = 0x02,   /**< Adjustable LCD backlight */
+    CAP_DMA2D = 0x04,           /**< DMA2D accelerated fills and copies */
+    CAP_RTC = 0x08,             /**< Real-time clock */
+    CAP_INTERFACE_RESET = 0x10  /**< Peripheral reinitialization with interface_reset */
+} HAL_CAPABILITY;
+
 
 /**********************/
 /* Exported constants */
@@ -108,6 +132,50 @@ HAL_INTERFACE_RESULT configure_callback(const uint8_t p_id, const HAL_INTERFACE_
  */
 HAL_INTERFACE_RESULT gpio_write(const uint8_t p_id, const GPIO_WRITE_ACTION p_action);
 
+/**
+ * @brief Generates a high pulse of the given width on a GPIO interface.
+ *
+ * @param p_id The GPIO interface ID.
+ * @param p_width_us The pulse width in microseconds.
+ * @return OK if successful, or an error code.
+ */
+HAL_INTERFACE_RESULT gpio_pulse(const uint8_t p_id, const uint32_t p_width_us);
+
+/**
+ * @brief Reads the level of a GPIO interface.
+ *
+ * @param p_id The GPIO interface ID.
+ * @param p_state Output: true if the pin is high, false if low.
+ * @return OK if successful, or an error code.
+ */
+HAL_INTERFACE_RESULT gpio_read(const uint8_t p_id, bool *p_state);
+
+/**
+ * @brief Writes a 12-bit value to a DAC interface.
+ *
+ * @param p_id The DAC interface ID.
+ * @param p_value The output value, clamped to K_DAC_MAX_VALUE.
+ * @return OK if successful, or an error code.
+ */
+HAL_INTERFACE_RESULT dac_write(const uint8_t p_id, const uint16_t p_value);
+
+/**
+ * @brief Starts or stops a hardware timer calling the interface callback periodically or once.
+ *
+ * @param p_id The timer interface ID.
+ * @param p_period_us The callback period in microseconds, 0 stops the timer.
+ * @param p_one_shot true to call the callback only once, one period after the start.
+ * @return OK if successful, or an error code.
+ */
+HAL_INTERFACE_RESULT timer_configure(const uint8_t p_id, const uint32_t p_period_us, const bool p_one_shot);
+
+/**
+ * @brief Update interrupt handler shared by all timer interfaces.
+ *
+ * @param p_tim The timer peripheral which raised the interrupt.
+ */
+void timer_it_handler(TIM_TypeDef *p_tim);
+
 /**
  * @brief Writes a string to a USART interface.
  *
@@ -118,6 +186,14 @@ HAL_INTERFACE_RESULT gpio_write(const uint8_t p_id, const GPIO_WRITE_ACTION p_ac
  */
 HAL_INTERFACE_RESULT usart_write(const uint8_t p_id, const uint8_t *p_str, const uint16_t p_len);
 
+/**
+ * @brief Reinitializes an interface peripheral.
+ *
+ * @param p_id The interface ID.
+ * @return OK if successful, or an error code.
+ */
+HAL_INTERFACE_RESULT interface_reset(const uint8_t p_id);
+
 /**
  * @brief Retrieves the receive buffer for a given interface.
  *
@@ -134,6 +210,40 @@ HAL_INTERFACE_RESULT get_read_buffer(const uint8_t p_id, RX_BUFFER **p_buffer);
  */
 uint32_t get_core_clk();
 
+/**
+ * @brief Returns the optional features available on the board.
+ *
+ * @return A bit mask of HAL_CAPABILITY values.
+ */
+uint32_t get_capabilities();
+
+/**
+ * @brief Computes the CRC-32 of a memory region using the hardware CRC unit.
+ *
+ * @param p_data Pointer to the first byte of the region.
+ * @param p_len Length of the region in bytes.
+ * @return The computed CRC.
+ */
+uint32_t crc32(const uint8_t *p_data, const uint32_t p_len);
+
+/**
+ * @brief Reads the start of the persistent configuration area.
+ *
+ * @param p_data Destination of the bytes.
+ * @param p_len Number of bytes to read, at most K_CONFIG_STORAGE_SIZE.
+ * @return Status of the operation.
+ */
+HAL_INTERFACE_RESULT config_read(uint8_t *p_data, const uint32_t p_len);
+
+/**
+ * @brief Erases the persistent configuration area and stores new content.
+ *
+ * @param p_data Bytes to store.
+ * @param p_len Number of bytes to store, at most K_CONFIG_STORAGE_SIZE.
+ * @return Status of the operation.
+ */
+HAL_INTERFACE_RESULT config_write(const uint8_t *p_data, const uint32_t p_len);
+
 /**
  * @brief Enables or disables an LCD interface.
  *
@@ -195,6 +305,29 @@ HAL_INTERFACE_RESULT get_fb_address(const uint8_t p_id, const uint8_t p_layer, u
  */
 HAL_INTERFACE_RESULT set_fb_address(const uint8_t p_id, const uint8_t p_layer, const uint32_t p_addr);
 
+/**
+ * @brief Transfers an area of an LCD layer frame buffer to the panel.
+ *
+ * @param p_id The LCD interface ID.
+ * @param p_layer The layer index.
+ * @param p_x X coordinate of the top-left corner.
+ * @param p_y Y coordinate of the top-left corner.
+ * @param p_width Width of the area.
+ * @param p_height Height of the area.
+ * @return OK if successful, or an error code.
+ */
+HAL_INTERFACE_RESULT lcd_refresh(const uint8_t p_id, const uint8_t p_layer, const uint16_t p_x, const uint16_t p_y,
+                                 const uint16_t p_width, const uint16_t p_height);
+
+/**
+ * @brief Sets the backlight brightness of an LCD.
+ *
+ * @param p_id The LCD interface ID.
+ * @param p_percent Brightness in percent (0 to 100).
+ * @return OK if successful, or an error code.
+ */
+HAL_INTERFACE_RESULT lcd_set_backlight(const uint8_t p_id, const uint8_t p_percent);
+
 /**
  * @brief Provides a delay in milliseconds.
  *
@@ -16,6 +16,7 @@
 #include "usart.h"
 #include "gpio.h"
 #include "stm32f769i_discovery_lcd.h"
+#include "../Inc/lib_interface.h"
 
 /*******************/
 /* Private typedef */
@@ -41,11 +42,16 @@ const GPIO_ALLOC K_GPIO_PJ13 = {
     .gpio = GPIOJ,
     .pin = GPIO_PIN_13,
 };
+const TIMER_ALLOC K_TIMER_TIM7 = {
+    .tim = TIM7,
+    .irq = TIM7_IRQn,
+};
 const DRIVER_ALLOC K_DRIVERS_ALLOC[] = {
     { (uint8_t*)"SERIAL_MAIN", USART, INOUT, (void*) &huart1, (void*) &G_USART1_BUFFER, 0 },
     { (uint8_t*)"ACT_LED", GPIO, OUT, (void*) &K_GPIO_PJ5, (void*) 0, 1 },
     { (uint8_t*)"ERR_LED", GPIO, OUT, (void*) &K_GPIO_PJ13, (void*) 0, 2 },
     { (uint8_t*)"LCD", LCD, INOUT, (void*) 0, (void*) 0, 3 },
+    { (uint8_t*)"HW_TIMER", TIMER, OUT, (void*) &K_TIMER_TIM7, (void*) 0, 4 },
 };
 
 /******************/
@@ -76,6 +82,7 @@ void drivers_init()
 
     // LCD initialization
     BSP_LCD_Init();
+    BSP_LCD_LayerDefaultInit(0, LCD_FB_START_ADDRESS + 0x400000);
     BSP_LCD_LayerDefaultInit(1, LCD_FB_START_ADDRESS);
     BSP_LCD_DisplayOff();
 
@@ -85,3 +92,8 @@ void USART1_it_handler()
 {
     HAL_UART_IRQHandler(&huart1);
 }
+
+void TIM7_it_handler()
+{
+    timer_it_handler(TIM7);
+}
@@ -14,9 +14,11 @@
 /********************/
 #include "gpio.h"
 #include "usart.h"
+#include "crc.h"
 #include "../Inc/lib_interface.h"
 #include "../Inc/drivers_alloc.h"
 #include <stdbool.h>
+#include <string.h>
 #include <sys/types.h>
 
 #include "fmc.h"
@@ -31,6 +33,11 @@
 /* Private defines */
 /*******************/
 
+/** Flash sector reserved for the persistent configuration, excluded from the firmware area */
+#define K_CONFIG_FLASH_SECTOR FLASH_SECTOR_11
+/** Start address of the configuration sector */
+#define K_CONFIG_FLASH_ADDRESS 0x081C0000U
+
 /*********************/
 /* Private constants */
 /*********************/
@@ -124,6 +131,7 @@ HAL_INTERFACE_RESULT lcd_id_check(const uint8_t p_id)
  * The sequence of initialization includes:
  * - Configuring the system clock using SystemClock_Config.
  * - Configuring the common peripheral clocks using PeriphCommonClock_Config.
+ * - Initializing the hardware CRC unit using MX_CRC_Init.
  * - Initializing the GPIO peripherals using MX_GPIO_Init.
  * - Setting up the USART1 UART peripheral using MX_USART1_UART_Init.
  */
@@ -133,6 +141,7 @@ void hal_init()
     SystemClock_Config();
     PeriphCommonClock_Config();
     MX_FMC_Init();
+    MX_CRC_Init();
 
     drivers_init();
 
@@ -219,6 +228,110 @@ uint32_t get_core_clk()
     return HAL_RCC_GetSysClockFreq();
 }
 
+/**
+ * @brief Returns the optional features available on the board.
+ *
+ * The mask is built from the activated drivers, so the Rust side can adapt at runtime
+ * instead of relying on build flags.
+ *
+ * @return A bit mask of HAL_CAPABILITY values.
+ */
+uint32_t get_capabilities()
+{
+    uint32_t l_caps = CAP_HW_CRC;
+
+#ifdef K_DRIVER_ACTIVATE_LCD
+    l_caps |= CAP_LCD_BACKLIGHT;
+#endif
+
+#ifdef K_DRIVER_ACTIVATE_USART
+    l_caps |= CAP_INTERFACE_RESET;
+#endif
+
+    return l_caps;
+}
+
+/**
+ * @brief Computes the CRC-32 of a memory region.
+ *
+ * This function feeds the given bytes to the hardware CRC unit, configured by
+ * MX_CRC_Init with the default polynomial (0x04C11DB7), the default initial
+ * value (0xFFFFFFFF) and byte input format.
+ *
+ * @param p_data Pointer to the first byte of the region.
+ * @param p_len Length of the region in bytes.
+ *
+ * @return The computed CRC value.
+ */
+uint32_t crc32(const uint8_t *p_data, const uint32_t p_len)
+{
+    return HAL_CRC_Calculate(&hcrc, (uint32_t *) p_data, p_len);
+}
+
+/**
+ * @brief Reads the start of the persistent configuration area.
+ *
+ * The area is memory mapped, the bytes are copied as stored. An erased area reads as 0xFF.
+ *
+ * @param p_data Destination of the bytes.
+ * @param p_len Number of bytes to read, at most K_CONFIG_STORAGE_SIZE.
+ *
+ * @return OK if the bytes were copied;
+ *         ERR_INCOMPATIBLE_ACTION if p_len exceeds K_CONFIG_STORAGE_SIZE.
+ */
+HAL_INTERFACE_RESULT config_read(uint8_t *p_data, const uint32_t p_len)
+{
+    if (p_len > K_CONFIG_STORAGE_SIZE)
+    {
+        return ERR_INCOMPATIBLE_ACTION;
+    }
+
+    memcpy(p_data, (const uint8_t *) K_CONFIG_FLASH_ADDRESS, p_len);
+    return OK;
+}
+
+/**
+ * @brief Replaces the content of the persistent configuration area.
+ *
+ * The configuration sector is erased, then the bytes are programmed one by one. The CPU
+ * stalls while the sector is erased, as the code runs from the same flash bank: this takes
+ * up to a few seconds and must not be done in a time-critical context.
+ *
+ * @param p_data Bytes to store.
+ * @param p_len Number of bytes to store, at most K_CONFIG_STORAGE_SIZE.
+ *
+ * @return OK if the bytes were stored;
+ *         ERR_INCOMPATIBLE_ACTION if p_len exceeds K_CONFIG_STORAGE_SIZE;
+ *         ERR_WRITE_ERROR if erasing or programming the flash failed.
+ */
+HAL_INTERFACE_RESULT config_write(const uint8_t *p_data, const uint32_t p_len)
+{
+    FLASH_EraseInitTypeDef l_erase = {0};
+    uint32_t l_sector_error = 0;
+    HAL_StatusTypeDef l_status;
+
+    if (p_len > K_CONFIG_STORAGE_SIZE)
+    {
+        return ERR_INCOMPATIBLE_ACTION;
+    }
+
+    l_erase.TypeErase = FLASH_TYPEERASE_SECTORS;
+    l_erase.Sector = K_CONFIG_FLASH_SECTOR;
+    l_erase.NbSectors = 1;
+    l_erase.VoltageRange = FLASH_VOLTAGE_RANGE_3;
+
+    HAL_FLASH_Unlock();
+    l_status = HAL_FLASHEx_Erase(&l_erase, &l_sector_error);
+    for (uint32_t l_i = 0; l_i < p_len && l_status == HAL_OK; l_i++)
+    {
+        l_status = HAL_FLASH_Program(FLASH_TYPEPROGRAM_BYTE, K_CONFIG_FLASH_ADDRESS + l_i,
+                                     p_data[l_i]);
+    }
+    HAL_FLASH_Lock();
+
+    return l_status == HAL_OK ? OK : ERR_WRITE_ERROR;
+}
+
 /**
  * @brief Configures a callback function for a specified interface ID.
  *
@@ -279,6 +392,62 @@ HAL_INTERFACE_RESULT get_read_buffer(const uint8_t p_id, RX_BUFFER **p_buffer)
     return OK;
 }
 
+/**
+ * @brief Reinitializes the peripheral behind an interface.
+ *
+ * This function recovers a peripheral stuck in a bad state without resetting the MCU.
+ * Only USART interfaces are supported: any ongoing transfer is aborted, the peripheral is
+ * de-initialized and initialized again with the configuration stored in its handle, the
+ * receive buffer is emptied and reception in interrupt mode is restarted.
+ *
+ * The callback configured with configure_callback is preserved.
+ *
+ * @param p_id The ID of the interface to reset.
+ *
+ * @return - OK: The interface was successfully reinitialized.
+ *         - ERR_WRONG_INTERFACE_ID: The provided interface ID is invalid or out of bounds.
+ *         - ERR_INCOMPATIBLE_ACTION: The interface type does not support reset.
+ *         - ERR_RESET_ERROR: The peripheral could not be reinitialized.
+ */
+HAL_INTERFACE_RESULT interface_reset(const uint8_t p_id)
+{
+    if (p_id >= K_DRIVERS_ALLOC_SIZE)
+    {
+        return ERR_WRONG_INTERFACE_ID;
+    }
+
+    switch (K_DRIVERS_ALLOC[p_id].drv_type)
+    {
+#ifdef K_DRIVER_ACTIVATE_USART
+    case USART:
+        {
+            UART_HandleTypeDef *l_huart = K_DRIVERS_ALLOC[p_id].drv;
+            RX_BUFFER *l_buffer = K_DRIVERS_ALLOC[p_id].buffer;
+
+            // Abort pending transfers and re-apply configuration
+            HAL_UART_Abort(l_huart);
+            if (HAL_UART_DeInit(l_huart) != HAL_OK || HAL_UART_Init(l_huart) != HAL_OK)
+            {
+                return ERR_RESET_ERROR;
+            }
+
+            // Flush received data and restart reception
+            if (l_buffer != NULL)
+            {
+                l_buffer->size = 0;
+                if (HAL_UART_Receive_IT(l_huart, l_buffer->buffer, 1) != HAL_OK)
+                {
+                    return ERR_RESET_ERROR;
+                }
+            }
+            return OK;
+        }
+#endif
+    default:
+        return ERR_INCOMPATIBLE_ACTION;
+    }
+}
+
 #ifdef K_DRIVER_ACTIVATE_GPIO
 /**
  * @brief Writes a specified action to a GPIO pin, identified by its interface ID.
@@ -335,8 +504,312 @@ HAL_INTERFACE_RESULT gpio_write(const uint8_t p_id, const GPIO_WRITE_ACTION p_ac
 
     return OK;
 }
+
+/**
+ * @brief Busy-waits for the given number of microseconds.
+ *
+ * The delay is measured with the DWT cycle counter, which is enabled on first use.
+ * Interrupts are not masked, so the actual delay may be longer if an interrupt fires.
+ *
+ * @param p_us Delay duration in microseconds.
+ */
+static void delay_us(const uint32_t p_us)
+{
+    CoreDebug->DEMCR |= CoreDebug_DEMCR_TRCENA_Msk;
+    DWT->LAR = 0xC5ACCE55;
+    DWT->CTRL |= DWT_CTRL_CYCCNTENA_Msk;
+
+    const uint32_t l_cycles = p_us * (SystemCoreClock / 1000000U);
+    const uint32_t l_start = DWT->CYCCNT;
+    while ((DWT->CYCCNT - l_start) < l_cycles)
+    {
+    }
+}
+
+/**
+ * @brief Generates a high pulse on a GPIO pin, identified by its interface ID.
+ *
+ * The pin is set, held high for the requested duration using a busy-wait on the core
+ * clock, then cleared. The function blocks for the whole pulse duration, so the caller
+ * is responsible for bounding the pulse width.
+ *
+ * @param p_id The identifier of the GPIO interface within the driver allocation table.
+ * @param p_width_us The pulse width in microseconds.
+ *
+ * @return HAL_INTERFACE_RESULT Status of the operation, see gpio_write.
+ */
+HAL_INTERFACE_RESULT gpio_pulse(const uint8_t p_id, const uint32_t p_width_us)
+{
+    const HAL_INTERFACE_RESULT l_result = gpio_write(p_id, SET_PIN);
+    if (l_result != OK)
+    {
+        return l_result;
+    }
+
+    delay_us(p_width_us);
+
+    return gpio_write(p_id, CLEAR_PIN);
+}
+
+/**
+ * @brief Reads the level of a GPIO pin, identified by its interface ID.
+ *
+ * @param p_id The identifier of the GPIO interface within the driver allocation table.
+ * @param p_state Pointer where the pin level is written: true if the pin is high, false if low.
+ *
+ * @return HAL_INTERFACE_RESULT Status of the operation:
+ *         - OK: Pin level read successfully.
+ *         - ERR_WRONG_INTERFACE_ID: The id does not correspond to a valid interface.
+ *         - ERR_WRITE_ONLY_INTERFACE: The interface is an output and cannot be read.
+ *         - ERR_INCOMPATIBLE_ACTION: The interface is not a GPIO type.
+ */
+HAL_INTERFACE_RESULT gpio_read(const uint8_t p_id, bool *p_state)
+{
+    if (p_id >= K_DRIVERS_ALLOC_SIZE)
+    {
+        return ERR_WRONG_INTERFACE_ID;
+    }
+
+    if (K_DRIVERS_ALLOC[p_id].drv_direction == OUT)
+    {
+        return ERR_WRITE_ONLY_INTERFACE;
+    }
+
+    if (K_DRIVERS_ALLOC[p_id].drv_type != GPIO)
+    {
+        return ERR_INCOMPATIBLE_ACTION;
+    }
+
+    const GPIO_ALLOC *l_gpio = K_DRIVERS_ALLOC[p_id].drv;
+    *p_state = HAL_GPIO_ReadPin(l_gpio->gpio, l_gpio->pin) == GPIO_PIN_SET;
+
+    return OK;
+}
 #endif
 
+/**
+ * @brief Writes an output value to a DAC channel, identified by its interface ID.
+ *
+ * The DAC is used with 12-bit right-aligned data: values above K_DAC_MAX_VALUE are clamped
+ * to full scale instead of wrapping around. The channel must have been enabled by the
+ * peripheral initialization sequence, this function only updates its data holding register.
+ *
+ * The function is always compiled: when no DAC is allocated, every interface is reported
+ * as incompatible.
+ *
+ * @param p_id The identifier of the DAC interface within the driver allocation table.
+ * @param p_value The output value, from 0 to K_DAC_MAX_VALUE.
+ *
+ * @return HAL_INTERFACE_RESULT Status of the operation:
+ *         - OK: Value written successfully.
+ *         - ERR_WRONG_INTERFACE_ID: The id does not correspond to a valid interface.
+ *         - ERR_READ_ONLY_INTERFACE: The interface is read-only and cannot perform a write action.
+ *         - ERR_INCOMPATIBLE_ACTION: The interface is not a DAC type.
+ */
+HAL_INTERFACE_RESULT dac_write(const uint8_t p_id, const uint16_t p_value)
+{
+    if (p_id >= K_DRIVERS_ALLOC_SIZE)
+    {
+        return ERR_WRONG_INTERFACE_ID;
+    }
+
+    if (K_DRIVERS_ALLOC[p_id].drv_direction == IN)
+    {
+        return ERR_READ_ONLY_INTERFACE;
+    }
+
+    switch (K_DRIVERS_ALLOC[p_id].drv_type)
+    {
+#ifdef K_DRIVER_ACTIVATE_DAC
+    case DAC_IF:
+        {
+            const DAC_ALLOC *l_dac = K_DRIVERS_ALLOC[p_id].drv;
+            const uint16_t l_value = p_value > K_DAC_MAX_VALUE ? K_DAC_MAX_VALUE : p_value;
+            if (l_dac->channel == 2)
+            {
+                l_dac->dac->DHR12R2 = l_value;
+            }
+            else
+            {
+                l_dac->dac->DHR12R1 = l_value;
+            }
+            return OK;
+        }
+#endif
+    default:
+        (void)p_value;
+        return ERR_INCOMPATIBLE_ACTION;
+    }
+}
+
+#ifdef K_DRIVER_ACTIVATE_TIMER
+/**
+ * @brief Enables the clock of a timer peripheral and returns its input frequency.
+ *
+ * Timers on APB1 and APB2 are clocked at twice the bus frequency when the bus prescaler
+ * is not 1.
+ *
+ * @param p_tim The timer peripheral.
+ *
+ * @return The timer input clock frequency in Hz, or 0 if the timer is not supported.
+ */
+static uint32_t timer_clock_enable(const TIM_TypeDef *p_tim)
+{
+    uint32_t l_pclk;
+    uint32_t l_apb_div;
+
+    if (p_tim == TIM6)
+    {
+        __HAL_RCC_TIM6_CLK_ENABLE();
+    }
+    else if (p_tim == TIM7)
+    {
+        __HAL_RCC_TIM7_CLK_ENABLE();
+    }
+    else
+    {
+        return 0;
+    }
+
+    // Basic timers are on APB1
+    l_pclk = HAL_RCC_GetPCLK1Freq();
+    l_apb_div = RCC->CFGR & RCC_CFGR_PPRE1;
+    return l_apb_div == RCC_HCLK_DIV1 ? l_pclk : 2 * l_pclk;
+}
+#endif
+
+/**
+ * @brief Starts or stops a hardware timer calling the interface callback periodically.
+ *
+ * The timer counts in up mode and raises its update interrupt once per period. The
+ * interrupt handler calls the callback configured with configure_callback, in interrupt
+ * context. The prescaler is chosen as small as possible, so the period is exact to within
+ * one timer clock cycle when it fits a 16-bit counter.
+ *
+ * In one-shot mode, the timer runs in one pulse mode: the counter stops at the first update
+ * event and the interrupt handler disables the update interrupt, so the callback is called
+ * only once.
+ *
+ * Calling this function again reprograms the running timer. A period of 0 stops it.
+ *
+ * @param p_id The identifier of the timer interface within the driver allocation table.
+ * @param p_period_us The callback period in microseconds, from K_TIMER_MIN_PERIOD_US. 0 stops the timer.
+ * @param p_one_shot true to call the callback only once, one period after the start.
+ *
+ * @return HAL_INTERFACE_RESULT Status of the operation:
+ *         - OK: The timer was started or stopped.
+ *         - ERR_WRONG_INTERFACE_ID: The id does not correspond to a valid interface.
+ *         - ERR_INCOMPATIBLE_ACTION: The interface is not a timer, or its peripheral is not supported.
+ *         - ERR_INVALID_PERIOD: The period is below K_TIMER_MIN_PERIOD_US or too long for the timer.
+ */
+HAL_INTERFACE_RESULT timer_configure(const uint8_t p_id, const uint32_t p_period_us, const bool p_one_shot)
+{
+    if (p_id >= K_DRIVERS_ALLOC_SIZE)
+    {
+        return ERR_WRONG_INTERFACE_ID;
+    }
+
+    switch (K_DRIVERS_ALLOC[p_id].drv_type)
+    {
+#ifdef K_DRIVER_ACTIVATE_TIMER
+    case TIMER:
+        {
+            const TIMER_ALLOC *l_timer = K_DRIVERS_ALLOC[p_id].drv;
+            TIM_TypeDef *l_tim = l_timer->tim;
+            const uint32_t l_clock = timer_clock_enable(l_tim);
+            if (l_clock == 0)
+            {
+                return ERR_INCOMPATIBLE_ACTION;
+            }
+
+            // Stop the timer before any change
+            HAL_NVIC_DisableIRQ(l_timer->irq);
+            l_tim->CR1 &= ~TIM_CR1_CEN;
+            l_tim->DIER &= ~TIM_DIER_UIE;
+            l_tim->SR = ~TIM_SR_UIF;
+
+            if (p_period_us == 0)
+            {
+                return OK;
+            }
+            if (p_period_us < K_TIMER_MIN_PERIOD_US)
+            {
+                return ERR_INVALID_PERIOD;
+            }
+
+            // Split the period in timer clock cycles into prescaler and reload values
+            const uint64_t l_cycles = (uint64_t)(l_clock / 1000000U) * p_period_us;
+            const uint64_t l_prescaler = (l_cycles + 0xFFFFU) / 0x10000U;
+            if (l_prescaler > 0x10000U)
+            {
+                return ERR_INVALID_PERIOD;
+            }
+
+            l_tim->PSC = (uint32_t)(l_prescaler - 1);
+            l_tim->ARR = (uint32_t)(l_cycles / l_prescaler - 1);
+            l_tim->CNT = 0;
+
+            // Load the prescaler now, without calling the callback
+            l_tim->EGR = TIM_EGR_UG;
+            l_tim->SR = ~TIM_SR_UIF;
+
+            if (p_one_shot)
+            {
+                l_tim->CR1 |= TIM_CR1_OPM;
+            }
+            else
+            {
+                l_tim->CR1 &= ~TIM_CR1_OPM;
+            }
+
+            l_tim->DIER |= TIM_DIER_UIE;
+            HAL_NVIC_SetPriority(l_timer->irq, 0, 0);
+            HAL_NVIC_EnableIRQ(l_timer->irq);
+            l_tim->CR1 |= TIM_CR1_CEN;
+            return OK;
+        }
+#endif
+    default:
+        return ERR_INCOMPATIBLE_ACTION;
+    }
+}
+
+/**
+ * @brief Update interrupt handler shared by all timer interfaces.
+ *
+ * Clears the update flag, then calls the callback configured for the interface using
+ * this timer, if any. The update interrupt of a one-shot timer is disabled first, the
+ * hardware has already stopped its counter.
+ *
+ * @param p_tim The timer peripheral which raised the interrupt.
+ */
+void timer_it_handler(TIM_TypeDef *p_tim)
+{
+    p_tim->SR = ~TIM_SR_UIF;
+    if ((p_tim->CR1 & TIM_CR1_OPM) != 0U)
+    {
+        p_tim->DIER &= ~TIM_DIER_UIE;
+    }
+
+#ifdef K_DRIVER_ACTIVATE_TIMER
+    // Get the ID corresponding to the timer
+    for (uint8_t l_i = 0; l_i < K_DRIVERS_ALLOC_SIZE; l_i++)
+    {
+        if (K_DRIVERS_ALLOC[l_i].drv_type == TIMER
+            && ((const TIMER_ALLOC *)K_DRIVERS_ALLOC[l_i].drv)->tim == p_tim)
+        {
+            // If a callback is configured
+            if (G_callbacks[l_i] != NULL)
+            {
+                // Call the callback
+                G_callbacks[l_i](l_i);
+            }
+            break;
+        }
+    }
+#endif
+}
+
 #ifdef K_DRIVER_ACTIVATE_USART
 /**
  * @brief Writes data to a specified USART interface.
@@ -637,4 +1110,69 @@ HAL_INTERFACE_RESULT set_fb_address(const uint8_t p_id, const uint8_t p_layer, c
     BSP_LCD_SetLayerAddress(p_layer, p_addr);
     return OK;
 }
+
+/**
+ * @brief Transfers an area of an LCD layer frame buffer to the panel.
+ *
+ * This function validates the LCD interface ID before requesting the update of
+ * the given area. The DSI panel is driven in video mode: the LTDC continuously
+ * scans the frame buffer, so no explicit transfer is required and the call only
+ * performs the validation.
+ *
+ * @param p_id The interface ID corresponding to the target LCD.
+ * @param p_layer The layer index to refresh.
+ * @param p_x X coordinate of the top-left corner of the area.
+ * @param p_y Y coordinate of the top-left corner of the area.
+ * @param p_width Width of the area in pixels.
+ * @param p_height Height of the area in pixels.
+ *
+ * @return HAL_INTERFACE_RESULT Returns one of the following results:
+ *         - OK on success.
+ *         - ERR_WRONG_INTERFACE_ID if the specified interface ID is invalid.
+ *         - ERR_INCOMPATIBLE_ACTION if the specified interface is not an LCD type.
+ */
+HAL_INTERFACE_RESULT lcd_refresh(const uint8_t p_id, const uint8_t p_layer, const uint16_t p_x, const uint16_t p_y,
+                                 const uint16_t p_width, const uint16_t p_height)
+{
+    const HAL_INTERFACE_RESULT l_result = lcd_id_check(p_id);
+    if (l_result != OK)
+    {
+        return l_result;
+    }
+
+    (void) p_layer;
+    (void) p_x;
+    (void) p_y;
+    (void) p_width;
+    (void) p_height;
+
+    return OK;
+}
+
+/**
+ * @brief Sets the backlight brightness of the specified LCD.
+ *
+ * This function validates the LCD interface ID and updates the panel brightness
+ * using BSP_LCD_SetBrightness. Values above 100 are saturated to 100.
+ *
+ * @param p_id The interface ID corresponding to the target LCD.
+ * @param p_percent The brightness in percent, from 0 (dark) to 100 (full brightness).
+ *
+ * @return HAL_INTERFACE_RESULT Returns one of the following results:
+ *         - OK on success.
+ *         - ERR_WRONG_INTERFACE_ID if the specified interface ID is invalid.
+ *         - ERR_INCOMPATIBLE_ACTION if the specified interface is not an LCD type.
+ */
+HAL_INTERFACE_RESULT lcd_set_backlight(const uint8_t p_id, const uint8_t p_percent)
+{
+    const HAL_INTERFACE_RESULT l_result = lcd_id_check(p_id);
+    if (l_result != OK)
+    {
+        return l_result;
+    }
+
+    BSP_LCD_SetBrightness(p_percent > 100 ? 100 : p_percent);
+
+    return OK;
+}
 #endif
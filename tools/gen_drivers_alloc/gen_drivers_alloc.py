@@ -30,6 +30,12 @@ DRIVER_ALLOC_TABLE_NAME = "K_DRIVERS_ALLOC"
 
 USART_DRIVER_NAME = "USART"
 GPIO_DRIVER_NAME = "GPIO"
+DAC_DRIVER_NAME = "DAC"
+TIMER_DRIVER_NAME = "TIMER"
+
+# INTERFACE_TYPE enumerators differing from the configuration type name. DAC is a CMSIS
+# peripheral macro, so the enumerator cannot use that name.
+INTERFACE_TYPE_ENUM = {DAC_DRIVER_NAME: "DAC_IF"}
 
 BUFFER_NAME_SUFFIX = "_BUFFER"
 BUFFER_SIZE_SUFFIX = "_BUFFER_SIZE"
@@ -161,14 +167,17 @@ def gen_table(table_type: str, table_name: str, fields: list, is_const: bool = F
 def get_peripheral_handler(peripheral, handlers_init: list):
     """
     Returns a string reference to the peripheral handler or empty string based on the
-    peripheral type. Updates the `handlers_init` list if the peripheral type is GPIO.
+    peripheral type. Updates the `handlers_init` list if the peripheral type is GPIO, DAC
+    or TIMER.
 
     :param peripheral: A dictionary containing details about the peripheral. Must
         include keys "type" and "peripheral". If "type" is "GPIO", "peripheral"
-        must also contain nested keys "port" and "pin".
+        must also contain nested keys "port" and "pin". If "type" is "DAC",
+        "peripheral" must contain the nested key "channel". If "type" is "TIMER",
+        "peripheral" is the timer name (e.g. "TIM7").
     :type peripheral: dict
     :param handlers_init: A list to which generated initialization structures
-        for GPIO peripherals will be appended.
+        for GPIO, DAC and TIMER peripherals will be appended.
     :type handlers_init: list
     :return: A string reference to the peripheral handler or an empty string if
         the peripheral type is unsupported.
@@ -190,6 +199,34 @@ def get_peripheral_handler(peripheral, handlers_init: list):
             )
         )
         return f"&{gpio_strict_name}"
+    elif peripheral["type"] == DAC_DRIVER_NAME:
+        dac_strict_name = f"K_DAC_CH{peripheral['peripheral']['channel']}"
+        handlers_init.extend(
+            gen_struct_init(
+                "DAC_ALLOC",
+                dac_strict_name,
+                [
+                    ["dac", "DAC"],
+                    ["channel", f"{peripheral['peripheral']['channel']}"],
+                ],
+                True,
+            )
+        )
+        return f"&{dac_strict_name}"
+    elif peripheral["type"] == TIMER_DRIVER_NAME:
+        timer_strict_name = f"K_TIMER_{peripheral['peripheral']}"
+        handlers_init.extend(
+            gen_struct_init(
+                "TIMER_ALLOC",
+                timer_strict_name,
+                [
+                    ["tim", peripheral["peripheral"]],
+                    ["irq", f"{peripheral['peripheral']}_IRQn"],
+                ],
+                True,
+            )
+        )
+        return f"&{timer_strict_name}"
     elif peripheral["peripheral"] == "None":
         return "0"
     else:
@@ -232,7 +269,7 @@ def gen_drivers_alloc(peri_config: dict, analysis: dict):
         # Generate peripherals dictionary
         peri_fields = [
             peripheral["name"],
-            peripheral["type"],
+            INTERFACE_TYPE_ENUM.get(peripheral["type"], peripheral["type"]),
             peripheral["direction"],
             get_peripheral_handler(peripheral, struct_init_c_code),
             peri_buffer,
@@ -345,6 +382,8 @@ def gen_handlers_func(config: dict):
                 func_code.append(
                     f"    HAL_UART_IRQHandler({get_peripheral_handler(drv, [])});"
                 )
+            elif drv["type"] == TIMER_DRIVER_NAME:
+                func_code.append(f"    timer_it_handler({drv['peripheral']});")
             func_code.append("}")
 
     return func_code
@@ -599,6 +638,11 @@ if __name__ == "__main__":
             and "usart.h" not in pre_analysis["includes_c"]
         ):
             pre_analysis["includes_c"].append("usart.h")
+        if (
+            driver["type"] == TIMER_DRIVER_NAME
+            and "../Inc/lib_interface.h" not in pre_analysis["includes_c"]
+        ):
+            pre_analysis["includes_c"].append("../Inc/lib_interface.h")
         # Add activation for each driver
         activation = f"K_DRIVER_ACTIVATE_{driver['type']}"
         if activation not in pre_analysis["activations"]: